@@ -1,5 +1,6 @@
 use anyhow::Result;
 use chart_core::{Axis, Chart, RenderOptions, Series};
+use chart_core::scale::{map_points, TimeScale, ValueScale};
 use chart_core::series::SeriesType;
 use criterion::{criterion_group, criterion_main, Criterion, black_box};
 
@@ -36,6 +37,38 @@ fn bench_render(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_render);
+// Per-point closures (the old draw_line_series path) vs bulk `map_points` on a 200k line,
+// plus the full render that now uses the bulk path.
+fn bench_map_points(c: &mut Criterion) {
+    let n = 200_000usize;
+    let ch = build_chart_xy(n);
+    let data = &ch.series[0].data_xy;
+    let ts = TimeScale::new(60.0, 0.0, 720.0 / (n - 1) as f32);
+    let vs = ValueScale::new_linear(20.0, 460.0, -12.0, 12.0);
+    let mut group = c.benchmark_group("map_points");
+    group.bench_function("per_point_200k", |b| {
+        b.iter(|| {
+            let mapped: Vec<(f32, f32)> = data.iter().map(|&(x, y)| (ts.to_px(x), vs.to_px(y))).collect();
+            black_box(mapped);
+        });
+    });
+    group.bench_function("bulk_200k", |b| {
+        let mut mapped = Vec::new();
+        b.iter(|| {
+            map_points(&ts, &vs, data, &mut mapped);
+            black_box(&mapped);
+        });
+    });
+    group.bench_function("render_xy_200k", |b| {
+        let opts = RenderOptions { width: 800, height: 500, draw_labels: false, ..Default::default() };
+        b.iter(|| -> Result<()> {
+            black_box(ch.render_to_png_bytes(&opts)?);
+            Ok(())
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_render, bench_map_points);
 criterion_main!(benches);
 
@@ -1,23 +1,50 @@
 // File: crates/chart-core/src/axis.rs
 // Summary: Axis model with labels and ranges.
 
+use skia_safe as skia;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ScaleKind {
     Linear,
     Log10,
 }
 
+/// How tick labels (and tooltip values) are formatted for an axis.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LabelFormat {
+    /// Decimals chosen from the axis span; SI suffixes for very large spans.
+    #[default]
+    Auto,
+    /// Fixed number of decimals, e.g. `Fixed(2)` for prices.
+    Fixed(usize),
+    /// Mantissa/exponent notation (`1.23e4`).
+    Scientific,
+    /// K/M/B/T suffixes (`1.2M`).
+    SiPrefix,
+    /// Values already expressed in percent, suffixed with `%`.
+    Percent,
+    /// Thousands separators (`1,234,567.89`).
+    Grouped,
+}
+
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Axis {
     pub label: String,
     pub min: f64,
     pub max: f64,
     pub kind: ScaleKind,
+    pub label_format: LabelFormat,
+    pub label_precision: Option<usize>, // decimals override for every format except Fixed
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::spec::opt_color"))]
+    pub line_color: Option<skia::Color>, // axis line color; theme.axis_line when None
 }
 
 impl Axis {
     pub fn new(label: impl Into<String>, min: f64, max: f64) -> Self {
-        Self { label: label.into(), min, max, kind: ScaleKind::Linear }
+        Self { label: label.into(), min, max, kind: ScaleKind::Linear, label_format: LabelFormat::Auto, label_precision: None, line_color: None }
     }
 
     pub fn default_x() -> Self {
@@ -31,4 +58,103 @@ impl Axis {
         a.kind = ScaleKind::Linear;
         a
     }
+
+    /// Format a value on this axis according to `label_format` / `label_precision`.
+    /// `Fixed(n)` always uses its own `n`; the other formats fall back to their
+    /// usual precision when `label_precision` is `None`.
+    pub fn format_value(&self, v: f64) -> String {
+        let prec = self.label_precision;
+        match self.label_format {
+            LabelFormat::Auto => match prec {
+                Some(p) => format!("{:.*}", p, v),
+                None => format_tick(v, self.min, self.max),
+            },
+            LabelFormat::Fixed(n) => format!("{:.*}", n, v),
+            LabelFormat::Scientific => format!("{:.*e}", prec.unwrap_or(2), v),
+            LabelFormat::SiPrefix => format_si(v, prec),
+            LabelFormat::Percent => {
+                format!("{:.*}%", prec.unwrap_or_else(|| auto_decimals(self.min, self.max)), v)
+            }
+            LabelFormat::Grouped => format_grouped(v, prec.unwrap_or_else(|| auto_decimals(self.min, self.max))),
+        }
+    }
+
+    /// Round `min` down and `max` up to multiples of the tick step the axis would use
+    /// (about six ticks across the span), so endpoints land on clean values. Log10 axes
+    /// snap to whole decades instead. Empty or non-finite ranges are left alone.
+    pub fn snap_to_nice_bounds(&mut self) {
+        if !self.min.is_finite() || !self.max.is_finite() || self.max <= self.min { return; }
+        match self.kind {
+            ScaleKind::Linear => {
+                let step = nice_step((self.max - self.min) / 6.0);
+                self.min = (self.min / step).floor() * step;
+                self.max = (self.max / step).ceil() * step;
+            }
+            ScaleKind::Log10 => {
+                if self.min <= 0.0 { return; }
+                self.min = 10f64.powf(self.min.log10().floor());
+                self.max = 10f64.powf(self.max.log10().ceil());
+            }
+        }
+    }
+}
+
+/// Tick step from the 1-2-5 sequence closest above `raw`.
+pub(crate) fn nice_step(raw: f64) -> f64 {
+    // 1-2-5 scheme scaled by power of 10
+    let power = raw.abs().log10().floor();
+    let base = 10f64.powf(power);
+    let n = raw / base;
+    let nice = if n <= 1.0 { 1.0 } else if n <= 2.0 { 2.0 } else if n <= 5.0 { 5.0 } else { 10.0 };
+    nice * base
+}
+
+/// Decimals for a plain numeric label given the visible span.
+fn auto_decimals(min: f64, max: f64) -> usize {
+    let mag = (max - min).abs().max(1e-12).log10();
+    if mag >= 6.0 { 0 } else if mag >= 3.0 { 1 } else if mag >= 1.0 { 2 } else { 3 }
+}
+
+/// Format `v` with `decimals` places and a comma between each group of three integer digits.
+pub fn format_grouped(v: f64, decimals: usize) -> String {
+    let plain = format!("{:.*}", decimals, v);
+    if !v.is_finite() { return plain; }
+    let (sign, unsigned) = match plain.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", plain.as_str()),
+    };
+    let (int_part, frac_part) = match unsigned.find('.') {
+        Some(dot) => unsigned.split_at(dot),
+        None => (unsigned, ""),
+    };
+    let mut out = String::with_capacity(plain.len() + int_part.len() / 3);
+    out.push_str(sign);
+    for (i, ch) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i) % 3 == 0 { out.push(','); }
+        out.push(ch);
+    }
+    out.push_str(frac_part);
+    out
+}
+
+pub(crate) fn format_tick(v: f64, min: f64, max: f64) -> String {
+    let span = (max - min).abs().max(1e-12);
+    // Use SI prefixes for large spans
+    if span >= 1e6 {
+        return format_si(v, None);
+    }
+    format!("{:.*}", auto_decimals(min, max), v)
+}
+
+fn format_si(v: f64, precision: Option<usize>) -> String {
+    let av = v.abs();
+    let (unit, div) = if av >= 1e12 { ("T", 1e12) }
+        else if av >= 1e9 { ("B", 1e9) }
+        else if av >= 1e6 { ("M", 1e6) }
+        else if av >= 1e3 { ("K", 1e3) }
+        else { ("", 1.0) };
+    if unit.is_empty() { return format!("{:.*}", precision.unwrap_or(2), v); }
+    let val = v / div;
+    let decimals = precision.unwrap_or(if av >= 1e9 { 2 } else { 1 });
+    format!("{:.*}{}", decimals, val, unit)
 }
@@ -1,22 +1,104 @@
 // File: crates/chart-core/src/chart.rs
 // Summary: Chart struct and headless PNG rendering pipeline using Skia CPU raster surfaces.
 
+use std::cell::Cell;
+
 use anyhow::Result;
 use skia_safe as skia;
 
 use crate::grid::linspace;
-use crate::series::{Series, SeriesType};
+use crate::series::{BarAlign, CandlePattern, MarkerShape, Series, SeriesType};
 use crate::types::{Insets, WIDTH, HEIGHT};
+use crate::geometry::RectI32;
 use crate::Axis;
+use crate::view::ViewState;
 use crate::theme::Theme;
-use crate::axis::ScaleKind;
-use crate::scale::{TimeScale, ValueScale};
+use crate::axis::{format_tick, nice_step, LabelFormat, ScaleKind};
+use crate::scale::{map_points, TimeScale, ValueScale};
 use crate::text::TextShaper;
-use crate::plugin::Overlay as OverlayTrait;
+use crate::plugin::{Overlay as OverlayTrait, OverlayLabel, PaneTarget};
+use crate::png::{insert_text_chunks, PngMetadata};
 // For time-aware axis formatting
 
 
 
+/// Candle body rendering style.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CandleStyle {
+    Filled,
+    Hollow, // up-candles outlined only, down-candles filled
+}
+
+/// Which crosshair lines to draw when `RenderOptions::crosshair` is set.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CrosshairMode {
+    #[default]
+    Cross,
+    HorizontalOnly, // value (price) line only
+    VerticalOnly,   // time line only
+}
+
+impl CrosshairMode {
+    pub fn horizontal(self) -> bool { self != CrosshairMode::VerticalOnly }
+    pub fn vertical(self) -> bool { self != CrosshairMode::HorizontalOnly }
+}
+
+/// Direction bars grow in. `Horizontal` puts X (categories) on the vertical axis and Y
+/// (values) along the bottom; only Column and Histogram series are drawn in that mode
+/// (others, overlays and tooltips are skipped), Y is always mapped linearly, and SVG
+/// export stays vertical.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Orientation {
+    #[default]
+    Vertical,
+    Horizontal,
+}
+
+/// Rectangle stroked along the image edge after all content, e.g. to separate a chart
+/// from a page of the same background color. `width` is in logical px (scaled by DPR).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Frame {
+    pub color: skia::Color,
+    pub width: f32,
+}
+
+impl Frame {
+    pub fn new(color: skia::Color, width: f32) -> Self {
+        Self { color, width }
+    }
+}
+
+/// Large faint text centered behind the plot (e.g. a ticker symbol), drawn in the
+/// axis label color at `opacity` (0..1).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Watermark {
+    pub text: String,
+    pub opacity: f32,
+}
+
+impl Watermark {
+    pub fn new(text: impl Into<String>, opacity: f32) -> Self {
+        Self { text: text.into(), opacity }
+    }
+
+    /// Font size (device px) that fits the text within a `w` x `h` box: at most
+    /// 30% of the height, narrowed for long strings.
+    pub(crate) fn font_size(&self, w: f32, h: f32) -> f32 {
+        let chars = self.text.chars().count().max(1) as f32;
+        // ~0.6em average advance for proportional fonts
+        (h * 0.3).min(w * 0.8 / (chars * 0.6)).max(1.0)
+    }
+}
+
+/// How `RenderOptions::background_image` covers the image.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BackgroundFit {
+    #[default]
+    Stretch, // scaled to the full width x height, ignoring aspect ratio
+    Tile,    // repeated at its natural pixel size from the top-left corner
+}
+
+#[derive(Clone, Debug)]
 pub struct RenderOptions {
     pub width: i32,
     pub height: i32,
@@ -27,7 +109,27 @@ pub struct RenderOptions {
     pub show_tooltip: bool,  // when crosshair is present, render hover tooltip
     pub crisp_lines: bool,   // align 1px lines to half-pixels for sharpness
     pub crosshair: Option<(f32, f32)>, // device px; when Some, draw crosshair overlay
+    pub crosshair_mode: CrosshairMode,
     pub dpr: f32,            // device pixel ratio for HiDPI (1.0 default)
+    pub show_minor_grid: bool, // extend minor ticks into faint gridlines
+    pub candle_style: CandleStyle,
+    pub candle_pattern_tint: Option<skia::Color>, // draw doji/marubozu candles in this color
+    pub auto_left_inset: bool, // size insets.left to the widest Y tick label at render time
+    pub x_label_rotation_deg: f32, // rotate X tick labels about their anchor (e.g. -45.0 for long timestamps)
+    pub orientation: Orientation,
+    pub gradient_fills: bool, // candle bodies and histogram/column bars fade lighter toward the top (raster only)
+    pub watermark: Option<Watermark>,
+    pub deterministic: bool, // AA off + bundled font, for byte-identical labeled output across platforms (raster)
+    pub equal_axis_scale: bool, // widen one axis range at render time so a data unit spans equal px on X and Y
+    pub png_metadata: Option<PngMetadata>, // tEXt chunks (title, time, series count) in PNG output
+    pub frame: Option<Frame>, // border around the whole image, drawn last
+    pub plot_border: bool, // close the plot area with top/right lines matching the axis lines
+    pub axis_line_width: f32, // axis (and plot border) stroke width in logical px
+    pub grid_opacity: f32, // 0..1 multiplier on theme.grid alpha (major and minor gridlines)
+    pub last_value_line: bool, // dashed line + axis badge at the first series' last value
+    pub background_image: Option<Vec<u8>>, // encoded PNG/JPEG drawn over the background color, under everything else
+    pub background_fit: BackgroundFit,
+    pub accessible_svg: bool, // SVG: role="img" plus <title>/<desc> summarizing series and axis ranges
 }
 
 impl Default for RenderOptions {
@@ -42,16 +144,255 @@ impl Default for RenderOptions {
             show_tooltip: false,
             crisp_lines: true,
             crosshair: None,
+            crosshair_mode: CrosshairMode::Cross,
             dpr: 1.0,
+            show_minor_grid: false,
+            candle_style: CandleStyle::Filled,
+            candle_pattern_tint: None,
+            auto_left_inset: false,
+            x_label_rotation_deg: 0.0,
+            orientation: Orientation::Vertical,
+            gradient_fills: false,
+            watermark: None,
+            deterministic: false,
+            equal_axis_scale: false,
+            png_metadata: None,
+            frame: None,
+            plot_border: false,
+            axis_line_width: 1.5,
+            grid_opacity: 1.0,
+            last_value_line: false,
+            background_image: None,
+            background_fit: BackgroundFit::Stretch,
+            accessible_svg: true,
         }
     }
 }
 
+impl RenderOptions {
+    /// Start a `RenderOptionsBuilder` seeded with `RenderOptions::default()`.
+    pub fn builder() -> RenderOptionsBuilder {
+        RenderOptionsBuilder::new()
+    }
+
+    /// Builder-style: replace the plot insets.
+    pub fn with_insets(mut self, insets: Insets) -> Self {
+        self.insets = insets;
+        self
+    }
+
+    /// Builder-style: grow `insets.left` (never shrink) so the widest Y tick label of `chart` fits.
+    pub fn fit_left_inset(mut self, chart: &Chart) -> Self {
+        self.insets.left = self.insets.left.max(chart.required_left_inset(&self));
+        self
+    }
+
+    /// Theme used for gridlines: `theme` with the grid alpha scaled by `grid_opacity`.
+    pub(crate) fn grid_theme(&self) -> Theme {
+        let mut theme = self.theme;
+        theme.grid = scale_alpha(self.theme.grid, self.grid_opacity);
+        theme
+    }
+}
+
+/// Setters for `RenderOptions`, one per field, taking the field's own type.
+///
+/// ```
+/// use chart_core::RenderOptions;
+///
+/// let opts = RenderOptions::builder().width(800).height(400).dpr(2.0).draw_labels(false).build();
+/// assert_eq!((opts.width, opts.height), (800, 400));
+/// assert!(!opts.draw_labels);
+/// assert_eq!(opts.axis_line_width, RenderOptions::default().axis_line_width);
+/// ```
+pub struct RenderOptionsBuilder {
+    opts: RenderOptions,
+}
+
+macro_rules! render_options_setters {
+    ($($field:ident: $ty:ty),* $(,)?) => {
+        $(
+            pub fn $field(mut self, value: $ty) -> Self {
+                self.opts.$field = value;
+                self
+            }
+        )*
+    };
+}
+
+impl RenderOptionsBuilder {
+    pub fn new() -> Self {
+        Self { opts: RenderOptions::default() }
+    }
+
+    render_options_setters!(
+        width: i32,
+        height: i32,
+        insets: Insets,
+        background: skia::Color,
+        theme: Theme,
+        draw_labels: bool,
+        show_tooltip: bool,
+        crisp_lines: bool,
+        crosshair: Option<(f32, f32)>,
+        crosshair_mode: CrosshairMode,
+        dpr: f32,
+        show_minor_grid: bool,
+        candle_style: CandleStyle,
+        candle_pattern_tint: Option<skia::Color>,
+        auto_left_inset: bool,
+        x_label_rotation_deg: f32,
+        orientation: Orientation,
+        gradient_fills: bool,
+        watermark: Option<Watermark>,
+        deterministic: bool,
+        equal_axis_scale: bool,
+        png_metadata: Option<PngMetadata>,
+        frame: Option<Frame>,
+        plot_border: bool,
+        axis_line_width: f32,
+        grid_opacity: f32,
+        last_value_line: bool,
+        background_image: Option<Vec<u8>>,
+        background_fit: BackgroundFit,
+        accessible_svg: bool,
+    );
+
+    pub fn build(self) -> RenderOptions {
+        self.opts
+    }
+}
+
+impl Default for RenderOptionsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Chainable alternative to mutating a fresh `Chart` field by field.
+///
+/// ```
+/// use chart_core::{Axis, Chart, Series, SeriesType, Theme};
+///
+/// let (chart, opts) = Chart::builder()
+///     .x_axis(Axis::new("Day", 0.0, 4.0))
+///     .y_axis(Axis::new("Price", 0.0, 10.0))
+///     .series(Series::with_data(SeriesType::Line, vec![(0.0, 2.0), (2.0, 7.5), (4.0, 5.0)]))
+///     .theme(Theme::light())
+///     .build_with_options();
+/// assert_eq!(chart.series.len(), 1);
+/// assert_eq!(opts.theme.name, "light");
+/// let png = chart.render_to_png_bytes(&opts)?;
+/// assert!(!png.is_empty());
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub struct ChartBuilder {
+    chart: Chart,
+    theme: Option<Theme>,
+}
+
+impl ChartBuilder {
+    pub fn new() -> Self {
+        Self { chart: Chart::new(), theme: None }
+    }
+
+    pub fn x_axis(mut self, axis: Axis) -> Self {
+        self.chart.x_axis = axis;
+        self
+    }
+
+    pub fn y_axis(mut self, axis: Axis) -> Self {
+        self.chart.y_axis = axis;
+        self
+    }
+
+    /// Append a series; call repeatedly for several (drawn in insertion order).
+    pub fn series(mut self, series: Series) -> Self {
+        self.chart.add_series(series);
+        self
+    }
+
+    pub fn overlay<O: OverlayTrait + 'static>(mut self, overlay: O) -> Self {
+        self.chart.add_overlay(overlay);
+        self
+    }
+
+    /// Theme for the `RenderOptions` returned by `build_with_options`. A chart itself has
+    /// no theme (it comes from `RenderOptions` at render time), so `build` ignores it.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    pub fn build(self) -> Chart {
+        self.chart
+    }
+
+    /// The chart plus default `RenderOptions` carrying the configured theme.
+    pub fn build_with_options(self) -> (Chart, RenderOptions) {
+        let mut opts = RenderOptions::default();
+        if let Some(theme) = self.theme { opts.theme = theme; }
+        (self.chart, opts)
+    }
+}
+
+impl Default for ChartBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Shaded region between two XY series of a chart, added with `Chart::fill_between`.
+/// Indices refer to `Chart::series`; fills whose indices are out of range draw nothing.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FillBetween {
+    pub series_a: usize,
+    pub series_b: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::spec::color"))]
+    pub color: skia::Color,
+}
+
+impl FillBetween {
+    /// Closed outline in data space: A's finite points forward, then B evaluated at the same
+    /// x values on the way back. B is linearly interpolated (held flat past its ends), so
+    /// both series only need to share an x-domain, not sample points.
+    pub(crate) fn polygon(&self, series: &[Series]) -> Vec<(f64, f64)> {
+        let (Some(a), Some(b)) = (series.get(self.series_a), series.get(self.series_b)) else { return Vec::new() };
+        let finite = |s: &Series| -> Vec<(f64, f64)> {
+            s.data_xy.iter().copied().filter(|p| p.0.is_finite() && p.1.is_finite()).collect()
+        };
+        let (a, b) = (finite(a), finite(b));
+        if a.len() < 2 || b.is_empty() { return Vec::new(); }
+        let b_at = |x: f64| -> f64 {
+            let i = b.partition_point(|p| p.0 < x);
+            if i == 0 { return b[0].1; }
+            if i == b.len() { return b[i - 1].1; }
+            let ((x0, y0), (x1, y1)) = (b[i - 1], b[i]);
+            if x1 == x0 { y1 } else { y0 + (y1 - y0) * (x - x0) / (x1 - x0) }
+        };
+        let back: Vec<(f64, f64)> = a.iter().rev().map(|&(x, _)| (x, b_at(x))).collect();
+        a.into_iter().chain(back).collect()
+    }
+}
+
+/// Result of `Chart::hit_test`: the nearest series point within tolerance.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HitResult {
+    pub series_index: usize,
+    pub point_index: usize, // index into data_xy or data_ohlc
+    pub x: f64,
+    pub y: f64,             // y value for xy series; close for candles/bars
+    pub distance_px: f32,
+}
+
 pub struct Chart {
     pub series: Vec<Series>,
     pub x_axis: Axis,
     pub y_axis: Axis,
     pub overlays: Vec<Box<dyn OverlayTrait>>, // optional computed overlays
+    pub nice_bounds: bool,                    // autoscale snaps both axes to nice_step multiples
+    pub fills: Vec<FillBetween>,              // shaded regions between series, under all series
 }
 
 impl Chart {
@@ -61,13 +402,34 @@ impl Chart {
             x_axis: Axis::default_x(),
             y_axis: Axis::default_y(),
             overlays: Vec::new(),
+            nice_bounds: false,
+            fills: Vec::new(),
         }
     }
 
+    /// Start a `ChartBuilder` (default axes, no series).
+    pub fn builder() -> ChartBuilder {
+        ChartBuilder::new()
+    }
+
     pub fn add_series(&mut self, series: Series) {
         self.series.push(series);
     }
 
+    /// Add a series after validating its candles (see `Series::validate_candles`);
+    /// malformed input is rejected with the first bad index instead of rendering oddly.
+    pub fn add_series_checked(&mut self, series: Series) -> Result<()> {
+        series.validate_candles()?;
+        self.series.push(series);
+        Ok(())
+    }
+
+    /// Shade the region between the XY series at `series_a_idx` and `series_b_idx` (e.g. a
+    /// spread). Filled in `color` beneath every series and clipped to the plot.
+    pub fn fill_between(&mut self, series_a_idx: usize, series_b_idx: usize, color: skia::Color) {
+        self.fills.push(FillBetween { series_a: series_a_idx, series_b: series_b_idx, color });
+    }
+
     /// Add an overlay provider (computed series drawn above base series).
     pub fn add_overlay<O: OverlayTrait + 'static>(&mut self, overlay: O) {
         self.overlays.push(Box::new(overlay));
@@ -76,55 +438,171 @@ impl Chart {
     /// Remove all overlays.
     pub fn clear_overlays(&mut self) { self.overlays.clear(); }
 
-    /// Dispatch an overlay event to all overlays (world coordinates).
-    pub fn handle_overlay_event(&self, evt: &crate::plugin::OverlayEvent) {
-        for ov in &self.overlays { ov.handle_event(evt, self); }
+    /// Remove the first overlay whose `Overlay::id` is `id`, keeping the others in order.
+    /// Returns false if none matched.
+    pub fn remove_overlay(&mut self, id: &str) -> bool {
+        match self.overlays.iter().position(|ov| ov.id() == id) {
+            Some(i) => { self.overlays.remove(i); true }
+            None => false,
+        }
+    }
+
+    /// Overlays drawn on this chart's own plot; `PaneTarget::Separate` ones are left to
+    /// `MultiChart::with_overlay_panes`.
+    pub(crate) fn main_overlays(&self) -> impl Iterator<Item = &dyn OverlayTrait> + '_ {
+        self.overlays.iter().map(|ov| ov.as_ref()).filter(|ov| ov.target_pane() == PaneTarget::Main)
+    }
+
+    /// Ids of the overlays in drawing order (duplicates possible).
+    pub fn overlay_ids(&self) -> Vec<&str> {
+        self.overlays.iter().map(|ov| ov.id()).collect()
+    }
+
+    /// Apply `params` to every overlay with this `Overlay::id`; the next render recomputes
+    /// with them. Returns false if no overlay has the id.
+    pub fn set_overlay_params(&mut self, id: &str, params: crate::plugin::OverlayParams) -> bool {
+        let mut found = false;
+        for ov in self.overlays.iter_mut().filter(|ov| ov.id() == id) {
+            ov.set_params(params);
+            found = true;
+        }
+        found
+    }
+
+    /// Dispatch an overlay event (world coordinates) topmost overlay first, stopping at the
+    /// first one that consumes it. `Consumed` means the frontend should not also pan.
+    pub fn handle_overlay_event(&self, evt: &crate::plugin::OverlayEvent) -> crate::plugin::EventResult {
+        for ov in self.overlays.iter().rev() {
+            if ov.handle_event(evt, self).is_consumed() { return crate::plugin::EventResult::Consumed; }
+        }
+        crate::plugin::EventResult::Ignored
+    }
+
+    /// Convert every series to percent change from its own first value (or from `base`
+    /// for all of them) and switch Y labels to percent. Call an autoscale afterwards;
+    /// the axis range is left untouched.
+    pub fn normalize_percent(&mut self, base: Option<f64>) {
+        for s in &mut self.series {
+            *s = s.to_percent_change(base);
+        }
+        self.y_axis.label_format = LabelFormat::Percent;
+    }
+
+    /// Per-series stack bases: for each stacked Column/Baseline series, the value each
+    /// point starts from (the running total of earlier series in the same group at that x).
+    /// Positive and negative values stack separately. `None` for unstacked series.
+    pub(crate) fn stack_bases(&self) -> Vec<Option<Vec<f64>>> {
+        use std::collections::HashMap;
+        let mut pos: HashMap<(&str, u64), f64> = HashMap::new();
+        let mut neg: HashMap<(&str, u64), f64> = HashMap::new();
+        self.series
+            .iter()
+            .map(|s| {
+                let group = s.stack_group.as_deref().filter(|_| s.is_stacked())?;
+                let bases = s
+                    .data_xy
+                    .iter()
+                    .map(|&(x, y)| {
+                        let totals = if y < 0.0 { &mut neg } else { &mut pos };
+                        let total = totals.entry((group, x.to_bits())).or_insert(s.baseline_value());
+                        let base = *total;
+                        if y.is_finite() { *total += y; }
+                        base
+                    })
+                    .collect();
+                Some(bases)
+            })
+            .collect()
+    }
+
+    /// Series indices in paint order: ascending `z_index`, insertion order among equals.
+    pub(crate) fn draw_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.series.len()).collect();
+        order.sort_by_key(|&i| self.series[i].z_index);
+        order
+    }
+
+    /// Per-series (x, y_low, y_high) extents with stacking applied; autoscale and
+    /// `ViewState` fitting use these so stacked totals stay in view.
+    pub(crate) fn series_extents(&self) -> Vec<Box<dyn Iterator<Item = (f64, f64, f64)> + '_>> {
+        self.series
+            .iter()
+            .zip(self.stack_bases())
+            .map(|(s, bases)| -> Box<dyn Iterator<Item = (f64, f64, f64)> + '_> {
+                match bases {
+                    Some(b) => Box::new(stacked_spans(&s.data_xy, Some(&b), 0.0).into_iter().map(|(x, a, c)| (x, a.min(c), a.max(c)))),
+                    None => s.extents(),
+                }
+            })
+            .collect()
     }
 
     /// Auto-scale x/y axes to fit all attached series. Optional margin fraction expands the y range.
     pub fn autoscale_axes(&mut self, y_margin_frac: f64) {
-        if self.series.is_empty() { return; }
+        self.autoscale_axes_padded(0.0, y_margin_frac);
+    }
+
+    /// Auto-scale with independent padding fractions; each range grows by `frac * span` on both sides.
+    pub fn autoscale_axes_padded(&mut self, x_pad_frac: f64, y_pad_frac: f64) {
+        let Some((x_min, x_max, y_min, y_max)) = self.data_bounds() else { return };
+
+        // Apply padding symmetrically
+        let xm = (x_max - x_min) * x_pad_frac.max(0.0);
+        let ym = (y_max - y_min) * y_pad_frac.max(0.0);
+        self.x_axis.min = x_min - xm;
+        self.x_axis.max = x_max + xm;
+        self.y_axis.min = y_min - ym;
+        self.y_axis.max = y_max + ym;
+        self.snap_if_nice();
+    }
 
+    /// Auto-scale with Y anchored at zero, as for volume or histogram panes: all-positive
+    /// data gets `y_min = 0`, all-negative data `y_max = 0`, and only the opposite end is
+    /// padded by `y_margin_frac`. Data straddling zero is padded on both ends as usual.
+    /// X fits the data without padding.
+    pub fn autoscale_with_zero_base(&mut self, y_margin_frac: f64) {
+        let Some((x_min, x_max, y_min, y_max)) = self.data_bounds() else { return };
+        let lo = y_min.min(0.0);
+        let hi = y_max.max(0.0);
+        let m = (hi - lo) * y_margin_frac.max(0.0);
+        self.x_axis.min = x_min;
+        self.x_axis.max = x_max;
+        self.y_axis.min = if lo == 0.0 { 0.0 } else { lo - m };
+        self.y_axis.max = if hi == 0.0 { 0.0 } else { hi + m };
+        self.snap_if_nice();
+    }
+
+    fn snap_if_nice(&mut self) {
+        if self.nice_bounds {
+            self.x_axis.snap_to_nice_bounds();
+            self.y_axis.snap_to_nice_bounds();
+        }
+    }
+
+    /// Data extents (x_min, x_max, y_min, y_max) over all series, stacking applied;
+    /// degenerate spans are widened to 1. `None` when there is no finite data.
+    fn data_bounds(&self) -> Option<(f64, f64, f64, f64)> {
         let mut x_min = f64::INFINITY;
         let mut x_max = f64::NEG_INFINITY;
         let mut y_min = f64::INFINITY;
         let mut y_max = f64::NEG_INFINITY;
 
-        for s in &self.series {
-            match s.series_type {
-                SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline => {
-                    for &(x, y) in &s.data_xy {
-                        x_min = x_min.min(x);
-                        x_max = x_max.max(x);
-                        y_min = y_min.min(y);
-                        y_max = y_max.max(y);
-                    }
-                    if let Some(b) = s.baseline { y_min = y_min.min(b); y_max = y_max.max(b); }
-                }
-                SeriesType::Candlestick | SeriesType::Bar => {
-                    for c in &s.data_ohlc {
-                        x_min = x_min.min(c.t);
-                        x_max = x_max.max(c.t);
-                        y_min = y_min.min(c.l);
-                        y_max = y_max.max(c.h);
-                    }
-                }
+        for (s, extents) in self.series.iter().zip(self.series_extents()).filter(|(s, _)| s.include_in_autoscale) {
+            for (x, lo, hi) in extents {
+                x_min = x_min.min(x);
+                x_max = x_max.max(x);
+                y_min = y_min.min(lo);
+                y_max = y_max.max(hi);
             }
+            if let Some(b) = s.extent_baseline() { y_min = y_min.min(b); y_max = y_max.max(b); }
         }
 
         if !x_min.is_finite() || !x_max.is_finite() || !y_min.is_finite() || !y_max.is_finite() {
-            return;
+            return None;
         }
         if (x_max - x_min).abs() < 1e-12 { x_max = x_min + 1.0; }
         if (y_max - y_min).abs() < 1e-12 { y_max = y_min + 1.0; }
-
-        // Apply margin to Y
-        let span = y_max - y_min;
-        let m = span * y_margin_frac.max(0.0);
-        self.x_axis.min = x_min;
-        self.x_axis.max = x_max;
-        self.y_axis.min = y_min - m;
-        self.y_axis.max = y_max + m;
+        Some((x_min, x_max, y_min, y_max))
     }
 
     /// Render the chart to a PNG at `output_png_path` using a CPU raster surface.
@@ -145,7 +623,189 @@ impl Chart {
         self.draw_into(canvas, opts);
     }
 
-    /// Render the chart and return PNG-encoded bytes (headless).
+    /// Map a data point to device pixels using the same scales as rendering.
+    pub fn data_to_pixel(&self, opts: &RenderOptions, x: f64, y: f64) -> (f32, f32) {
+        let (ts, vs) = self.plot_scales(opts);
+        (ts.to_px(x), vs.to_px(y))
+    }
+
+    /// Map device pixels back to data coordinates (inverse of `data_to_pixel`).
+    pub fn pixel_to_data(&self, opts: &RenderOptions, px: f32, py: f32) -> (f64, f64) {
+        let (ts, vs) = self.plot_scales(opts);
+        (ts.from_px(px), vs.from_px(py))
+    }
+
+    /// Insets actually used for layout, in device px: `opts.insets` (logical px) with the
+    /// left side replaced by `required_left_inset` when `opts.auto_left_inset` is set,
+    /// then scaled by `opts.dpr`.
+    pub fn effective_insets(&self, opts: &RenderOptions) -> Insets {
+        let mut insets = opts.insets;
+        if opts.auto_left_inset {
+            insets.left = self.required_left_inset(opts);
+        }
+        insets.scaled(opts.dpr)
+    }
+
+    /// Plot rectangle in device px (shared by raster, SVG, and coordinate mapping).
+    pub fn plot_rect(&self, opts: &RenderOptions) -> RectI32 {
+        let insets = self.effective_insets(opts);
+        RectI32::from_ltrb(
+            insets.left as i32,
+            insets.top as i32,
+            opts.width - insets.right as i32,
+            opts.height - insets.bottom as i32,
+        )
+    }
+
+    /// Device-px region to redraw when the view changes from `old_view` to `new_view`, for
+    /// compositors that scroll the previous frame and repaint only what is new.
+    ///
+    /// A pure horizontal pan reports the revealed strip at the left or right plot edge,
+    /// extended down through the X labels; a pure vertical pan the strip at the top or
+    /// bottom, extended left through the Y labels. Zooms, diagonal pans and pans past a
+    /// full plot width/height report the whole plot rect. None if the views are equal.
+    pub fn dirty_rect(&self, old_view: &ViewState, new_view: &ViewState, opts: &RenderOptions) -> Option<RectI32> {
+        if old_view == new_view { return None; }
+        let plot = self.plot_rect(opts);
+        let (w, h) = (plot.width() as f64, plot.height() as f64);
+        // Y spans compare in the axis' own (possibly log) space
+        let ty = |v: f64| if self.y_axis.kind == ScaleKind::Log10 { v.max(1e-12).log10() } else { v };
+        let (x_span, y_span) = (old_view.x_max - old_view.x_min, ty(old_view.y_max) - ty(old_view.y_min));
+        let same = |a: f64, b: f64| (a - b).abs() <= 1e-9 * a.abs().max(1.0);
+        if !same(x_span, new_view.x_max - new_view.x_min) || !same(y_span, ty(new_view.y_max) - ty(new_view.y_min)) {
+            return Some(plot);
+        }
+        let dx = (new_view.x_min - old_view.x_min) / x_span * w;
+        let dy = (ty(new_view.y_min) - ty(old_view.y_min)) / y_span * h;
+        // One extra px covers antialiasing bleeding across the seam
+        let strip = |d: f64, extent: f64| (d.abs().ceil() as i32 + 1).min(extent as i32);
+        match (dx.abs() > 1e-6, dy.abs() > 1e-6) {
+            (true, false) if dx.abs() < w => {
+                let sw = strip(dx, w);
+                // Panning toward larger x reveals data on the right
+                let (l, r) = if dx > 0.0 { (plot.right - sw, plot.right) } else { (plot.left, plot.left + sw) };
+                Some(RectI32::from_ltrb(l, plot.top, r, opts.height))
+            }
+            (false, true) if dy.abs() < h => {
+                let sh = strip(dy, h);
+                // Larger y values enter at the top
+                let (t, b) = if dy > 0.0 { (plot.top, plot.top + sh) } else { (plot.bottom - sh, plot.bottom) };
+                Some(RectI32::from_ltrb(0, t, plot.right, b))
+            }
+            _ => Some(plot),
+        }
+    }
+
+    /// Left inset (logical px) needed to show the widest Y tick label plus padding at the current axis range.
+    pub fn required_left_inset(&self, opts: &RenderOptions) -> u32 {
+        let dpr = opts.dpr.max(0.5);
+        let text_size = 12.0 * dpr;
+        // Follow `opts`, not the frame: this also runs outside a render (e.g. `data_to_pixel`)
+        let shaper = if opts.deterministic { TextShaper::bundled() } else { TextShaper::new() };
+        let widest = y_tick_values(&self.y_axis)
+            .into_iter()
+            .filter(|v| v.is_finite())
+            .map(|v| shaper.measure_width(&y_tick_label(v, &self.y_axis), text_size, true))
+            .fold(0.0f32, f32::max);
+        // 8px gap to the axis (as drawn) plus 8px outer margin
+        (widest / dpr + 16.0).ceil() as u32
+    }
+
+    /// Find the series point nearest to (px, py) within `tolerance_px`.
+    /// XY series use euclidean pixel distance; candle/bar series use the nearest x,
+    /// provided the pixel lies within the candle's high-low extent (plus tolerance).
+    pub fn hit_test(&self, opts: &RenderOptions, px: f32, py: f32, tolerance_px: f32) -> Option<HitResult> {
+        let (ts, vs) = self.plot_scales(opts);
+        let mut best: Option<HitResult> = None;
+        let mut consider = |hit: HitResult| {
+            if hit.distance_px <= tolerance_px && best.is_none_or(|b| hit.distance_px < b.distance_px) {
+                best = Some(hit);
+            }
+        };
+        for (si, s) in self.series.iter().enumerate() {
+            match s.series_type {
+                SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline | SeriesType::Column => {
+                    for (i, &(x, y)) in s.data_xy.iter().enumerate() {
+                        let dx = ts.to_px(x) - px;
+                        let dy = vs.to_px(y) - py;
+                        consider(HitResult { series_index: si, point_index: i, x, y, distance_px: (dx * dx + dy * dy).sqrt() });
+                    }
+                }
+                SeriesType::Candlestick | SeriesType::Bar => {
+                    for (i, c) in s.data_ohlc.iter().enumerate() {
+                        let top = vs.to_px(c.h) - tolerance_px;
+                        let bot = vs.to_px(c.l) + tolerance_px;
+                        if py < top || py > bot { continue; }
+                        let dx = (ts.to_px(c.t) - px).abs();
+                        consider(HitResult { series_index: si, point_index: i, x: c.t, y: c.c, distance_px: dx });
+                    }
+                }
+                SeriesType::ErrorBar => {
+                    for (i, &(x, y, _)) in s.data_xyerr.iter().enumerate() {
+                        let dx = ts.to_px(x) - px;
+                        let dy = vs.to_px(y) - py;
+                        consider(HitResult { series_index: si, point_index: i, x, y, distance_px: (dx * dx + dy * dy).sqrt() });
+                    }
+                }
+                SeriesType::Band => {
+                    // either boundary can be hit; report the nearer edge value
+                    for (i, &(x, lo, hi)) in s.data_band.iter().enumerate() {
+                        for y in [lo, hi] {
+                            let dx = ts.to_px(x) - px;
+                            let dy = vs.to_px(y) - py;
+                            consider(HitResult { series_index: si, point_index: i, x, y, distance_px: (dx * dx + dy * dy).sqrt() });
+                        }
+                    }
+                }
+            }
+        }
+        best
+    }
+
+    fn plot_scales(&self, opts: &RenderOptions) -> (TimeScale, ValueScale) {
+        let plot = self.plot_rect(opts);
+        let (l, r, t, b) = (plot.left, plot.right, plot.top, plot.bottom);
+        let (x_axis, y_axis) = self.display_axes(opts);
+        let xspan = (x_axis.max - x_axis.min).max(1e-9);
+        let ts = TimeScale::new(l as f32, x_axis.min, ((r - l) as f32) / (xspan as f32));
+        let vs = match y_axis.kind {
+            ScaleKind::Linear => ValueScale::new_linear(t as f32, b as f32, y_axis.min, y_axis.max),
+            ScaleKind::Log10 => ValueScale::new_log10(t as f32, b as f32, y_axis.min, y_axis.max),
+        };
+        (ts, vs)
+    }
+
+    /// Axis ranges as rendered. With `opts.equal_axis_scale` the axis with more pixels per
+    /// data unit is widened about its center until both match, so the plot never crops
+    /// data; log axes are left as they are.
+    pub fn display_axes(&self, opts: &RenderOptions) -> (Axis, Axis) {
+        let (mut x_axis, mut y_axis) = (self.x_axis.clone(), self.y_axis.clone());
+        if !opts.equal_axis_scale || x_axis.kind != ScaleKind::Linear || y_axis.kind != ScaleKind::Linear {
+            return (x_axis, y_axis);
+        }
+        let plot = self.plot_rect(opts);
+        let (w, h) = ((plot.right - plot.left) as f64, (plot.bottom - plot.top) as f64);
+        // Horizontal orientation runs X down the left edge and Y along the bottom
+        let (x_px, y_px) = if opts.orientation == Orientation::Horizontal { (h, w) } else { (w, h) };
+        let xspan = x_axis.max - x_axis.min;
+        let yspan = y_axis.max - y_axis.min;
+        if !(x_px > 0.0 && y_px > 0.0 && xspan > 0.0 && yspan > 0.0) { return (x_axis, y_axis); }
+        let (sx, sy) = (x_px / xspan, y_px / yspan);
+        let widen = |a: &mut Axis, span: f64| {
+            let mid = (a.min + a.max) * 0.5;
+            a.min = mid - span * 0.5;
+            a.max = mid + span * 0.5;
+        };
+        if sx > sy {
+            widen(&mut x_axis, x_px / sy);
+        } else if sy > sx {
+            widen(&mut y_axis, y_px / sx);
+        }
+        (x_axis, y_axis)
+    }
+
+    /// Render the chart and return PNG-encoded bytes (headless), with `opts.png_metadata`
+    /// written as text chunks when set.
     pub fn render_to_png_bytes(&self, opts: &RenderOptions) -> Result<Vec<u8>> {
         let mut surface = skia::surfaces::raster_n32_premul((opts.width, opts.height))
             .ok_or_else(|| anyhow::anyhow!("failed to create raster surface"))?;
@@ -156,7 +816,10 @@ impl Chart {
         let data = image
             .encode_to_data(skia::EncodedImageFormat::PNG)
             .ok_or_else(|| anyhow::anyhow!("encode PNG failed"))?;
-        Ok(data.as_bytes().to_vec())
+        match &opts.png_metadata {
+            Some(meta) => insert_text_chunks(data.as_bytes(), &meta.entries(self.series.len())),
+            None => Ok(data.as_bytes().to_vec()),
+        }
     }
 
     /// Render the chart into a CPU RGBA8 buffer (row-major), suitable for window blitting.
@@ -182,67 +845,38 @@ impl Chart {
         Ok((pixels, opts.width, opts.height, row_bytes))
     }
 
+    /// Record the chart into a Skia picture covering `opts.width` x `opts.height`.
+    /// Replaying it (e.g. under a scale matrix for another DPR) skips the series and
+    /// overlay computation that a fresh render would repeat.
+    pub fn record_picture(&self, opts: &RenderOptions) -> Result<skia::Picture> {
+        let bounds = skia::Rect::from_wh(opts.width as f32, opts.height as f32);
+        let mut recorder = skia::PictureRecorder::new();
+        let canvas = recorder.begin_recording(bounds, None);
+        self.draw_into(canvas, opts);
+        recorder
+            .finish_recording_as_picture(None)
+            .ok_or_else(|| anyhow::anyhow!("picture recording failed"))
+    }
+
     fn draw_into(&self, canvas: &skia::Canvas, opts: &RenderOptions) {
+        let _frame = RenderFrame::enter(opts);
         // Background
         canvas.clear(opts.theme.background);
+        draw_background_image(canvas, opts);
 
         // Plot rect
-        let plot_left = opts.insets.left as i32;
-        let plot_right = opts.width - opts.insets.right as i32;
-        let plot_top = opts.insets.top as i32;
-        let plot_bottom = opts.height - opts.insets.bottom as i32;
-
-        // Grid & axes
-        draw_grid(canvas, plot_left, plot_top, plot_right, plot_bottom, opts.crisp_lines, &opts.theme);
-        draw_axes(
-            canvas,
-            plot_left,
-            plot_top,
-            plot_right,
-            plot_bottom,
-            &self.x_axis,
-            &self.y_axis,
-            opts.draw_labels,
-            opts.crisp_lines,
-            &opts.theme,
-            opts.dpr,
-        );
+        let plot = self.plot_rect(opts);
+        let (plot_left, plot_top, plot_right, plot_bottom) = (plot.left, plot.top, plot.right, plot.bottom);
+        let (x_axis, y_axis) = self.display_axes(opts);
 
-        // Series
-        for s in &self.series {
-            match s.series_type {
-                SeriesType::Line => draw_line_series(
-                    canvas, plot_left, plot_top, plot_right, plot_bottom, &self.x_axis, &self.y_axis, s, &opts.theme,
-                ),
-                SeriesType::Candlestick => draw_candle_series(
-                    canvas, plot_left, plot_top, plot_right, plot_bottom, &self.x_axis, &self.y_axis, s, &opts.theme,
-                ),
-                SeriesType::Bar => draw_bar_series(
-                    canvas, plot_left, plot_top, plot_right, plot_bottom, &self.x_axis, &self.y_axis, s, &opts.theme,
-                ),
-                SeriesType::Histogram => draw_histogram_series(
-                    canvas, plot_left, plot_top, plot_right, plot_bottom, &self.x_axis, &self.y_axis, s, &opts.theme,
-                ),
-                SeriesType::Baseline => draw_baseline_series(
-                    canvas, plot_left, plot_top, plot_right, plot_bottom, &self.x_axis, &self.y_axis, s, &opts.theme,
-                ),
-            }
+        if let Some(wm) = &opts.watermark {
+            draw_watermark(canvas, plot, wm, &opts.theme);
         }
 
-        // Overlays (computed)
-        if !self.overlays.is_empty() {
-            let mut overlay_theme = opts.theme;
-            overlay_theme.line_stroke = opts.theme.crosshair;
-            for ov in &self.overlays {
-                let computed = ov.compute(self);
-                for s in &computed {
-                    if matches!(s.series_type, SeriesType::Line) {
-                        draw_line_series(
-                            canvas, plot_left, plot_top, plot_right, plot_bottom, &self.x_axis, &self.y_axis, s, &overlay_theme,
-                        );
-                    }
-                }
-            }
+        if opts.orientation == Orientation::Horizontal {
+            self.draw_plot_horizontal(canvas, opts, plot, &x_axis, &y_axis);
+        } else {
+            self.draw_plot(canvas, opts, plot, &x_axis, &y_axis, true);
         }
 
         // Crosshair overlay (if provided)
@@ -253,33 +887,166 @@ impl Chart {
             paint.set_anti_alias(false);
             paint.set_style(skia::paint::Style::Stroke);
             paint.set_color(opts.theme.crosshair);
-            paint.set_stroke_width(1.0);
-            // horizontal
-            canvas.draw_line((plot_left as f32, iy), (plot_right as f32, iy), &paint);
-            // vertical
-            canvas.draw_line((ix, plot_top as f32), (ix, plot_bottom as f32), &paint);
+            paint.set_stroke_width(px(1.0));
+            if opts.crosshair_mode.horizontal() {
+                canvas.draw_line((plot_left as f32, iy), (plot_right as f32, iy), &paint);
+            }
+            if opts.crosshair_mode.vertical() {
+                canvas.draw_line((ix, plot_top as f32), (ix, plot_bottom as f32), &paint);
+            }
 
-            if opts.show_tooltip {
+            if opts.show_tooltip && opts.orientation == Orientation::Vertical {
                 draw_tooltip(
                     canvas,
                     plot_left, plot_top, plot_right, plot_bottom,
-                    &self.x_axis, &self.y_axis,
+                    &x_axis, &y_axis,
                     &self.series,
                     ix, iy,
                     opts,
                 );
             }
         }
+
+        if let Some(frame) = &opts.frame {
+            draw_frame(canvas, opts.width, opts.height, frame);
+        }
+    }
+
+    /// Horizontal-orientation plot: X categories run bottom-to-top on the left axis and
+    /// Y values along the bottom. Only bar-like series (Column, Histogram) are drawn.
+    fn draw_plot_horizontal(&self, canvas: &skia::Canvas, opts: &RenderOptions, plot: RectI32, x_axis: &Axis, y_axis: &Axis) {
+        let (l, t, r, b) = (plot.left, plot.top, plot.right, plot.bottom);
+        draw_grid(canvas, l, t, r, b, opts.crisp_lines, &opts.grid_theme());
+        // Axes swap roles: values label the bottom edge, categories the left edge
+        draw_axes(canvas, l, t, r, b, y_axis, x_axis, opts.draw_labels, true, opts.crisp_lines, &opts.theme, opts.dpr, opts.x_label_rotation_deg, opts.axis_line_width);
+        if opts.plot_border { draw_plot_box(canvas, l, t, r, b, opts.crisp_lines, &opts.theme, opts.axis_line_width); }
+
+        let cat = ValueScale::new_linear(t as f32, b as f32, x_axis.min, x_axis.max);
+        let yspan = (y_axis.max - y_axis.min).max(1e-9);
+        let val = TimeScale::new(l as f32, y_axis.min, ((r - l) as f32) / (yspan as f32));
+        let cat_px = |x: f64| -> f32 { cat.to_px(x) };
+        let val_px = |y: f64| -> f32 { val.to_px(y) };
+
+        let stack_bases = self.stack_bases();
+        for i in self.draw_order() {
+            let (s, bases) = (&self.series[i], &stack_bases[i]);
+            let color = match s.series_type {
+                SeriesType::Column => s.color.unwrap_or(opts.theme.histogram),
+                SeriesType::Histogram => opts.theme.histogram,
+                _ => continue,
+            };
+            if s.data_xy.is_empty() { continue; }
+            let thickness = column_width_px(s, &cat_px, (b - t) as f32);
+            let spans = stacked_spans(&s.data_xy, bases.as_deref(), s.baseline_value());
+            let rects = bar_rects(&spans, thickness, &cat_px, &val_px, Orientation::Horizontal);
+            fill_rects(canvas, &rects, scale_alpha(color, s.opacity), opts.gradient_fills);
+        }
+    }
+
+    /// Grid, axes, series and overlays inside `plot` over the given axis ranges.
+    /// Shared by single-chart rendering and stacked panels (which pass a common X axis).
+    pub(crate) fn draw_plot(&self, canvas: &skia::Canvas, opts: &RenderOptions, plot: RectI32, x_axis: &Axis, y_axis: &Axis, x_labels: bool) {
+        let (plot_left, plot_top, plot_right, plot_bottom) = (plot.left, plot.top, plot.right, plot.bottom);
+
+        // Grid & axes
+        if opts.show_minor_grid {
+            draw_minor_grid(canvas, plot_left, plot_top, plot_right, plot_bottom, x_axis, y_axis, opts.crisp_lines, &opts.grid_theme());
+        }
+        draw_grid(canvas, plot_left, plot_top, plot_right, plot_bottom, opts.crisp_lines, &opts.grid_theme());
+        draw_axes(
+            canvas,
+            plot_left,
+            plot_top,
+            plot_right,
+            plot_bottom,
+            x_axis,
+            y_axis,
+            opts.draw_labels,
+            x_labels,
+            opts.crisp_lines,
+            &opts.theme,
+            opts.dpr,
+            opts.x_label_rotation_deg,
+            opts.axis_line_width,
+        );
+        if opts.plot_border {
+            draw_plot_box(canvas, plot_left, plot_top, plot_right, plot_bottom, opts.crisp_lines, &opts.theme, opts.axis_line_width);
+        }
+
+        // Fills between series, clipped so interpolated edges can't spill over the axes
+        if !self.fills.is_empty() {
+            canvas.save();
+            canvas.clip_rect(skia::Rect::from_ltrb(plot_left as f32, plot_top as f32, plot_right as f32, plot_bottom as f32), None, None);
+            for fill in &self.fills {
+                draw_fill_between(
+                    canvas, plot_left, plot_top, plot_right, plot_bottom, x_axis, y_axis, &fill.polygon(&self.series), fill.color,
+                );
+            }
+            canvas.restore();
+        }
+
+        // Series
+        let stack_bases = self.stack_bases();
+        for i in self.draw_order() {
+            let (s, bases) = (&self.series[i], &stack_bases[i]);
+            // Translucent series draw through a layer so overlapping strokes don't darken
+            let faded = s.opacity < 1.0;
+            if faded { canvas.save_layer_alpha_f(None, s.opacity.max(0.0)); }
+            draw_series(canvas, plot, x_axis, y_axis, s, bases.as_deref(), &opts.theme, opts);
+            if faded { canvas.restore(); }
+        }
+
+        // Overlays (computed): any series type, then their labels
+        for ov in self.main_overlays() {
+            for s in &ov.compute(self) {
+                let faded = s.opacity < 1.0;
+                if faded { canvas.save_layer_alpha_f(None, s.opacity.max(0.0)); }
+                draw_series(canvas, plot, x_axis, y_axis, s, None, &overlay_theme(s, &opts.theme), opts);
+                if faded { canvas.restore(); }
+            }
+            if opts.draw_labels {
+                for label in ov.labels(self) {
+                    draw_overlay_label(canvas, plot, x_axis, y_axis, &label, opts);
+                }
+            }
+        }
+
+        if opts.last_value_line {
+            if let Some((value, color)) = self.last_value_marker(&opts.theme) {
+                draw_last_value_line(canvas, plot_left, plot_top, plot_right, plot_bottom, y_axis, value, color, opts);
+            }
+        }
+    }
+
+    /// Value and color for `RenderOptions::last_value_line`: the first series' last value,
+    /// colored by the last candle's direction for OHLC data and the line color otherwise.
+    fn last_value_marker(&self, theme: &Theme) -> Option<(f64, skia::Color)> {
+        let s = self.series.first()?;
+        let value = s.last_value()?;
+        let color = match (s.series_type, s.data_ohlc.last()) {
+            (SeriesType::Candlestick | SeriesType::Bar, Some(c)) if c.c < c.o => theme.candle_down,
+            (SeriesType::Candlestick | SeriesType::Bar, _) => theme.candle_up,
+            _ => theme.line_stroke,
+        };
+        Some((value, color))
     }
 
-    /// Export the chart as an SVG file. Current implementation embeds a PNG as a data URI
-    /// to ensure deterministic output without vectorization differences. This serves as a
-    /// practical first step; a pure-vector SVG path can be added later.
+    /// Export the chart as an SVG file (see `render_to_svg_string` for the markup).
     pub fn render_to_svg(
         &self,
         opts: &RenderOptions,
         output_svg_path: impl AsRef<std::path::Path>,
     ) -> Result<()> {
+        let svg = self.render_to_svg_string(opts)?;
+        let path = output_svg_path.as_ref();
+        if let Some(parent) = path.parent() { std::fs::create_dir_all(parent)?; }
+        std::fs::write(path, svg)?;
+        Ok(())
+    }
+
+    /// Render the chart as SVG markup without touching disk, e.g. to stream from a server.
+    /// Plot layout matches the raster path (same plot rect and scales).
+    pub fn render_to_svg_string(&self, opts: &RenderOptions) -> Result<String> {
         fn color_to_rgba(c: skia::Color) -> (u8, u8, u8, u8) {
             (c.r(), c.g(), c.b(), c.a())
         }
@@ -291,22 +1058,43 @@ impl Chart {
             let (_r, _g, _b, a) = color_to_rgba(c);
             format!("{:.3}", (a as f32) / 255.0)
         }
-
         let w = opts.width.max(1) as i32;
         let h = opts.height.max(1) as i32;
-        let l = opts.insets.left as i32;
-        let rpx = w - opts.insets.right as i32;
-        let t = opts.insets.top as i32;
-        let bpx = h - opts.insets.bottom as i32;
+        let plot = self.plot_rect(opts);
+        let (l, rpx, t, bpx) = (plot.left, plot.right, plot.top, plot.bottom);
+        let (x_axis, y_axis) = self.display_axes(opts);
         let crisp = opts.crisp_lines;
         let align = |v: f32| if crisp { v.floor() + 0.5 } else { v };
+        // Stroke widths and dot radii are logical px, scaled by DPR like the raster path
+        let dpr = opts.dpr.max(0.5);
+        let (sw1, sw2) = (dpr, 2.0 * dpr);
 
         let mut out = String::new();
+        let aria = if opts.accessible_svg { " role=\"img\" aria-labelledby=\"chart-title chart-desc\"" } else { "" };
         out.push_str(&format!(
-            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n",
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\"{aria}>\n",
             w = w,
             h = h
         ));
+        if opts.accessible_svg {
+            // Screen readers announce the title, then the description
+            let title = match &opts.png_metadata {
+                Some(meta) if !meta.title.is_empty() => meta.title.clone(),
+                _ => format!("{} vs {}", y_axis.label, x_axis.label),
+            };
+            let desc = format!(
+                "Chart with {n} series. {xl} axis from {x0} to {x1}. {yl} axis from {y0} to {y1}.",
+                n = self.series.len(),
+                xl = x_axis.label,
+                x0 = x_axis.format_value(x_axis.min),
+                x1 = x_axis.format_value(x_axis.max),
+                yl = y_axis.label,
+                y0 = y_axis.format_value(y_axis.min),
+                y1 = y_axis.format_value(y_axis.max),
+            );
+            out.push_str(&format!("  <title id=\"chart-title\">{}</title>\n", xml_escape(&title)));
+            out.push_str(&format!("  <desc id=\"chart-desc\">{}</desc>\n", xml_escape(&desc)));
+        }
         // Background
         out.push_str(&format!(
             "  <rect x=\"0\" y=\"0\" width=\"{w}\" height=\"{h}\" fill=\"{}\" fill-opacity=\"{}\" />\n",
@@ -315,11 +1103,84 @@ impl Chart {
             w = w,
             h = h
         ));
+        if let Some(bytes) = &opts.background_image {
+            let mime = if bytes.starts_with(b"\x89PNG") { "image/png" } else { "image/jpeg" };
+            let href = format!("data:{mime};base64,{}", base64_encode(bytes));
+            match opts.background_fit {
+                BackgroundFit::Stretch => out.push_str(&format!(
+                    "  <image id=\"background-image\" x=\"0\" y=\"0\" width=\"{w}\" height=\"{h}\" preserveAspectRatio=\"none\" href=\"{href}\" />\n"
+                )),
+                BackgroundFit::Tile => {
+                    // Pattern cell is the image's pixel size; skip if it cannot be decoded
+                    if let Some(image) = skia::Image::from_encoded(skia::Data::new_copy(bytes)) {
+                        out.push_str(&format!(
+                            "  <pattern id=\"background-tile\" patternUnits=\"userSpaceOnUse\" width=\"{iw}\" height=\"{ih}\"><image width=\"{iw}\" height=\"{ih}\" href=\"{href}\" /></pattern>\n  <rect id=\"background-image\" x=\"0\" y=\"0\" width=\"{w}\" height=\"{h}\" fill=\"url(#background-tile)\" />\n",
+                            iw = image.width(),
+                            ih = image.height()
+                        ));
+                    }
+                }
+            }
+        }
+
+        // Watermark (behind everything but the background)
+        if let Some(wm) = &opts.watermark {
+            let fs = wm.font_size((rpx - l) as f32, (bpx - t) as f32);
+            out.push_str(&format!(
+                "  <text id=\"watermark\" x=\"{x}\" y=\"{y}\" fill=\"{col}\" fill-opacity=\"{op:.3}\" font-size=\"{fs}\" font-weight=\"bold\" text-anchor=\"middle\" dominant-baseline=\"central\">{text}</text>\n",
+                x = (l + rpx) as f32 * 0.5,
+                y = (t + bpx) as f32 * 0.5,
+                col = color_hex_rgb(opts.theme.axis_label),
+                op = wm.opacity.clamp(0.0, 1.0),
+                fs = fs,
+                text = xml_escape(&wm.text)
+            ));
+        }
+
+        // Minor grid (below major grid)
+        if opts.show_minor_grid {
+            let minor_col = minor_grid_color(opts.grid_theme().grid);
+            let col = color_hex_rgb(minor_col);
+            let op = color_opacity(minor_col);
+            let xspan = (x_axis.max - x_axis.min).max(1e-9);
+            let ts = TimeScale::new(l as f32, x_axis.min, ((rpx - l) as f32) / (xspan as f32));
+            let vs = match y_axis.kind {
+                ScaleKind::Linear => ValueScale::new_linear(t as f32, bpx as f32, y_axis.min, y_axis.max),
+                ScaleKind::Log10 => ValueScale::new_log10(t as f32, bpx as f32, y_axis.min, y_axis.max),
+            };
+            out.push_str(&format!("  <g id=\"minor-grid\" stroke-linecap=\"butt\" stroke-width=\"{sw1}\" fill=\"none\">\n"));
+            for vx in minor_tick_values_x(&x_axis) {
+                let xf = align(ts.to_px(vx));
+                if xf < l as f32 || xf > rpx as f32 { continue; }
+                out.push_str(&format!(
+                    "    <line x1=\"{x}\" y1=\"{y1}\" x2=\"{x}\" y2=\"{y2}\" stroke=\"{col}\" stroke-opacity=\"{op}\" />\n",
+                    x = xf,
+                    y1 = t,
+                    y2 = bpx,
+                    col = col,
+                    op = op
+                ));
+            }
+            for vy in minor_tick_values_y(&y_axis) {
+                let yf = align(vs.to_px(vy));
+                if yf < t as f32 || yf > bpx as f32 { continue; }
+                out.push_str(&format!(
+                    "    <line x1=\"{x1}\" y1=\"{y}\" x2=\"{x2}\" y2=\"{y}\" stroke=\"{col}\" stroke-opacity=\"{op}\" />\n",
+                    x1 = l,
+                    x2 = rpx,
+                    y = yf,
+                    col = col,
+                    op = op
+                ));
+            }
+            out.push_str("  </g>\n");
+        }
 
         // Grid
-        out.push_str("  <g id=\"grid\" stroke-linecap=\"butt\" stroke-width=\"1\" fill=\"none\">\n");
-        let grid_col = color_hex_rgb(opts.theme.grid);
-        let grid_op = color_opacity(opts.theme.grid);
+        out.push_str(&format!("  <g id=\"grid\" stroke-linecap=\"butt\" stroke-width=\"{sw1}\" fill=\"none\">\n"));
+        let grid_color = opts.grid_theme().grid;
+        let grid_col = color_hex_rgb(grid_color);
+        let grid_op = color_opacity(grid_color);
         for x in linspace(l as f64, rpx as f64, 10) {
             let xf = align(x as f32);
             out.push_str(&format!(
@@ -349,10 +1210,25 @@ impl Chart {
         let axis_op = color_opacity(opts.theme.axis_line);
         let bx = align(bpx as f32);
         let lx = align(l as f32);
+        // Per-axis color overrides go on the individual lines
+        let line_stroke = |c: Option<skia::Color>| match c {
+            Some(c) => format!(" stroke=\"{}\" stroke-opacity=\"{}\"", color_hex_rgb(c), color_opacity(c)),
+            None => String::new(),
+        };
+        // plot_border closes the box with top and right edges inside the axes group
+        let border = if opts.plot_border {
+            let (ty, rx) = (align(t as f32), align(rpx as f32));
+            format!("    <line x1=\"{l}\" y1=\"{ty}\" x2=\"{rpx}\" y2=\"{ty}\" />\n    <line x1=\"{rx}\" y1=\"{t}\" x2=\"{rx}\" y2=\"{bpx}\" />\n")
+        } else {
+            String::new()
+        };
         out.push_str(&format!(
-            "  <g id=\"axes\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"1.5\" fill=\"none\">\n    <line x1=\"{l}\" y1=\"{bx}\" x2=\"{r}\" y2=\"{bx}\" />\n    <line x1=\"{lx}\" y1=\"{t}\" x2=\"{lx}\" y2=\"{b}\" />\n  </g>\n",
+            "  <g id=\"axes\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"{sw}\" fill=\"none\">\n    <line x1=\"{l}\" y1=\"{bx}\" x2=\"{r}\" y2=\"{bx}\"{xs} />\n    <line x1=\"{lx}\" y1=\"{t}\" x2=\"{lx}\" y2=\"{b}\"{ys} />\n{border}  </g>\n",
             col = axis_col,
             op = axis_op,
+            sw = opts.axis_line_width * opts.dpr.max(0.5),
+            xs = line_stroke(x_axis.line_color),
+            ys = line_stroke(y_axis.line_color),
             l = l,
             r = rpx,
             t = t,
@@ -372,7 +1248,7 @@ impl Chart {
                 "  <text x=\"{}\" y=\"{}\" fill=\"{col}\" fill-opacity=\"{op}\" font-size=\"{fs}\">{}</text>\n",
                 rpx as f32 - 80.0 * opts.dpr,
                 bpx as f32 + 28.0 * opts.dpr,
-                self.x_axis.label,
+                x_axis.label,
                 col = text_fill,
                 op = text_op,
                 fs = text_size
@@ -381,25 +1257,20 @@ impl Chart {
                 "  <text x=\"{}\" y=\"{}\" fill=\"{col}\" fill-opacity=\"{op}\" font-size=\"{fs}\">{}</text>\n",
                 l as f32 + 8.0 * opts.dpr,
                 t as f32 + 14.0 * opts.dpr,
-                self.y_axis.label,
+                y_axis.label,
                 col = text_fill,
                 op = text_op,
                 fs = text_size
             ));
 
             let target_xticks = 8usize;
-            let target_yticks = 6usize;
-            let xticks = nice_ticks(self.x_axis.min, self.x_axis.max, target_xticks.max(2));
-            let yticks = if self.y_axis.kind == ScaleKind::Log10 {
-                log_ticks(self.y_axis.min.max(1e-12), self.y_axis.max, target_yticks.max(2))
-            } else {
-                nice_ticks(self.y_axis.min, self.y_axis.max, target_yticks.max(2))
-            };
-            let xspan = (self.x_axis.max - self.x_axis.min).max(1e-9);
-            let ts = TimeScale::new(l as f32, self.x_axis.min, ((rpx - l) as f32) / (xspan as f32));
-            let vs = match self.y_axis.kind {
-                ScaleKind::Linear => ValueScale::new_linear(t as f32, bpx as f32, self.y_axis.min, self.y_axis.max),
-                ScaleKind::Log10 => ValueScale::new_log10(t as f32, bpx as f32, self.y_axis.min, self.y_axis.max),
+            let xticks = nice_ticks(x_axis.min, x_axis.max, target_xticks.max(2));
+            let yticks = y_tick_values(&y_axis);
+            let xspan = (x_axis.max - x_axis.min).max(1e-9);
+            let ts = TimeScale::new(l as f32, x_axis.min, ((rpx - l) as f32) / (xspan as f32));
+            let vs = match y_axis.kind {
+                ScaleKind::Linear => ValueScale::new_linear(t as f32, bpx as f32, y_axis.min, y_axis.max),
+                ScaleKind::Log10 => ValueScale::new_log10(t as f32, bpx as f32, y_axis.min, y_axis.max),
             };
             let sx = |vx: f64| -> f32 { ts.to_px(vx) };
             let sy = |vy: f64| -> f32 { vs.to_px(vy) };
@@ -407,46 +1278,61 @@ impl Chart {
             let tick_col = color_hex_rgb(opts.theme.tick);
             let tick_op = color_opacity(opts.theme.tick);
             out.push_str("  <g id=\"ticks\" fill=\"none\">\n");
-            // X major ticks and labels
-            for vx in xticks {
-                if !vx.is_finite() { continue; }
-                let xpx = align(sx(vx));
+            // X major ticks and labels (thinned like the raster path)
+            let xs: Vec<(f32, String)> =
+                xticks.into_iter().filter(|vx| vx.is_finite()).map(|vx| (align(sx(vx)), x_tick_label(vx, &x_axis))).collect();
+            let shaper = text_shaper();
+            let advances: Vec<f32> = xs.iter().map(|(_, label)| shaper.measure_width(label, text_size, true)).collect();
+            let xpxs: Vec<f32> = xs.iter().map(|(xpx, _)| *xpx).collect();
+            let stride = x_label_stride(&xpxs, &advances, text_size, opts.x_label_rotation_deg, opts.dpr);
+            for (i, (xpx, label)) in xs.into_iter().enumerate() {
                 out.push_str(&format!(
-                    "    <line x1=\"{x}\" y1=\"{by}\" x2=\"{x}\" y2=\"{y2}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"1\" />\n",
+                    "    <line x1=\"{x}\" y1=\"{by}\" x2=\"{x}\" y2=\"{y2}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"{sw1}\" />\n",
                     x = xpx,
                     by = bx,
                     y2 = bx - 6.0 * opts.dpr,
                     col = tick_col,
                     op = tick_op
                 ));
-                let label = if detect_time_like(self.x_axis.min, self.x_axis.max).is_some() {
-                    format_time_tick(vx, self.x_axis.min, self.x_axis.max)
+                if i % stride != 0 { continue; }
+                let rot = opts.x_label_rotation_deg;
+                if rot == 0.0 {
+                    out.push_str(&format!(
+                        "    <text x=\"{x}\" y=\"{y}\" fill=\"{col}\" fill-opacity=\"{op}\" font-size=\"{fs}\" text-anchor=\"middle\">{label}</text>\n",
+                        x = xpx,
+                        y = bpx as f32 + 18.0 * opts.dpr,
+                        col = text_fill,
+                        op = text_op,
+                        fs = text_size,
+                        label = label
+                    ));
                 } else {
-                    format_tick(vx, self.x_axis.min, self.x_axis.max)
-                };
-                out.push_str(&format!(
-                    "    <text x=\"{x}\" y=\"{y}\" fill=\"{col}\" fill-opacity=\"{op}\" font-size=\"{fs}\" text-anchor=\"middle\">{label}</text>\n",
-                    x = xpx,
-                    y = bpx as f32 + 18.0 * opts.dpr,
-                    col = text_fill,
-                    op = text_op,
-                    fs = text_size,
-                    label = label
-                ));
+                    // Anchor the label end (rising text) or start (falling text) at the tick
+                    let (ax, ay, _) = rotated_x_label_anchor(xpx, bpx as f32, 0.0, rot, opts.dpr);
+                    out.push_str(&format!(
+                        "    <text x=\"{ax}\" y=\"{y}\" fill=\"{col}\" fill-opacity=\"{op}\" font-size=\"{fs}\" text-anchor=\"{anchor}\" transform=\"rotate({rot} {ax} {ay})\">{label}</text>\n",
+                        y = ay + 4.0 * opts.dpr,
+                        col = text_fill,
+                        op = text_op,
+                        fs = text_size,
+                        anchor = if rot < 0.0 { "end" } else { "start" },
+                        label = label
+                    ));
+                }
             }
             // Y major ticks and labels
             for vy in yticks {
                 if !vy.is_finite() { continue; }
                 let ypx = align(sy(vy));
                 out.push_str(&format!(
-                    "    <line x1=\"{x1}\" y1=\"{y}\" x2=\"{x2}\" y2=\"{y}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"1\" />\n",
+                    "    <line x1=\"{x1}\" y1=\"{y}\" x2=\"{x2}\" y2=\"{y}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"{sw1}\" />\n",
                     x1 = lx,
                     x2 = lx + 6.0 * opts.dpr,
                     y = ypx,
                     col = tick_col,
                     op = tick_op
                 ));
-                let label = if self.y_axis.kind == ScaleKind::Log10 { format_log_tick(vy) } else { format_tick(vy, self.y_axis.min, self.y_axis.max) };
+                let label = y_tick_label(vy, &y_axis);
                 out.push_str(&format!(
                     "    <text x=\"{x}\" y=\"{y}\" fill=\"{col}\" fill-opacity=\"{op}\" font-size=\"{fs}\" text-anchor=\"end\">{label}</text>\n",
                     x = l as f32 - 8.0 * opts.dpr,
@@ -461,41 +1347,92 @@ impl Chart {
         }
 
         // Series
-        let xspan = (self.x_axis.max - self.x_axis.min).max(1e-9);
-        let ts = TimeScale::new(l as f32, self.x_axis.min, ((rpx - l) as f32) / (xspan as f32));
-        let vs = match self.y_axis.kind {
-            ScaleKind::Linear => ValueScale::new_linear(t as f32, bpx as f32, self.y_axis.min, self.y_axis.max),
-            ScaleKind::Log10 => ValueScale::new_log10(t as f32, bpx as f32, self.y_axis.min, self.y_axis.max),
+        let xspan = (x_axis.max - x_axis.min).max(1e-9);
+        let ts = TimeScale::new(l as f32, x_axis.min, ((rpx - l) as f32) / (xspan as f32));
+        let vs = match y_axis.kind {
+            ScaleKind::Linear => ValueScale::new_linear(t as f32, bpx as f32, y_axis.min, y_axis.max),
+            ScaleKind::Log10 => ValueScale::new_log10(t as f32, bpx as f32, y_axis.min, y_axis.max),
         };
         let sx = |vx: f64| -> f32 { ts.to_px(vx) };
         let sy = |vy: f64| -> f32 { vs.to_px(vy) };
 
-        out.push_str("  <g id=\"series\" fill=\"none\" stroke-linejoin=\"round\" stroke-linecap=\"round\">\n");
-        for s in &self.series {
+        if !self.fills.is_empty() {
+            out.push_str(&format!(
+                "  <clipPath id=\"plot-clip\"><rect x=\"{x}\" y=\"{y}\" width=\"{w}\" height=\"{h}\" /></clipPath>\n",
+                x = l, y = t, w = rpx - l, h = bpx - t
+            ));
+            out.push_str("  <g id=\"fills\" clip-path=\"url(#plot-clip)\" stroke=\"none\">\n");
+            for fill in &self.fills {
+                let poly = fill.polygon(&self.series);
+                if poly.is_empty() { continue; }
+                let mut d = String::new();
+                for (i, &(xv, yv)) in poly.iter().enumerate() {
+                    d.push_str(&format!("{}{} {}", if i == 0 { "M " } else { " L " }, sx(xv), sy(yv)));
+                }
+                out.push_str(&format!(
+                    "    <path d=\"{d} Z\" fill=\"{col}\" fill-opacity=\"{op}\" />\n",
+                    d = d, col = color_hex_rgb(fill.color), op = color_opacity(fill.color)
+                ));
+            }
+            out.push_str("  </g>\n");
+        }
+
+        // Markup for one series (base or overlay); `bases` are stacking bases when stacked
+        let series_svg = |s: &Series, bases: Option<&[f64]>, theme: &Theme| -> String {
+            let mut out = String::new();
+            // Series opacity composes with each color's own alpha
+            let color_opacity = |c: skia::Color| format!("{:.3}", (c.a() as f32) / 255.0 * s.opacity.clamp(0.0, 1.0));
             match s.series_type {
                 SeriesType::Line => {
                     if s.data_xy.len() >= 2 {
-                        let stroke = color_hex_rgb(opts.theme.line_stroke);
-                        let sop = color_opacity(opts.theme.line_stroke);
-                        let mut d = String::new();
-                        let (x0, y0) = (sx(s.data_xy[0].0), sy(s.data_xy[0].1));
-                        d.push_str(&format!("M {} {}", x0, y0));
-                        for &(xv, yv) in s.data_xy.iter().skip(1) {
-                            d.push_str(&format!(" L {} {}", sx(xv), sy(yv)));
+                        // One M...L run per gap-free segment; threshold colors split runs into two paths
+                        let runs = match s.threshold_color {
+                            Some((th, _, _)) => s.threshold_runs(th),
+                            None => s.line_runs().into_iter().map(|run| (true, run)).collect(),
+                        };
+                        let mut ds = [String::new(), String::new()]; // [above (or whole line), below]
+                        for (above, run) in runs {
+                            let d = &mut ds[usize::from(!above)];
+                            for (i, &(xv, yv)) in run.iter().enumerate() {
+                                let cmd = if i == 0 { if d.is_empty() { "M" } else { " M" } } else { " L" };
+                                d.push_str(&format!("{} {} {}", cmd, sx(xv), sy(yv)));
+                            }
+                        }
+                        let (above, below) = s.threshold_color.map_or((theme.line_stroke, theme.line_stroke), |(_, a, b)| (a, b));
+                        for (d, c) in ds.iter().zip([above, below]) {
+                            if d.is_empty() { continue; }
+                            out.push_str(&format!("    <path d=\"{d}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"{sw2}\" />\n", d = d, col = color_hex_rgb(c), op = color_opacity(c)));
                         }
-                        out.push_str(&format!("    <path d=\"{d}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"2\" />\n", d = d, col = stroke, op = sop));
+                    }
+                    if s.show_markers {
+                        let fill = color_hex_rgb(theme.line_stroke);
+                        let fop = color_opacity(theme.line_stroke);
+                        let m = MARKER_RADIUS * dpr;
+                        out.push_str(&format!("    <g class=\"markers\" fill=\"{fill}\" fill-opacity=\"{fop}\" stroke=\"none\">\n"));
+                        for &(xv, yv) in s.data_xy.iter().filter(|p| p.0.is_finite() && p.1.is_finite()) {
+                            let (cx, cy) = (sx(xv), sy(yv));
+                            out.push_str(&match s.marker_shape {
+                                MarkerShape::Circle => format!("      <circle cx=\"{cx}\" cy=\"{cy}\" r=\"{m}\" />\n"),
+                                MarkerShape::Square => format!("      <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" />\n", cx - m, cy - m, 2.0 * m, 2.0 * m),
+                                MarkerShape::Diamond => format!(
+                                    "      <polygon points=\"{},{} {},{} {},{} {},{}\" />\n",
+                                    cx, cy - m, cx + m, cy, cx, cy + m, cx - m, cy
+                                ),
+                            });
+                        }
+                        out.push_str("    </g>\n");
                     }
                 }
                 SeriesType::Histogram => {
                     if !s.data_xy.is_empty() {
                         let base = s.baseline.unwrap_or(0.0);
                         let y0 = sy(base);
-                        let fill = color_hex_rgb(opts.theme.histogram);
-                        let fop = color_opacity(opts.theme.histogram);
-                        let mut wpx = ((rpx - l) as f32) / (s.data_xy.len().max(1) as f32) * 0.8;
+                        let fill = color_hex_rgb(theme.histogram);
+                        let fop = color_opacity(theme.histogram);
+                        let mut wpx = ((rpx - l) as f32) / (s.data_xy.len().max(1) as f32) * (1.0 - s.bar_gap_frac) as f32;
                         if wpx < 1.0 { wpx = 1.0; }
                         for &(xv, yv) in &s.data_xy {
-                            let cx = sx(xv);
+                            let cx = sx(xv) + bar_align_shift(s.bar_align, wpx);
                             let yy = sy(yv);
                             let (ymin, ymax) = if yy < y0 { (yy, y0) } else { (y0, yy) };
                             out.push_str(&format!(
@@ -510,23 +1447,110 @@ impl Chart {
                         }
                     }
                 }
+                SeriesType::Column => {
+                    let color = s.color.unwrap_or(theme.histogram);
+                    let fill = color_hex_rgb(color);
+                    let fop = color_opacity(color);
+                    let wpx = column_width_px(s, &sx, (rpx - l) as f32);
+                    for (xv, from, to) in stacked_spans(&s.data_xy, bases, s.baseline_value()) {
+                        let (y0, yy) = (sy(from), sy(to));
+                        let (ymin, ymax) = if yy < y0 { (yy, y0) } else { (y0, yy) };
+                        out.push_str(&format!(
+                            "    <rect x=\"{x}\" y=\"{y}\" width=\"{w}\" height=\"{h}\" fill=\"{col}\" fill-opacity=\"{op}\" />\n",
+                            x = sx(xv) - wpx * 0.5,
+                            y = ymin,
+                            w = wpx,
+                            h = (ymax - ymin).max(1.0),
+                            col = fill,
+                            op = fop
+                        ));
+                    }
+                }
+                SeriesType::ErrorBar => {
+                    let stroke = color_hex_rgb(theme.line_stroke);
+                    let sop = color_opacity(theme.line_stroke);
+                    for &(xv, yv, e) in &s.data_xyerr {
+                        let x = sx(xv);
+                        let (y_top, y_bot) = (sy(yv + e.abs()), sy(yv - e.abs()));
+                        let mut d = format!("M {} {} L {} {}", x, y_top, x, y_bot);
+                        for yc in [y_top, y_bot] {
+                            d.push_str(&format!(" M {} {} L {} {}", x - ERROR_CAP_HALF, yc, x + ERROR_CAP_HALF, yc));
+                        }
+                        out.push_str(&format!("    <path d=\"{d}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"{sw1}\" fill=\"none\" />\n", d = d, col = stroke, op = sop));
+                        out.push_str(&format!("    <circle cx=\"{x}\" cy=\"{y}\" r=\"{r}\" fill=\"{col}\" fill-opacity=\"{op}\" />\n", x = x, y = sy(yv), r = 3.0 * dpr, col = stroke, op = sop));
+                    }
+                }
+                SeriesType::Band => {
+                    if s.data_band.len() >= 2 {
+                        let d = band_area_svg_path(&s.data_band, &sx, &sy);
+                        out.push_str(&format!(
+                            "    <path d=\"{d}\" fill=\"{col}\" fill-opacity=\"{op}\" />\n",
+                            d = d,
+                            col = color_hex_rgb(theme.band_fill),
+                            op = color_opacity(theme.band_fill)
+                        ));
+                        if s.band_strokes {
+                            let stroke = color_hex_rgb(theme.band_stroke);
+                            let sop = color_opacity(theme.band_stroke);
+                            for upper in [false, true] {
+                                let mut d = String::new();
+                                for (i, &(xv, lo, hi)) in s.data_band.iter().enumerate() {
+                                    let yv = if upper { hi } else { lo };
+                                    d.push_str(&format!("{}{} {}", if i == 0 { "M " } else { " L " }, sx(xv), sy(yv)));
+                                }
+                                out.push_str(&format!("    <path d=\"{d}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"{sw1}\" fill=\"none\" />\n", d = d, col = stroke, op = sop));
+                            }
+                        }
+                    }
+                }
                 SeriesType::Baseline => {
                     if s.data_xy.len() >= 2 {
                         let base = s.baseline.unwrap_or(0.0);
                         let y0 = sy(base);
-                        let stroke = color_hex_rgb(opts.theme.baseline_stroke);
-                        let sop = color_opacity(opts.theme.baseline_stroke);
-                        let fill = color_hex_rgb(opts.theme.baseline_fill);
-                        let fop = color_opacity(opts.theme.baseline_fill);
-                        let mut d = String::new();
-                        d.push_str(&format!("M {} {}", sx(s.data_xy[0].0), y0));
-                        for &(xv, yv) in &s.data_xy { d.push_str(&format!(" L {} {}", sx(xv), sy(yv))); }
-                        d.push_str(&format!(" L {} {} Z", sx(s.data_xy.last().unwrap().0), y0));
-                        out.push_str(&format!("    <path d=\"{d}\" fill=\"{col}\" fill-opacity=\"{op}\" />\n", d = d, col = fill, op = fop));
-                        let mut d2 = String::new();
-                        d2.push_str(&format!("M {} {}", sx(s.data_xy[0].0), sy(s.data_xy[0].1)));
-                        for &(xv, yv) in s.data_xy.iter().skip(1) { d2.push_str(&format!(" L {} {}", sx(xv), sy(yv))); }
-                        out.push_str(&format!("    <path d=\"{d}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"2\" fill=\"none\" />\n", d = d2, col = stroke, op = sop));
+                        let stroke = color_hex_rgb(theme.baseline_stroke);
+                        let sop = color_opacity(theme.baseline_stroke);
+                        let fill_color = s.color.unwrap_or(theme.baseline_fill);
+                        let fill = color_hex_rgb(fill_color);
+                        let fop = color_opacity(fill_color);
+                        let (d, d2) = if let Some(b) = bases {
+                            // Stacked layer: band between the running total and this layer's top
+                            let spans = stacked_spans(&s.data_xy, Some(b), base);
+                            let d = band_area_svg_path(&spans, &sx, &sy);
+                            let mut d2 = String::new();
+                            for (i, &(xv, _, top)) in spans.iter().enumerate() {
+                                d2.push_str(&format!("{}{} {}", if i == 0 { "M " } else { " L " }, sx(xv), sy(top)));
+                            }
+                            (d, d2)
+                        } else {
+                            let mut d = String::new();
+                            d.push_str(&format!("M {} {}", sx(s.data_xy[0].0), y0));
+                            for &(xv, yv) in &s.data_xy { d.push_str(&format!(" L {} {}", sx(xv), sy(yv))); }
+                            d.push_str(&format!(" L {} {} Z", sx(s.data_xy.last().unwrap().0), y0));
+                            let mut d2 = String::new();
+                            d2.push_str(&format!("M {} {}", sx(s.data_xy[0].0), sy(s.data_xy[0].1)));
+                            for &(xv, yv) in s.data_xy.iter().skip(1) { d2.push_str(&format!(" L {} {}", sx(xv), sy(yv))); }
+                            (d, d2)
+                        };
+                        if bases.is_none() && s.color.is_none() && theme.baseline_fill_neg != theme.baseline_fill {
+                            // Two-color area: one polygon per side, split where the line crosses the baseline
+                            let pts: Vec<(f32, f32)> = s.data_xy.iter().map(|&(xv, yv)| (sx(xv), sy(yv))).collect();
+                            for (above, color) in [(true, theme.baseline_fill), (false, theme.baseline_fill_neg)] {
+                                let side = baseline_side_polygon(&pts, y0, above);
+                                let mut d = String::new();
+                                for (i, (x, y)) in side.iter().enumerate() {
+                                    d.push_str(&format!("{}{} {}", if i == 0 { "M " } else { " L " }, x, y));
+                                }
+                                out.push_str(&format!(
+                                    "    <path class=\"{class}\" d=\"{d} Z\" fill=\"{col}\" fill-opacity=\"{op}\" />\n",
+                                    class = if above { "baseline-pos" } else { "baseline-neg" },
+                                    col = color_hex_rgb(color),
+                                    op = color_opacity(color)
+                                ));
+                            }
+                        } else {
+                            out.push_str(&format!("    <path d=\"{d}\" fill=\"{col}\" fill-opacity=\"{op}\" />\n", d = d, col = fill, op = fop));
+                        }
+                        out.push_str(&format!("    <path d=\"{d}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"{sw2}\" fill=\"none\" />\n", d = d2, col = stroke, op = sop));
                     }
                 }
                 SeriesType::Candlestick | SeriesType::Bar => {
@@ -534,33 +1558,56 @@ impl Chart {
                         let n = s.data_ohlc.len();
                         let mut wpx = ((rpx - l) as f32) / (n.max(1) as f32) * 0.6;
                         if wpx < 1.0 { wpx = 1.0; }
-                        for c in &s.data_ohlc {
+                        for (i, c) in s.data_ohlc.iter().enumerate() {
                             let x = sx(c.t);
                             let y_o = sy(c.o);
                             let y_c = sy(c.c);
                             let y_h = sy(c.h);
                             let y_l = sy(c.l);
                             let up = c.c >= c.o;
-                            let col = if up { opts.theme.candle_up } else { opts.theme.candle_down };
-                            let stroke = color_hex_rgb(col);
-                            let sop = color_opacity(col);
-                            out.push_str(&format!(
-                                "    <line x1=\"{x}\" y1=\"{y1}\" x2=\"{x}\" y2=\"{y2}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"1\" />\n",
-                                x = x,
-                                y1 = y_l,
-                                y2 = y_h,
-                                col = stroke,
-                                op = sop
-                            ));
-                            if matches!(s.series_type, SeriesType::Candlestick) {
+                            let col = if up { theme.candle_up } else { theme.candle_down };
+                            let is_candle = matches!(s.series_type, SeriesType::Candlestick);
+                            let custom = s.candle_color_fn.as_ref().filter(|_| is_candle).map(|f| f(i, c));
+                            let tint = opts.candle_pattern_tint.filter(|_| is_candle && custom.is_none() && c.pattern() != CandlePattern::Normal);
+                            let col = custom.map(|(body, _)| body).or(tint).unwrap_or(col);
+                            // OHLC bars have no body, so they keep the body color for their strokes
+                            let border = match custom {
+                                Some((_, wick)) => wick,
+                                None if !is_candle || tint.is_some() => col,
+                                None if up => theme.candle_up_border,
+                                None => theme.candle_down_border,
+                            };
+                            let stroke = color_hex_rgb(border);
+                            let sop = color_opacity(border);
+                            let hollow = is_candle && up && tint.is_none() && custom.is_none() && opts.candle_style == CandleStyle::Hollow;
+                            // Hollow bodies leave the wick outside the body so it does not show through
+                            let wick_spans = if hollow {
+                                vec![(y_l, y_o.max(y_c)), (y_o.min(y_c), y_h)]
+                            } else {
+                                vec![(y_l, y_h)]
+                            };
+                            for (y1, y2) in wick_spans {
+                                out.push_str(&format!(
+                                    "    <line x1=\"{x}\" y1=\"{y1}\" x2=\"{x}\" y2=\"{y2}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"{sw1}\" />\n",
+                                    x = x,
+                                    y1 = y1,
+                                    y2 = y2,
+                                    col = stroke,
+                                    op = sop
+                                ));
+                            }
+                            if is_candle {
                                 let y_top = y_o.min(y_c);
                                 let y_bot = y_o.max(y_c);
+                                let fill = if hollow { "none".to_string() } else { color_hex_rgb(col) };
                                 out.push_str(&format!(
-                                    "    <rect x=\"{x}\" y=\"{y}\" width=\"{w}\" height=\"{h}\" fill=\"{col}\" fill-opacity=\"{op}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"1\" transform=\"translate({tx},0)\" />\n",
+                                    "    <rect x=\"{x}\" y=\"{y}\" width=\"{w}\" height=\"{h}\" fill=\"{fill}\" fill-opacity=\"{fop}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"{sw1}\" transform=\"translate({tx},0)\" />\n",
                                     x = -wpx * 0.5,
                                     y = y_top,
                                     w = wpx,
                                     h = (y_bot - y_top).abs().max(1.0),
+                                    fill = fill,
+                                    fop = color_opacity(col),
                                     col = stroke,
                                     op = sop,
                                     tx = x
@@ -568,7 +1615,7 @@ impl Chart {
                             } else {
                                 let half = wpx * 0.5;
                                 out.push_str(&format!(
-                                    "    <line x1=\"{x1}\" y1=\"{y}\" x2=\"{x2}\" y2=\"{y}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"1\" />\n",
+                                    "    <line x1=\"{x1}\" y1=\"{y}\" x2=\"{x2}\" y2=\"{y}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"{sw1}\" />\n",
                                     x1 = x - half,
                                     x2 = x,
                                     y = y_o,
@@ -576,7 +1623,7 @@ impl Chart {
                                     op = sop
                                 ));
                                 out.push_str(&format!(
-                                    "    <line x1=\"{x1}\" y1=\"{y}\" x2=\"{x2}\" y2=\"{y}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"1\" />\n",
+                                    "    <line x1=\"{x1}\" y1=\"{y}\" x2=\"{x2}\" y2=\"{y}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"{sw1}\" />\n",
                                     x1 = x,
                                     x2 = x + half,
                                     y = y_c,
@@ -588,35 +1635,302 @@ impl Chart {
                     }
                 }
             }
+            if let Some((min, max)) = s.extreme_points().filter(|_| s.highlight_extremes) {
+                let col = color_hex_rgb(theme.axis_label);
+                let op = color_opacity(theme.axis_label);
+                out.push_str("    <g class=\"extremes\">\n");
+                for (kind, (xv, yv)) in [("max", max), ("min", min)] {
+                    let (cx, cy) = (sx(xv), sy(yv));
+                    out.push_str(&format!(
+                        "      <circle class=\"{kind}\" cx=\"{cx}\" cy=\"{cy}\" r=\"{r}\" fill=\"{col}\" fill-opacity=\"{op}\" />\n",
+                        r = EXTREME_DOT_RADIUS * dpr,
+                    ));
+                    if opts.draw_labels {
+                        // Max label above its dot, min label below
+                        let ty = if kind == "max" { cy - 8.0 * dpr } else { cy + 16.0 * dpr };
+                        out.push_str(&format!(
+                            "      <text x=\"{cx}\" y=\"{ty}\" fill=\"{col}\" fill-opacity=\"{op}\" font-size=\"{fs}\" text-anchor=\"middle\">{label}</text>\n",
+                            fs = 12.0 * dpr,
+                            label = y_tick_label(yv, &y_axis),
+                        ));
+                    }
+                }
+                out.push_str("    </g>\n");
+            }
+            out
+        };
+
+        out.push_str("  <g id=\"series\" fill=\"none\" stroke-linejoin=\"round\" stroke-linecap=\"round\">\n");
+        let stack_bases = self.stack_bases();
+        for i in self.draw_order() {
+            out.push_str(&series_svg(&self.series[i], stack_bases[i].as_deref(), &opts.theme));
         }
         out.push_str("  </g>\n");
         // Overlays (computed)
-        if !self.overlays.is_empty() {
+        if self.main_overlays().next().is_some() {
             out.push_str("  <g id=\"overlays\" fill=\"none\" stroke-linejoin=\"round\" stroke-linecap=\"round\">\n");
-            let (sr, sg, sb, sa) = (opts.theme.crosshair.r(), opts.theme.crosshair.g(), opts.theme.crosshair.b(), opts.theme.crosshair.a());
-            let stroke = format!("#{:02X}{:02X}{:02X}", sr, sg, sb);
-            let sop = format!("{:.3}", (sa as f32) / 255.0);
-            for ov in &self.overlays {
-                let computed = ov.compute(self);
-                for s in &computed {
-                    if matches!(s.series_type, SeriesType::Line) && s.data_xy.len() >= 2 {
-                        let mut dpath = String::new();
-                        dpath.push_str(&format!("M {} {}", sx(s.data_xy[0].0), sy(s.data_xy[0].1)));
-                        for &(xv, yv) in s.data_xy.iter().skip(1) { dpath.push_str(&format!(" L {} {}", sx(xv), sy(yv))); }
-                        out.push_str(&format!("    <path d=\"{d}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"1.5\" />\n", d = dpath, col = stroke, op = sop));
-                    }
+            for ov in self.main_overlays() {
+                for s in &ov.compute(self) {
+                    out.push_str(&series_svg(s, None, &overlay_theme(s, &opts.theme)));
+                }
+                if !opts.draw_labels { continue; }
+                let text_size = 12.0 * dpr;
+                let pad = OVERLAY_LABEL_PAD * dpr;
+                for label in ov.labels(self) {
+                    let bw = text_shaper().measure_width(&label.text, text_size, true) + 2.0 * pad;
+                    let bh = text_size + pad;
+                    let (cx, bottom) = (sx(label.x), sy(label.y) - pad);
+                    out.push_str(&format!(
+                        "    <rect class=\"overlay-label\" x=\"{x}\" y=\"{y}\" width=\"{bw}\" height=\"{bh}\" fill=\"{col}\" fill-opacity=\"{op}\" />\n",
+                        x = cx - bw * 0.5,
+                        y = bottom - bh,
+                        col = color_hex_rgb(opts.theme.crosshair),
+                        op = color_opacity(opts.theme.crosshair),
+                    ));
+                    out.push_str(&format!(
+                        "    <text x=\"{cx}\" y=\"{ty}\" fill=\"{tc}\" font-size=\"{text_size}\" text-anchor=\"middle\">{text}</text>\n",
+                        ty = bottom - bh * 0.5 + pad,
+                        tc = color_hex_rgb(opts.theme.background),
+                        text = xml_escape(&label.text),
+                    ));
                 }
             }
             out.push_str("  </g>\n");
         }
 
+        // Last-value line and badge
+        if let Some((value, color)) = self.last_value_marker(&opts.theme).filter(|_| opts.last_value_line) {
+            let ypx = sy(value);
+            if ypx >= t as f32 && ypx <= bpx as f32 {
+                let y = align(ypx);
+                let (col, op) = (color_hex_rgb(color), color_opacity(color));
+                let (dash_on, dash_off) = (4.0 * dpr, 3.0 * dpr);
+                out.push_str("  <g id=\"last-value\">\n");
+                out.push_str(&format!(
+                    "    <line x1=\"{l}\" y1=\"{y}\" x2=\"{rpx}\" y2=\"{y}\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"{sw1}\" stroke-dasharray=\"{dash_on} {dash_off}\" />\n"
+                ));
+                if opts.draw_labels {
+                    let text_size = 12.0 * dpr;
+                    let label = y_tick_label(value, &y_axis);
+                    let bw = text_shaper().measure_width(&label, text_size, true) + 2.0 * LAST_VALUE_BADGE_PAD * dpr;
+                    let bh = text_size + LAST_VALUE_BADGE_PAD * dpr;
+                    out.push_str(&format!(
+                        "    <rect x=\"{x}\" y=\"{by}\" width=\"{bw}\" height=\"{bh}\" fill=\"{col}\" fill-opacity=\"{op}\" />\n",
+                        x = l as f32 - bw,
+                        by = y - bh * 0.5,
+                    ));
+                    out.push_str(&format!(
+                        "    <text x=\"{x}\" y=\"{ty}\" fill=\"{tc}\" font-size=\"{fs}\" text-anchor=\"end\">{label}</text>\n",
+                        x = l as f32 - LAST_VALUE_BADGE_PAD * dpr,
+                        ty = y + 4.0 * dpr,
+                        tc = color_hex_rgb(opts.theme.background),
+                        fs = text_size,
+                    ));
+                }
+                out.push_str("  </g>\n");
+            }
+        }
+
+        // Image frame (on top of everything)
+        if let Some(frame) = &opts.frame {
+            let fw = frame.width * opts.dpr.max(0.5);
+            if fw > 0.0 {
+                out.push_str(&format!(
+                    "  <rect id=\"frame\" x=\"{x}\" y=\"{x}\" width=\"{fw_}\" height=\"{fh}\" fill=\"none\" stroke=\"{col}\" stroke-opacity=\"{op}\" stroke-width=\"{sw}\" />\n",
+                    x = fw * 0.5,
+                    fw_ = (w as f32 - fw).max(0.0),
+                    fh = (h as f32 - fw).max(0.0),
+                    col = color_hex_rgb(frame.color),
+                    op = color_opacity(frame.color),
+                    sw = fw
+                ));
+            }
+        }
+
         out.push_str("</svg>\n");
+        Ok(out)
+    }
+}
 
-        let path = output_svg_path.as_ref();
-        if let Some(parent) = path.parent() { std::fs::create_dir_all(parent)?; }
-        std::fs::write(path, out)?;
+/// Per-frame settings the draw helpers read without threading them through every call.
+#[derive(Clone, Copy)]
+struct FrameState {
+    deterministic: bool, // `RenderOptions::deterministic`
+    dpr: f32,            // `RenderOptions::dpr`, clamped like text sizes
+}
+
+thread_local! {
+    // Settings of the frame currently being drawn on this thread.
+    static FRAME: Cell<FrameState> = const { Cell::new(FrameState { deterministic: false, dpr: 1.0 }) };
+}
+
+/// Scope guard installing `opts`' frame settings for the current thread's draw; restores
+/// the previous settings on drop so nested and panicking draws stay consistent.
+pub(crate) struct RenderFrame(FrameState);
+
+impl RenderFrame {
+    pub(crate) fn enter(opts: &RenderOptions) -> Self {
+        let state = FrameState { deterministic: opts.deterministic, dpr: opts.dpr.max(0.5) };
+        Self(FRAME.with(|f| f.replace(state)))
+    }
+}
+
+impl Drop for RenderFrame {
+    fn drop(&mut self) {
+        FRAME.with(|f| f.set(self.0));
+    }
+}
+
+/// Anti-aliasing for shape paints; off in deterministic frames.
+fn antialias() -> bool {
+    !FRAME.with(Cell::get).deterministic
+}
+
+/// Logical stroke width or marker radius scaled to device pixels for the current frame.
+fn px(logical: f32) -> f32 {
+    logical * FRAME.with(Cell::get).dpr
+}
+
+/// Text shaper for the current frame: the bundled font in deterministic frames, system fonts otherwise.
+pub(crate) fn text_shaper() -> TextShaper {
+    if FRAME.with(Cell::get).deterministic { TextShaper::bundled() } else { TextShaper::new() }
+}
+
+/// Overlay output computed ahead of time, so a chart can be rebuilt on another thread
+/// without moving the (possibly `!Send`) overlay providers.
+#[cfg(feature = "tokio")]
+struct PrecomputedOverlay(Vec<Series>);
+
+#[cfg(feature = "tokio")]
+impl OverlayTrait for PrecomputedOverlay {
+    fn id(&self) -> &'static str { "precomputed" }
+    fn compute(&self, _chart: &Chart) -> Vec<Series> { self.0.clone() }
+}
+
+#[cfg(feature = "tokio")]
+impl Chart {
+    /// Async counterpart of `render_to_png_bytes` for use inside a tokio runtime.
+    ///
+    /// Series, axes and overlay output are snapshotted on the calling thread; rasterization
+    /// and PNG encoding then run on tokio's blocking pool via `spawn_blocking`, so the
+    /// returned future is `'static` and doesn't tie up an executor thread. Must be awaited
+    /// (or at least called) from within a runtime.
+    pub fn render_to_png_bytes_async(&self, opts: &RenderOptions) -> impl std::future::Future<Output = Result<Vec<u8>>> + Send + 'static {
+        let series = self.series.clone();
+        let (x_axis, y_axis) = (self.x_axis.clone(), self.y_axis.clone());
+        let (fills, nice_bounds) = (self.fills.clone(), self.nice_bounds);
+        let overlays: Vec<Vec<Series>> = self.main_overlays().map(|ov| ov.compute(self)).collect();
+        let opts = opts.clone();
+        let task = tokio::task::spawn_blocking(move || {
+            let mut chart = Chart { series, x_axis, y_axis, fills, nice_bounds, ..Chart::new() };
+            for computed in overlays {
+                chart.add_overlay(PrecomputedOverlay(computed));
+            }
+            chart.render_to_png_bytes(&opts)
+        });
+        async move { task.await.map_err(|e| anyhow::anyhow!("render task failed: {e}"))? }
+    }
+}
+
+impl Chart {
+    /// HTML `<table>` of every series' `iter_xy` points, one row per point, as a text
+    /// fallback next to an exported image (e.g. in emailed reports). Columns are the series
+    /// index and the two axis labels; values use each axis' tick formatting.
+    pub fn export_html_table(&self) -> String {
+        let (x_axis, y_axis) = (&self.x_axis, &self.y_axis);
+        let mut out = String::from("<table class=\"chart-data\">\n");
+        out.push_str(&format!("  <caption>{} vs {}</caption>\n", xml_escape(&y_axis.label), xml_escape(&x_axis.label)));
+        out.push_str(&format!(
+            "  <thead>\n    <tr><th scope=\"col\">series</th><th scope=\"col\">{}</th><th scope=\"col\">{}</th></tr>\n  </thead>\n  <tbody>\n",
+            xml_escape(&x_axis.label),
+            xml_escape(&y_axis.label)
+        ));
+        for (i, s) in self.series.iter().enumerate() {
+            for (x, y) in s.iter_xy() {
+                out.push_str(&format!(
+                    "    <tr><td>{i}</td><td>{}</td><td>{}</td></tr>\n",
+                    xml_escape(&x_axis.format_value(x)),
+                    xml_escape(&y_axis.format_value(y))
+                ));
+            }
+        }
+        out.push_str("  </tbody>\n</table>\n");
+        out
+    }
+}
+
+/// Escape text for SVG/HTML element content and double-quoted attributes.
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// Column layout of `Chart::export_csv`; `x`/`y` hold (t, close) on candle rows.
+#[cfg(feature = "csv")]
+const CSV_HEADER: [&str; 7] = ["series", "x", "y", "o", "h", "l", "c"];
+
+#[cfg(feature = "csv")]
+impl Chart {
+    /// Write every series to one CSV with header `series,x,y,o,h,l,c`, one row per point.
+    /// `series` is the index into `self.series`. Candle/bar rows fill `t,o,h,l,c` (t in the
+    /// `x` column, the close repeated in `y` so generic tools can plot it); other rows leave
+    /// the OHLC columns empty. Error bars and bands are written as their `iter_xy` points.
+    /// Values use Rust's shortest round-trip formatting, so `import_csv` restores them exactly.
+    pub fn export_csv(&self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        if let Some(parent) = path.as_ref().parent() { std::fs::create_dir_all(parent)?; }
+        let mut w = csv::Writer::from_path(path)?;
+        w.write_record(CSV_HEADER)?;
+        for (i, s) in self.series.iter().enumerate() {
+            let idx = i.to_string();
+            match s.series_type {
+                SeriesType::Candlestick | SeriesType::Bar => {
+                    for c in &s.data_ohlc {
+                        let (t, o, h, l, cl) = (c.t.to_string(), c.o.to_string(), c.h.to_string(), c.l.to_string(), c.c.to_string());
+                        w.write_record([idx.as_str(), &t, &cl, &o, &h, &l, &cl])?;
+                    }
+                }
+                _ => {
+                    for (x, y) in s.iter_xy() {
+                        w.write_record([idx.as_str(), &x.to_string(), &y.to_string(), "", "", "", ""])?;
+                    }
+                }
+            }
+        }
+        w.flush()?;
         Ok(())
     }
+
+    /// Load series written by `export_csv`: one series per distinct `series` value, in
+    /// ascending order. Rows with OHLC columns become a Candlestick series, others a Line.
+    /// Axes keep their defaults; call `autoscale_axes` afterwards.
+    pub fn import_csv(path: impl AsRef<std::path::Path>) -> Result<Chart> {
+        use crate::series::Candle;
+
+        let mut rdr = csv::Reader::from_path(path)?;
+        let headers = rdr.headers()?.clone();
+        let col = |name: &str| headers.iter().position(|h| h == name).ok_or_else(|| anyhow::anyhow!("missing CSV column '{name}'"));
+        let [series, x, y, o, h, l, c] = CSV_HEADER.map(col);
+        let (series, x, y, o, h, l, c) = (series?, x?, y?, o?, h?, l?, c?);
+
+        let mut groups: std::collections::BTreeMap<usize, Series> = std::collections::BTreeMap::new();
+        for (row, record) in rdr.records().enumerate() {
+            let record = record?;
+            let field = |i: usize| record.get(i).unwrap_or("").trim();
+            let num = |i: usize| -> Result<f64> {
+                field(i).parse().map_err(|_| anyhow::anyhow!("row {}: bad number '{}' in column '{}'", row + 1, field(i), &headers[i]))
+            };
+            let idx: usize = field(series).parse().map_err(|_| anyhow::anyhow!("row {}: bad series index '{}'", row + 1, field(series)))?;
+            if field(o).is_empty() {
+                let s = groups.entry(idx).or_insert_with(|| Series::new(SeriesType::Line));
+                s.data_xy.push((num(x)?, num(y)?));
+            } else {
+                let s = groups.entry(idx).or_insert_with(|| Series::new(SeriesType::Candlestick));
+                s.data_ohlc.push(Candle { t: num(x)?, o: num(o)?, h: num(h)?, l: num(l)?, c: num(c)? });
+            }
+        }
+        let mut chart = Chart::new();
+        chart.series = groups.into_values().collect();
+        Ok(chart)
+    }
 }
 
 // ---- helpers ----------------------------------------------------------------
@@ -624,8 +1938,8 @@ impl Chart {
 fn draw_grid(canvas: &skia::Canvas, l: i32, t: i32, r: i32, b: i32, crisp: bool, theme: &Theme) {
     let mut paint = skia::Paint::default();
     paint.set_color(theme.grid);
-    paint.set_anti_alias(true);
-    paint.set_stroke_width(1.0);
+    paint.set_anti_alias(antialias());
+    paint.set_stroke_width(px(1.0));
 
     // verticals
     for x in linspace(l as f64, r as f64, 10) {
@@ -639,6 +1953,74 @@ fn draw_grid(canvas: &skia::Canvas, l: i32, t: i32, r: i32, b: i32, crisp: bool,
     }
 }
 
+/// Faint full-length gridlines at minor tick positions (value space, unlike the major grid).
+fn draw_minor_grid(
+    canvas: &skia::Canvas,
+    l: i32, t: i32, r: i32, b: i32,
+    x_axis: &Axis, y_axis: &Axis,
+    crisp: bool,
+    theme: &Theme,
+) {
+    let xspan = (x_axis.max - x_axis.min).max(1e-9);
+    let ts = TimeScale::new(l as f32, x_axis.min, ((r - l) as f32) / (xspan as f32));
+    let vs = match y_axis.kind {
+        ScaleKind::Linear => ValueScale::new_linear(t as f32, b as f32, y_axis.min, y_axis.max),
+        ScaleKind::Log10 => ValueScale::new_log10(t as f32, b as f32, y_axis.min, y_axis.max),
+    };
+
+    let mut paint = skia::Paint::default();
+    paint.set_color(minor_grid_color(theme.grid));
+    paint.set_anti_alias(antialias());
+    paint.set_stroke_width(px(1.0));
+
+    for vx in minor_tick_values_x(x_axis) {
+        let x = ts.to_px(vx);
+        if x < l as f32 || x > r as f32 { continue; }
+        let xf = if crisp { align_half(x) } else { x };
+        canvas.draw_line((xf, t as f32), (xf, b as f32), &paint);
+    }
+    for vy in minor_tick_values_y(y_axis) {
+        let y = vs.to_px(vy);
+        if y < t as f32 || y > b as f32 { continue; }
+        let yf = if crisp { align_half(y) } else { y };
+        canvas.draw_line((l as f32, yf), (r as f32, yf), &paint);
+    }
+}
+
+/// Dimmed variant of the theme grid color used for minor gridlines.
+fn minor_grid_color(grid: skia::Color) -> skia::Color {
+    grid.with_a(grid.a() / 2)
+}
+
+/// Minor tick values along X (linear subdivisions between the major ticks drawn by `draw_axes`).
+fn minor_tick_values_x(x: &Axis) -> Vec<f64> {
+    let xticks = nice_ticks(x.min, x.max, 8);
+    minor_ticks_linear(&xticks, 4).into_iter().filter(|v| v.is_finite()).collect()
+}
+
+/// Minor tick values along Y (linear: subdivisions; log: 2..9 per decade).
+fn minor_tick_values_y(y: &Axis) -> Vec<f64> {
+    let out = if y.kind == ScaleKind::Log10 {
+        minor_ticks_log(y.min.max(1e-12), y.max)
+    } else {
+        minor_ticks_linear(&nice_ticks(y.min, y.max, 6), 4)
+    };
+    out.into_iter().filter(|v| v.is_finite()).collect()
+}
+
+/// Top and right edges of the plot box in the theme's axis color, `line_width` logical px
+/// wide like the axis lines `draw_axes` puts on the bottom and left.
+fn draw_plot_box(canvas: &skia::Canvas, l: i32, t: i32, r: i32, b: i32, crisp: bool, theme: &Theme, line_width: f32) {
+    let mut paint = skia::Paint::default();
+    paint.set_color(theme.axis_line);
+    paint.set_anti_alias(antialias());
+    paint.set_stroke_width(px(line_width));
+    let ty = if crisp { align_half(t as f32) } else { t as f32 };
+    let rx = if crisp { align_half(r as f32) } else { r as f32 };
+    canvas.draw_line((l as f32, ty), (r as f32, ty), &paint);
+    canvas.draw_line((rx, t as f32), (rx, b as f32), &paint);
+}
+
 fn draw_axes(
     canvas: &skia::Canvas,
     l: i32,
@@ -648,43 +2030,42 @@ fn draw_axes(
     x: &Axis,
     y: &Axis,
     draw_labels: bool,
+    x_labels: bool,
     crisp: bool,
     theme: &Theme,
     dpr: f32,
+    x_label_rotation_deg: f32,
+    line_width: f32,
 ) {
     let mut axis_paint = skia::Paint::default();
-    axis_paint.set_color(theme.axis_line);
-    axis_paint.set_anti_alias(true);
-    axis_paint.set_stroke_width(1.5);
+    axis_paint.set_anti_alias(antialias());
+    axis_paint.set_stroke_width(px(line_width));
 
-    // X and Y axis lines
+    // X and Y axis lines, each in its own override color when set
     let bx = if crisp { align_half(b as f32) } else { b as f32 };
     let lx = if crisp { align_half(l as f32) } else { l as f32 };
+    axis_paint.set_color(x.line_color.unwrap_or(theme.axis_line));
     canvas.draw_line((l as f32, bx), (r as f32, bx), &axis_paint);
+    axis_paint.set_color(y.line_color.unwrap_or(theme.axis_line));
     canvas.draw_line((lx, t as f32), (lx, b as f32), &axis_paint);
 
     if draw_labels {
         let mut paint_text = skia::Paint::default();
         paint_text.set_color(theme.axis_label);
-        paint_text.set_anti_alias(true);
+        paint_text.set_anti_alias(antialias());
         let text_size = 12.0 * dpr.max(0.5);
-        let shaper = TextShaper::new();
+        let shaper = text_shaper();
 
         // Draw axis titles
-        shaper.draw_left(canvas, &x.label, r as f32 - 80.0 * dpr, b as f32 + 28.0 * dpr, text_size, theme.axis_label, false);
+        if x_labels { shaper.draw_left(canvas, &x.label, r as f32 - 80.0 * dpr, b as f32 + 28.0 * dpr, text_size, theme.axis_label, false); }
         shaper.draw_left(canvas, &y.label, l as f32 + 8.0 * dpr, t as f32 + 14.0 * dpr, text_size, theme.axis_label, false);
 
         // Ticks configuration
         let target_xticks = 8usize;
-        let target_yticks = 6usize;
 
         // Compute "nice" ticks in value space
         let xticks = nice_ticks(x.min, x.max, target_xticks.max(2));
-        let yticks = if y.kind == ScaleKind::Log10 {
-            log_ticks(y.min.max(1e-12), y.max, target_yticks.max(2))
-        } else {
-            nice_ticks(y.min, y.max, target_yticks.max(2))
-        };
+        let yticks = y_tick_values(y);
 
         // Build scales to place ticks in pixel space
         let xspan = (x.max - x.min).max(1e-9);
@@ -700,24 +2081,38 @@ fn draw_axes(
         // Tick paints
         let mut tick_paint = skia::Paint::default();
         tick_paint.set_color(theme.tick);
-        tick_paint.set_anti_alias(true);
-        tick_paint.set_stroke_width(1.0);
-
-        // X ticks and labels (bottom)
-        for vx in xticks.iter().copied() {
-            if !vx.is_finite() { continue; }
-            let xpx = if crisp { align_half(sx(vx)) } else { sx(vx) };
+        tick_paint.set_anti_alias(antialias());
+        tick_paint.set_stroke_width(px(1.0));
+
+        // X ticks and labels (bottom); every tick keeps its mark, labels are thinned
+        // to every `stride`-th one so neighbours don't collide
+        let xs: Vec<(f32, String)> = xticks
+            .iter()
+            .copied()
+            .filter(|vx| vx.is_finite())
+            .map(|vx| (if crisp { align_half(sx(vx)) } else { sx(vx) }, x_tick_label(vx, x)))
+            .collect();
+        let advances: Vec<f32> = xs.iter().map(|(_, label)| shaper.measure_width(label, text_size, true)).collect();
+        let xpxs: Vec<f32> = xs.iter().map(|(xpx, _)| *xpx).collect();
+        let stride = x_label_stride(&xpxs, &advances, text_size, x_label_rotation_deg, dpr);
+        for (i, ((xpx, label), advance)) in xs.iter().zip(advances).enumerate() {
+            let xpx = *xpx;
             // small tick up from baseline
             canvas.draw_line((xpx, bx), (xpx, bx - 6.0 * dpr), &tick_paint);
+            if !x_labels || i % stride != 0 { continue; }
             // label
-            let label = if detect_time_like(x.min, x.max).is_some() {
-                format_time_tick(vx, x.min, x.max)
+            if x_label_rotation_deg == 0.0 {
+                // center roughly: shift by half label width
+                shaper.draw_left(canvas, label, xpx - advance * 0.5, b as f32 + 18.0 * dpr, text_size, theme.axis_label, true);
             } else {
-                format_tick(vx, x.min, x.max)
-            };
-            // center roughly: shift by half label width
-            let advance = shaper.measure_width(&label, text_size, true);
-            shaper.draw_left(canvas, &label, xpx - advance * 0.5, b as f32 + 18.0 * dpr, text_size, theme.axis_label, true);
+                // rotate about an anchor just below the tick; same geometry as the SVG export
+                let (ax, ay, dx) = rotated_x_label_anchor(xpx, b as f32, advance, x_label_rotation_deg, dpr);
+                canvas.save();
+                canvas.translate((ax, ay));
+                canvas.rotate(x_label_rotation_deg, None);
+                shaper.draw_left(canvas, label, dx, 4.0 * dpr, text_size, theme.axis_label, true);
+                canvas.restore();
+            }
         }
 
         // Y ticks and labels (left)
@@ -727,7 +2122,7 @@ fn draw_axes(
             // small tick to the right from axis
             canvas.draw_line((lx, ypx), (lx + 6.0 * dpr, ypx), &tick_paint);
             // label to the left of axis, right-aligned
-            let label = if y.kind == ScaleKind::Log10 { format_log_tick(vy) } else { format_tick(vy, y.min, y.max) };
+            let label = y_tick_label(vy, y);
             let advance = shaper.measure_width(&label, text_size, true);
             shaper.draw_left(canvas, &label, l as f32 - 8.0 * dpr - advance, ypx + 4.0 * dpr, text_size, theme.axis_label, true);
         }
@@ -735,22 +2130,22 @@ fn draw_axes(
         // Minor ticks (no labels)
         let mut minor_paint = tick_paint.clone();
         minor_paint.set_color(skia::Color::from_argb(180, 120, 120, 130));
-        minor_paint.set_stroke_width(0.8);
+        minor_paint.set_stroke_width(px(0.8));
 
         // X minor ticks (linear only, between majors)
         let x_minors = minor_ticks_linear(&xticks, 4);
         for vx in x_minors {
             if !vx.is_finite() { continue; }
             let xpx = if crisp { align_half(sx(vx)) } else { sx(vx) };
-            canvas.draw_line((xpx, bx), (xpx, bx - 3.0), &minor_paint);
+            canvas.draw_line((xpx, bx), (xpx, bx - 3.0 * dpr), &minor_paint);
         }
         // Y minor ticks (linear: subdiv; log: 2..9 per decade)
         if y.kind == ScaleKind::Log10 {
             let y_minors = minor_ticks_log(y.min.max(1e-12), y.max);
-            for vy in y_minors { let ypx = if crisp { align_half(sy(vy)) } else { sy(vy) }; canvas.draw_line((lx, ypx), (lx + 3.0, ypx), &minor_paint); }
+            for vy in y_minors { let ypx = if crisp { align_half(sy(vy)) } else { sy(vy) }; canvas.draw_line((lx, ypx), (lx + 3.0 * dpr, ypx), &minor_paint); }
         } else {
             let y_minors = minor_ticks_linear(&yticks, 4);
-            for vy in y_minors { let ypx = if crisp { align_half(sy(vy)) } else { sy(vy) }; canvas.draw_line((lx, ypx), (lx + 3.0, ypx), &minor_paint); }
+            for vy in y_minors { let ypx = if crisp { align_half(sy(vy)) } else { sy(vy) }; canvas.draw_line((lx, ypx), (lx + 3.0 * dpr, ypx), &minor_paint); }
         }
     }
 }
@@ -767,7 +2162,7 @@ fn draw_line_series(
     theme: &Theme,
 ) {
     let data = &series.data_xy;
-    if data.len() < 2 {
+    if data.len() < 2 && !(series.show_markers && data.len() == 1) {
         return;
     }
 
@@ -781,29 +2176,68 @@ fn draw_line_series(
     let sx = |x: f64| -> f32 { ts.to_px(x) };
     let sy = |y: f64| -> f32 { vs.to_px(y) };
 
-    let mut path = skia::Path::new();
-    let (x0, y0) = data[0];
-    path.move_to((sx(x0), sy(y0)));
-
-    for &(x, y) in data.iter().skip(1) {
-        path.line_to((sx(x), sy(y)));
+    // Gaps (NaN/inf) are handled per `series.gap_mode`; each run starts a new sub-path.
+    // A threshold color further splits runs at crossings into an above and a below path.
+    let runs = match series.threshold_color {
+        Some((th, _, _)) => series.threshold_runs(th),
+        None => series.line_runs().into_iter().map(|run| (true, run)).collect(),
+    };
+    let mut paths = [skia::Path::new(), skia::Path::new()]; // [above (or whole line), below]
+    // Runs are mapped in bulk; long series spend most of their time here
+    let mut mapped = Vec::new();
+    for (above, run) in runs {
+        let path = &mut paths[usize::from(!above)];
+        map_points(&ts, &vs, &run, &mut mapped);
+        path.move_to(mapped[0]);
+        for &p in &mapped[1..] {
+            path.line_to(p);
+        }
     }
 
     let mut stroke = skia::Paint::default();
-    stroke.set_anti_alias(true);
+    stroke.set_anti_alias(antialias());
     stroke.set_style(skia::paint::Style::Stroke);
-    stroke.set_stroke_width(2.0);
-    stroke.set_color(theme.line_stroke);
+    stroke.set_stroke_width(px(2.0));
 
-    canvas.draw_path(&path, &stroke);
+    let (above, below) = series.threshold_color.map_or((theme.line_stroke, theme.line_stroke), |(_, a, b)| (a, b));
+    for (path, color) in paths.iter().zip([above, below]) {
+        stroke.set_color(color);
+        canvas.draw_path(path, &stroke);
+    }
+
+    if series.show_markers {
+        let mut fill = skia::Paint::default();
+        fill.set_anti_alias(antialias());
+        fill.set_style(skia::paint::Style::Fill);
+        fill.set_color(theme.line_stroke);
+        let m = px(MARKER_RADIUS);
+        for &(x, y) in data.iter().filter(|p| p.0.is_finite() && p.1.is_finite()) {
+            let (cx, cy) = (sx(x), sy(y));
+            match series.marker_shape {
+                MarkerShape::Circle => { canvas.draw_circle((cx, cy), m, &fill); }
+                MarkerShape::Square => { canvas.draw_rect(skia::Rect::from_ltrb(cx - m, cy - m, cx + m, cy + m), &fill); }
+                MarkerShape::Diamond => {
+                    let mut d = skia::Path::new();
+                    d.move_to((cx, cy - m)).line_to((cx + m, cy)).line_to((cx, cy + m)).line_to((cx - m, cy)).close();
+                    canvas.draw_path(&d, &fill);
+                }
+            }
+        }
+    }
 }
 
+/// Line-series marker size (circle radius, half the square side), in logical px.
+const MARKER_RADIUS: f32 = 3.0;
+
 fn draw_candle_series(
     canvas: &skia::Canvas,
     l: i32, t: i32, r: i32, b: i32,
     x_axis: &Axis, y_axis: &Axis,
     series: &Series,
     theme: &Theme,
+    style: CandleStyle,
+    pattern_tint: Option<skia::Color>,
+    gradient: bool,
 ) {
     if series.data_ohlc.is_empty() { return; }
 
@@ -818,40 +2252,88 @@ fn draw_candle_series(
 
     // style
     let mut wick_paint = skia::Paint::default();
-    wick_paint.set_anti_alias(true);
+    wick_paint.set_anti_alias(antialias());
     wick_paint.set_style(skia::paint::Style::Stroke);
-    wick_paint.set_stroke_width(1.0);
+    wick_paint.set_stroke_width(px(1.0));
 
+    let hollow = style == CandleStyle::Hollow;
     let mut body_paint_up = skia::Paint::default();
-    body_paint_up.set_anti_alias(true);
-    body_paint_up.set_style(skia::paint::Style::Fill);
-    body_paint_up.set_color(theme.candle_up);
+    body_paint_up.set_anti_alias(antialias());
+    if hollow {
+        body_paint_up.set_style(skia::paint::Style::Stroke);
+        body_paint_up.set_stroke_width(px(1.0));
+    } else {
+        body_paint_up.set_style(skia::paint::Style::Fill);
+    }
+    body_paint_up.set_color(if hollow { theme.candle_up_border } else { theme.candle_up });
 
     let mut body_paint_down = skia::Paint::default();
-    body_paint_down.set_anti_alias(true);
+    body_paint_down.set_anti_alias(antialias());
     body_paint_down.set_style(skia::paint::Style::Fill);
     body_paint_down.set_color(theme.candle_down);
 
+    // Gradient bodies are filled rect by rect; the paths still carry the outlines
+    let mut body_rects_up: Vec<skia::Rect> = Vec::new();
+    let mut body_rects_down: Vec<skia::Rect> = Vec::new();
+
     // Batching: build combined paths for wicks and bodies (up/down)
     let mut wick_path_up = skia::Path::new();
     let mut wick_path_down = skia::Path::new();
     let mut body_path_up = skia::Path::new();
     let mut body_path_down = skia::Path::new();
+    let mut wick_path_tint = skia::Path::new();
+    let mut body_path_tint = skia::Path::new();
 
     // body width in pixels (roughly one “bar width” as fraction of plot)
     let n = series.data_ohlc.len() as f32;
     let bar_px = ((r - l) as f32 / n).max(3.0) * 0.7;
 
-    for c in &series.data_ohlc {
+    for (i, c) in series.data_ohlc.iter().enumerate() {
         let x = sx(c.t);
         let y_o = sy(c.o);
         let y_h = sy(c.h);
         let y_l = sy(c.l);
         let y_c = sy(c.c);
 
+        // caller-colored candles are drawn one by one, filled, in their own colors
+        if let Some(color_fn) = &series.candle_color_fn {
+            let (body, wick) = color_fn(i, c);
+            wick_paint.set_color(wick);
+            canvas.draw_line((x, y_h), (x, y_l), &wick_paint);
+            let half = bar_px * 0.5;
+            let top = y_o.min(y_c);
+            let rect = skia::Rect::from_ltrb(x - half, top, x + half, y_o.max(y_c).max(top + 1.0));
+            if gradient {
+                fill_rects(canvas, &[rect], body, true);
+            } else {
+                let mut body_paint = skia::Paint::default();
+                body_paint.set_anti_alias(antialias());
+                body_paint.set_style(skia::paint::Style::Fill);
+                body_paint.set_color(body);
+                canvas.draw_rect(rect, &body_paint);
+            }
+            continue;
+        }
+
+        // classified candles (doji/marubozu) are drawn solid in the tint color
+        if pattern_tint.is_some() && c.pattern() != CandlePattern::Normal {
+            let half = bar_px * 0.5;
+            let top = y_o.min(y_c);
+            let bot = y_o.max(y_c);
+            wick_path_tint.move_to((x, y_h));
+            wick_path_tint.line_to((x, y_l));
+            body_path_tint.add_rect(skia::Rect::from_ltrb(x - half, top, x + half, bot.max(top + 1.0)), None);
+            continue;
+        }
+
         let up = c.c >= c.o;
-        // wick into path
-        if up {
+        // wick into path (hollow bodies: stop at the body edges)
+        if up && hollow {
+            wick_path_up.move_to((x, y_h));
+            wick_path_up.line_to((x, y_o.min(y_c)));
+            wick_path_up.move_to((x, y_o.max(y_c)));
+            wick_path_up.line_to((x, y_l));
+        } else if up {
             wick_path_up.move_to((x, y_h));
             wick_path_up.line_to((x, y_l));
         } else {
@@ -866,25 +2348,61 @@ fn draw_candle_series(
         let rect = skia::Rect::from_ltrb(x - half, top, x + half, bot.max(top + 1.0));
         if up {
             body_path_up.add_rect(rect, None);
+            if gradient { body_rects_up.push(rect); }
         } else {
             body_path_down.add_rect(rect, None);
+            if gradient { body_rects_down.push(rect); }
         }
     }
 
-    // stroke wicks by color
-    wick_paint.set_color(theme.candle_up);
+    // stroke wicks by border color
+    wick_paint.set_color(theme.candle_up_border);
     canvas.draw_path(&wick_path_up, &wick_paint);
-    wick_paint.set_color(theme.candle_down);
+    wick_paint.set_color(theme.candle_down_border);
     canvas.draw_path(&wick_path_down, &wick_paint);
 
     // fill bodies by color
-    canvas.draw_path(&body_path_up, &body_paint_up);
-    canvas.draw_path(&body_path_down, &body_paint_down);
+    if gradient && !hollow {
+        fill_rects(canvas, &body_rects_up, theme.candle_up, true);
+    } else {
+        canvas.draw_path(&body_path_up, &body_paint_up);
+    }
+    if gradient {
+        fill_rects(canvas, &body_rects_down, theme.candle_down, true);
+    } else {
+        canvas.draw_path(&body_path_down, &body_paint_down);
+    }
+
+    // outline filled bodies only when the border differs (keeps default output unchanged)
+    let mut outline = skia::Paint::default();
+    outline.set_anti_alias(antialias());
+    outline.set_style(skia::paint::Style::Stroke);
+    outline.set_stroke_width(px(1.0));
+    if !hollow && theme.candle_up_border != theme.candle_up {
+        outline.set_color(theme.candle_up_border);
+        canvas.draw_path(&body_path_up, &outline);
+    }
+    if theme.candle_down_border != theme.candle_down {
+        outline.set_color(theme.candle_down_border);
+        canvas.draw_path(&body_path_down, &outline);
+    }
+
+    if let Some(tint) = pattern_tint {
+        wick_paint.set_color(tint);
+        canvas.draw_path(&wick_path_tint, &wick_paint);
+        let mut tint_paint = skia::Paint::default();
+        tint_paint.set_anti_alias(antialias());
+        tint_paint.set_style(skia::paint::Style::Fill);
+        tint_paint.set_color(tint);
+        canvas.draw_path(&body_path_tint, &tint_paint);
+    }
 }
 
+/// Snap a hairline coordinate so the frame's 1-logical-px stroke covers whole device
+/// pixels: half-pixel centers for odd widths (dpr 1), pixel edges for even ones (dpr 2).
 #[inline]
 fn align_half(v: f32) -> f32 {
-    v.floor() + 0.5
+    if px(1.0).round() as i32 % 2 == 0 { v.round() } else { v.floor() + 0.5 }
 }
 
 fn choose_font(size: f32) -> skia::Font {
@@ -910,6 +2428,55 @@ fn choose_font(size: f32) -> skia::Font {
     f
 }
 
+/// Major Y tick values as placed by `draw_axes`.
+fn y_tick_values(y: &Axis) -> Vec<f64> {
+    if y.kind == ScaleKind::Log10 {
+        log_ticks(y.min.max(1e-12), y.max, 6)
+    } else {
+        nice_ticks(y.min, y.max, 6)
+    }
+}
+
+fn y_tick_label(vy: f64, y: &Axis) -> String {
+    let auto = y.label_format == LabelFormat::Auto && y.label_precision.is_none();
+    if y.kind == ScaleKind::Log10 && auto { format_log_tick(vy) } else { y.format_value(vy) }
+}
+
+fn x_tick_label(vx: f64, x: &Axis) -> String {
+    // Time formatting only applies while the axis is left on automatic formatting
+    let auto = x.label_format == LabelFormat::Auto && x.label_precision.is_none();
+    if auto && detect_time_like(x.min, x.max).is_some() { format_time_tick(vx, x.min, x.max) } else { x.format_value(vx) }
+}
+
+/// Smallest power-of-two stride such that every `stride`-th X label clears its kept
+/// neighbour. Unrotated labels are centered, so they need half of each width apart;
+/// rotated labels are parallel strips whose spacing is bounded by the line height.
+fn x_label_stride(xpxs: &[f32], advances: &[f32], text_size: f32, rotation_deg: f32, dpr: f32) -> usize {
+    let (sin, cos) = rotation_deg.to_radians().sin_cos();
+    let footprint = |adv: f32| {
+        if sin.abs() < 1e-3 { adv } else { (adv * cos.abs()).min(text_size * 1.2 / sin.abs()) }
+    };
+    let gap = 6.0 * dpr.max(0.5);
+    let mut stride = 1;
+    while stride < xpxs.len() {
+        let fits = (stride..xpxs.len()).step_by(stride).all(|i| {
+            let j = i - stride;
+            (xpxs[i] - xpxs[j]).abs() >= 0.5 * (footprint(advances[i]) + footprint(advances[j])) + gap
+        });
+        if fits { break; }
+        stride *= 2;
+    }
+    stride
+}
+
+/// Rotation anchor (device px) for an X tick label at `xpx`, plus the x offset of the
+/// text start in the rotated frame. Negative angles (text rising to the right) end the
+/// label at the tick; positive angles start it there so it hangs below the axis.
+fn rotated_x_label_anchor(xpx: f32, bottom: f32, advance: f32, deg: f32, dpr: f32) -> (f32, f32, f32) {
+    let dx = if deg < 0.0 { -advance } else { 0.0 };
+    (xpx, bottom + 10.0 * dpr, dx)
+}
+
 // Generate "nice" tick positions over [min, max]
 fn nice_ticks(min: f64, max: f64, target: usize) -> Vec<f64> {
     if !min.is_finite() || !max.is_finite() || target < 2 { return vec![]; }
@@ -930,15 +2497,6 @@ fn nice_ticks(min: f64, max: f64, target: usize) -> Vec<f64> {
     out
 }
 
-fn nice_step(raw: f64) -> f64 {
-    // 1-2-5 scheme scaled by power of 10
-    let power = raw.abs().log10().floor();
-    let base = 10f64.powf(power);
-    let n = raw / base;
-    let nice = if n <= 1.0 { 1.0 } else if n <= 2.0 { 2.0 } else if n <= 5.0 { 5.0 } else { 10.0 };
-    nice * base
-}
-
 fn log_ticks(min: f64, max: f64, target: usize) -> Vec<f64> {
     if min <= 0.0 || !min.is_finite() || !max.is_finite() || target < 2 { return vec![]; }
     let start = min.log10().floor() as i32;
@@ -960,17 +2518,6 @@ fn format_log_tick(v: f64) -> String {
     }
 }
 
-fn format_tick(v: f64, min: f64, max: f64) -> String {
-    let span = (max - min).abs().max(1e-12);
-    // Use SI prefixes for large spans
-    if span >= 1e6 {
-        return format_si(v);
-    }
-    let mag = span.abs().log10();
-    let decimals = if mag >= 6.0 { 0 } else if mag >= 3.0 { 1 } else if mag >= 1.0 { 2 } else { 3 };
-    format!("{:.*}", decimals as usize, v)
-}
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum TimeUnit { Seconds, Millis }
 
@@ -1045,18 +2592,6 @@ fn minor_ticks_log(min: f64, max: f64) -> Vec<f64> {
     out
 }
 
-fn format_si(v: f64) -> String {
-    let av = v.abs();
-    let (unit, div) = if av >= 1e12 { ("T", 1e12) }
-        else if av >= 1e9 { ("B", 1e9) }
-        else if av >= 1e6 { ("M", 1e6) }
-        else if av >= 1e3 { ("K", 1e3) }
-        else { ("", 1.0) };
-    if unit.is_empty() { return format!("{:.2}", v); }
-    let val = v / div;
-    if av >= 1e9 { format!("{:.2}{}", val, unit) } else { format!("{:.1}{}", val, unit) }
-}
-
 fn draw_bar_series(
     canvas: &skia::Canvas,
     l: i32, t: i32, r: i32, b: i32,
@@ -1076,9 +2611,9 @@ fn draw_bar_series(
     let sy = |y: f64| -> f32 { vs.to_px(y) };
 
     let mut stroke = skia::Paint::default();
-    stroke.set_anti_alias(true);
+    stroke.set_anti_alias(antialias());
     stroke.set_style(skia::paint::Style::Stroke);
-    stroke.set_stroke_width(1.0);
+    stroke.set_stroke_width(px(1.0));
 
     // Batch into two paths by up/down color
     let mut path_up = skia::Path::new();
@@ -1120,6 +2655,7 @@ fn draw_histogram_series(
     x_axis: &Axis, y_axis: &Axis,
     series: &Series,
     theme: &Theme,
+    gradient: bool,
 ) {
     let data = &series.data_xy;
     if data.is_empty() { return; }
@@ -1134,7 +2670,6 @@ fn draw_histogram_series(
     let sy = |y: f64| -> f32 { vs.to_px(y) };
 
     let baseline_val = series.baseline.unwrap_or(0.0);
-    let y0 = sy(baseline_val);
 
     // Estimate bar width from min pixel distance between consecutive x
     let mut min_dx = f32::INFINITY;
@@ -1143,25 +2678,379 @@ fn draw_histogram_series(
         if dx > 0.0 { min_dx = min_dx.min(dx); }
     }
     if !min_dx.is_finite() { min_dx = ((r - l) as f32 / data.len() as f32).max(2.0); }
-    let bw = (min_dx * 0.8).max(2.0);
+    let bw = (min_dx * (1.0 - series.bar_gap_frac) as f32).max(2.0);
+    let shift = bar_align_shift(series.bar_align, bw);
+    let center = |x: f64| -> f32 { sx(x) + shift };
 
-    let mut fill = skia::Paint::default();
-    fill.set_anti_alias(true);
-    fill.set_style(skia::paint::Style::Fill);
-    fill.set_color(theme.histogram);
+    let rects = bar_rects(&stacked_spans(data, None, baseline_val), bw, &center, &sy, Orientation::Vertical);
+    fill_rects(canvas, &rects, theme.histogram, gradient);
+}
 
-    // Batch: accumulate rects into a single path
-    let mut path = skia::Path::new();
-    for &(xv, yv) in data {
+/// Offset (px) from a histogram bar's x to its center for `align` and bar width `bw`.
+fn bar_align_shift(align: BarAlign, bw: f32) -> f32 {
+    match align {
+        BarAlign::Left => bw * 0.5,
+        BarAlign::Center => 0.0,
+        BarAlign::Right => -bw * 0.5,
+    }
+}
+
+/// (x, from, to) value spans for XY points: from the stack base when stacked, else from `baseline`.
+fn stacked_spans(data: &[(f64, f64)], bases: Option<&[f64]>, baseline: f64) -> Vec<(f64, f64, f64)> {
+    data.iter()
+        .enumerate()
+        .map(|(i, &(x, y))| match bases {
+            Some(b) => (x, b[i], b[i] + y),
+            None => (x, baseline, y),
+        })
+        .collect()
+}
+
+/// One `bw`-px-thick rect per (x, from, to) span.
+/// `sx` maps x to the bar center and `sy` maps values along the bar; horizontal bars
+/// swap the pixel axes (center on y, length along x).
+fn bar_rects(
+    spans: &[(f64, f64, f64)],
+    bw: f32,
+    sx: &dyn Fn(f64) -> f32,
+    sy: &dyn Fn(f64) -> f32,
+    orientation: Orientation,
+) -> Vec<skia::Rect> {
+    let mut rects = Vec::with_capacity(spans.len());
+    for &(xv, from, to) in spans {
         let x = sx(xv);
-        let y = sy(yv);
+        let y = sy(to);
+        let y0 = sy(from);
         let half = bw * 0.5;
         let top = y.min(y0);
         let bot = y.max(y0);
-        let rect = skia::Rect::from_ltrb(x - half, top, x + half, (bot).max(top + 1.0));
-        path.add_rect(rect, None);
+        let rect = match orientation {
+            Orientation::Vertical => skia::Rect::from_ltrb(x - half, top, x + half, (bot).max(top + 1.0)),
+            Orientation::Horizontal => skia::Rect::from_ltrb(top, x - half, (bot).max(top + 1.0), x + half),
+        };
+        rects.push(rect);
+    }
+    rects
+}
+
+/// `c` with its alpha multiplied by `opacity` (clamped to 0..1).
+fn scale_alpha(c: skia::Color, opacity: f32) -> skia::Color {
+    c.with_a((c.a() as f32 * opacity.clamp(0.0, 1.0)).round() as u8)
+}
+
+/// Share of white mixed into the top edge of gradient fills.
+const GRADIENT_LIGHTEN: f32 = 0.35;
+
+/// Fill `rects` with `color`, batched in a single path. With `gradient` each rect instead
+/// gets its own vertical gradient from a lightened `color` at the top to `color` at the
+/// bottom; rects whose shader can't be created (e.g. zero height) fall back to flat.
+fn fill_rects(canvas: &skia::Canvas, rects: &[skia::Rect], color: skia::Color, gradient: bool) {
+    let mut fill = skia::Paint::default();
+    fill.set_anti_alias(antialias());
+    fill.set_style(skia::paint::Style::Fill);
+    fill.set_color(color);
+
+    if !gradient {
+        // Batch: accumulate rects into a single path
+        let mut path = skia::Path::new();
+        for rect in rects { path.add_rect(*rect, None); }
+        canvas.draw_path(&path, &fill);
+        return;
+    }
+
+    let lift = |c: u8| (c as f32 + (255.0 - c as f32) * GRADIENT_LIGHTEN).round() as u8;
+    let top_color = skia::Color::from_argb(color.a(), lift(color.r()), lift(color.g()), lift(color.b()));
+    for rect in rects {
+        let shader = skia::Shader::linear_gradient(
+            ((rect.left, rect.top), (rect.left, rect.bottom)),
+            &[top_color, color][..],
+            None,
+            skia::TileMode::Clamp,
+            None,
+            None,
+        );
+        fill.set_shader(shader);
+        canvas.draw_rect(*rect, &fill);
+    }
+}
+
+/// Column bar width in px: the slot (explicit `column_width` in x units, else the
+/// smallest x spacing) minus `column_gap`. Shared by raster and SVG output.
+fn column_width_px(series: &Series, sx: &dyn Fn(f64) -> f32, plot_w: f32) -> f32 {
+    let data = &series.data_xy;
+    let slot = match series.column_width {
+        Some(w) => (sx(w) - sx(0.0)).abs(),
+        None => data
+            .windows(2)
+            .map(|w| (sx(w[1].0) - sx(w[0].0)).abs())
+            .filter(|dx| *dx > 0.0)
+            .fold(f32::INFINITY, f32::min),
+    };
+    let slot = if slot.is_finite() && slot > 0.0 { slot } else { plot_w / data.len().max(1) as f32 };
+    (slot * (1.0 - series.column_gap.clamp(0.0, 0.95) as f32)).max(1.0)
+}
+
+fn draw_column_series(
+    canvas: &skia::Canvas,
+    l: i32, t: i32, r: i32, b: i32,
+    x_axis: &Axis, y_axis: &Axis,
+    series: &Series,
+    theme: &Theme,
+    stack_bases: Option<&[f64]>,
+    gradient: bool,
+) {
+    let data = &series.data_xy;
+    if data.is_empty() { return; }
+
+    let xspan = (x_axis.max - x_axis.min).max(1e-9);
+    let ts = TimeScale::new(l as f32, x_axis.min, ((r - l) as f32) / (xspan as f32));
+    let vs = match y_axis.kind {
+        ScaleKind::Linear => ValueScale::new_linear(t as f32, b as f32, y_axis.min, y_axis.max),
+        ScaleKind::Log10 => ValueScale::new_log10(t as f32, b as f32, y_axis.min, y_axis.max),
+    };
+    let sx = |x: f64| -> f32 { ts.to_px(x) };
+    let sy = |y: f64| -> f32 { vs.to_px(y) };
+
+    let bw = column_width_px(series, &sx, (r - l) as f32);
+    let spans = stacked_spans(data, stack_bases, series.baseline_value());
+    let rects = bar_rects(&spans, bw, &sx, &sy, Orientation::Vertical);
+    fill_rects(canvas, &rects, series.color.unwrap_or(theme.histogram), gradient);
+}
+
+/// `opts.background_image` over the whole image per `opts.background_fit`.
+/// Bytes Skia cannot decode are ignored, leaving the plain background color.
+pub(crate) fn draw_background_image(canvas: &skia::Canvas, opts: &RenderOptions) {
+    let Some(bytes) = &opts.background_image else { return };
+    let Some(image) = skia::Image::from_encoded(skia::Data::new_copy(bytes)) else { return };
+    let (iw, ih) = (image.width(), image.height());
+    if iw <= 0 || ih <= 0 { return; }
+    let paint = skia::Paint::default();
+    match opts.background_fit {
+        BackgroundFit::Stretch => {
+            let dst = skia::Rect::from_wh(opts.width as f32, opts.height as f32);
+            canvas.draw_image_rect(&image, None, dst, &paint);
+        }
+        BackgroundFit::Tile => {
+            for y in (0..opts.height).step_by(ih as usize) {
+                for x in (0..opts.width).step_by(iw as usize) {
+                    canvas.draw_image(&image, (x as f32, y as f32), Some(&paint));
+                }
+            }
+        }
+    }
+}
+
+/// Base64 (standard alphabet, padded) for `data:` URIs in SVG output.
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = (chunk[0] as u32) << 16 | (*chunk.get(1).unwrap_or(&0) as u32) << 8 | *chunk.get(2).unwrap_or(&0) as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// Centered watermark text inside `rect`; see `Watermark`.
+pub(crate) fn draw_watermark(canvas: &skia::Canvas, rect: RectI32, wm: &Watermark, theme: &Theme) {
+    if wm.text.is_empty() || wm.opacity <= 0.0 { return; }
+    let (w, h) = ((rect.right - rect.left) as f32, (rect.bottom - rect.top) as f32);
+    let size = wm.font_size(w, h);
+    let alpha = (wm.opacity.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let color = theme.axis_label.with_a(alpha);
+    let shaper = text_shaper();
+    let text_w = shaper.measure_width(&wm.text, size, false);
+    let x = rect.left as f32 + (w - text_w) * 0.5;
+    // draw_left takes a baseline; drop it ~0.35em below center to center the glyphs
+    let y = rect.top as f32 + h * 0.5 + size * 0.35;
+    shaper.draw_left(canvas, &wm.text, x, y, size, color, false);
+}
+
+/// Radius of the dots marking a series' max/min, in logical px.
+const EXTREME_DOT_RADIUS: f32 = 3.5;
+
+/// Dot and value label at the series' highest and lowest point; the max label sits above
+/// its dot and the min label below.
+fn draw_extremes(
+    canvas: &skia::Canvas,
+    l: i32, t: i32, r: i32, b: i32,
+    x_axis: &Axis, y_axis: &Axis,
+    series: &Series,
+    opts: &RenderOptions,
+) {
+    let Some((min, max)) = series.extreme_points() else { return };
+    let xspan = (x_axis.max - x_axis.min).max(1e-9);
+    let ts = TimeScale::new(l as f32, x_axis.min, ((r - l) as f32) / (xspan as f32));
+    let vs = match y_axis.kind {
+        ScaleKind::Linear => ValueScale::new_linear(t as f32, b as f32, y_axis.min, y_axis.max),
+        ScaleKind::Log10 => ValueScale::new_log10(t as f32, b as f32, y_axis.min, y_axis.max),
+    };
+    let color = opts.theme.axis_label;
+    let mut dot = skia::Paint::default();
+    dot.set_anti_alias(antialias());
+    dot.set_style(skia::paint::Style::Fill);
+    dot.set_color(color);
+    let dpr = opts.dpr.max(0.5);
+    let text_size = 12.0 * dpr;
+    let shaper = text_shaper();
+    for (is_max, (x, y)) in [(true, max), (false, min)] {
+        let (cx, cy) = (ts.to_px(x), vs.to_px(y));
+        canvas.draw_circle((cx, cy), px(EXTREME_DOT_RADIUS), &dot);
+        if opts.draw_labels {
+            let label = y_tick_label(y, y_axis);
+            let advance = shaper.measure_width(&label, text_size, true);
+            let baseline = if is_max { cy - 8.0 * dpr } else { cy + 16.0 * dpr };
+            shaper.draw_left(canvas, &label, cx - advance * 0.5, baseline, text_size, color, true);
+        }
+    }
+}
+
+/// Horizontal padding inside the last-value badge, in logical px.
+const LAST_VALUE_BADGE_PAD: f32 = 4.0;
+
+/// Dashed line across the plot at `value`, with a badge over the Y labels showing it.
+/// Skipped when the value is outside the visible Y range.
+fn draw_last_value_line(
+    canvas: &skia::Canvas,
+    l: i32, t: i32, r: i32, b: i32,
+    y_axis: &Axis,
+    value: f64,
+    color: skia::Color,
+    opts: &RenderOptions,
+) {
+    let vs = match y_axis.kind {
+        ScaleKind::Linear => ValueScale::new_linear(t as f32, b as f32, y_axis.min, y_axis.max),
+        ScaleKind::Log10 => ValueScale::new_log10(t as f32, b as f32, y_axis.min, y_axis.max),
+    };
+    let ypx = vs.to_px(value);
+    if !(ypx >= t as f32 && ypx <= b as f32) { return; }
+    let y = if opts.crisp_lines { align_half(ypx) } else { ypx };
+
+    let mut paint = skia::Paint::default();
+    paint.set_anti_alias(antialias());
+    paint.set_style(skia::paint::Style::Stroke);
+    paint.set_stroke_width(px(1.0));
+    paint.set_color(color);
+    paint.set_path_effect(skia::PathEffect::dash(&[px(4.0), px(3.0)], 0.0));
+    canvas.draw_line((l as f32, y), (r as f32, y), &paint);
+
+    if opts.draw_labels {
+        let dpr = opts.dpr.max(0.5);
+        let text_size = 12.0 * dpr;
+        let shaper = text_shaper();
+        let label = y_tick_label(value, y_axis);
+        let bw = shaper.measure_width(&label, text_size, true) + 2.0 * LAST_VALUE_BADGE_PAD * dpr;
+        let bh = text_size + LAST_VALUE_BADGE_PAD * dpr;
+        let mut fill = skia::Paint::default();
+        fill.set_anti_alias(antialias());
+        fill.set_style(skia::paint::Style::Fill);
+        fill.set_color(color);
+        canvas.draw_rect(skia::Rect::from_xywh(l as f32 - bw, y - bh * 0.5, bw, bh), &fill);
+        shaper.draw_left(canvas, &label, l as f32 - bw + LAST_VALUE_BADGE_PAD * dpr, y + 4.0 * dpr, text_size, opts.theme.background, true);
+    }
+}
+
+/// Padding around overlay label text and its gap above the anchor point, in logical px.
+const OVERLAY_LABEL_PAD: f32 = 4.0;
+
+/// Badge for an `OverlayLabel`: crosshair-colored box centered on the label's x and
+/// resting just above its y, with the text in the background color.
+fn draw_overlay_label(
+    canvas: &skia::Canvas,
+    plot: RectI32,
+    x_axis: &Axis, y_axis: &Axis,
+    label: &OverlayLabel,
+    opts: &RenderOptions,
+) {
+    let xspan = (x_axis.max - x_axis.min).max(1e-9);
+    let ts = TimeScale::new(plot.left as f32, x_axis.min, ((plot.right - plot.left) as f32) / (xspan as f32));
+    let vs = match y_axis.kind {
+        ScaleKind::Linear => ValueScale::new_linear(plot.top as f32, plot.bottom as f32, y_axis.min, y_axis.max),
+        ScaleKind::Log10 => ValueScale::new_log10(plot.top as f32, plot.bottom as f32, y_axis.min, y_axis.max),
+    };
+    let dpr = opts.dpr.max(0.5);
+    let text_size = 12.0 * dpr;
+    let pad = OVERLAY_LABEL_PAD * dpr;
+    let shaper = text_shaper();
+    let bw = shaper.measure_width(&label.text, text_size, true) + 2.0 * pad;
+    let bh = text_size + pad;
+    let (cx, bottom) = (ts.to_px(label.x), vs.to_px(label.y) - pad);
+    let mut fill = skia::Paint::default();
+    fill.set_anti_alias(antialias());
+    fill.set_style(skia::paint::Style::Fill);
+    fill.set_color(opts.theme.crosshair);
+    canvas.draw_rect(skia::Rect::from_xywh(cx - bw * 0.5, bottom - bh, bw, bh), &fill);
+    shaper.draw_left(canvas, &label.text, cx - bw * 0.5 + pad, bottom - bh * 0.5 + pad, text_size, opts.theme.background, true);
+}
+
+/// Stroke `frame` just inside the `width` x `height` image edge.
+pub(crate) fn draw_frame(canvas: &skia::Canvas, width: i32, height: i32, frame: &Frame) {
+    let fw = px(frame.width);
+    if fw <= 0.0 { return; }
+    let mut paint = skia::Paint::default();
+    paint.set_anti_alias(antialias());
+    paint.set_style(skia::paint::Style::Stroke);
+    paint.set_stroke_width(fw);
+    paint.set_color(frame.color);
+    // Center the stroke half its width inside so the full width stays on the image
+    let half = fw * 0.5;
+    canvas.draw_rect(skia::Rect::from_ltrb(half, half, width as f32 - half, height as f32 - half), &paint);
+}
+
+/// Half-width of error-bar caps, in px.
+const ERROR_CAP_HALF: f32 = 4.0;
+
+fn draw_error_bar_series(
+    canvas: &skia::Canvas,
+    l: i32, t: i32, r: i32, b: i32,
+    x_axis: &Axis, y_axis: &Axis,
+    series: &Series,
+    theme: &Theme,
+) {
+    let data = &series.data_xyerr;
+    if data.is_empty() { return; }
+
+    let xspan = (x_axis.max - x_axis.min).max(1e-9);
+    let ts = TimeScale::new(l as f32, x_axis.min, ((r - l) as f32) / (xspan as f32));
+    let vs = match y_axis.kind {
+        ScaleKind::Linear => ValueScale::new_linear(t as f32, b as f32, y_axis.min, y_axis.max),
+        ScaleKind::Log10 => ValueScale::new_log10(t as f32, b as f32, y_axis.min, y_axis.max),
+    };
+
+    let mut stroke = skia::Paint::default();
+    stroke.set_anti_alias(antialias());
+    stroke.set_style(skia::paint::Style::Stroke);
+    stroke.set_stroke_width(px(1.0));
+    stroke.set_color(theme.line_stroke);
+
+    let mut dot = skia::Paint::default();
+    dot.set_anti_alias(antialias());
+    dot.set_style(skia::paint::Style::Fill);
+    dot.set_color(theme.line_stroke);
+
+    // Batch whiskers and caps into one path; points drawn on top
+    let mut path = skia::Path::new();
+    for &(xv, yv, e) in data {
+        let x = ts.to_px(xv);
+        let y_top = vs.to_px(yv + e.abs());
+        let y_bot = vs.to_px(yv - e.abs());
+        path.move_to((x, y_top));
+        path.line_to((x, y_bot));
+        for yc in [y_top, y_bot] {
+            path.move_to((x - ERROR_CAP_HALF, yc));
+            path.line_to((x + ERROR_CAP_HALF, yc));
+        }
+    }
+    canvas.draw_path(&path, &stroke);
+    for &(xv, yv, _) in data {
+        canvas.draw_circle((ts.to_px(xv), vs.to_px(yv)), px(3.0), &dot);
     }
-    canvas.draw_path(&path, &fill);
 }
 
 fn draw_baseline_series(
@@ -1170,6 +3059,7 @@ fn draw_baseline_series(
     x_axis: &Axis, y_axis: &Axis,
     series: &Series,
     theme: &Theme,
+    stack_bases: Option<&[f64]>,
 ) {
     let data = &series.data_xy;
     if data.len() < 2 { return; }
@@ -1186,37 +3076,216 @@ fn draw_baseline_series(
     let baseline_val = series.baseline.unwrap_or(0.0);
     let y0 = sy(baseline_val);
 
-    // Stroke path
-    let mut path = skia::Path::new();
-    path.move_to((sx(data[0].0), sy(data[0].1)));
-    for &(xv, yv) in data.iter().skip(1) {
-        path.line_to((sx(xv), sy(yv)));
+    let (path, area) = if let Some(bases) = stack_bases {
+        // Stacked layer: fill between the running total below and this layer's top
+        let spans = stacked_spans(data, Some(bases), baseline_val);
+        let mut path = skia::Path::new();
+        for (i, &(xv, _, top)) in spans.iter().enumerate() {
+            if i == 0 { path.move_to((sx(xv), sy(top))); } else { path.line_to((sx(xv), sy(top))); }
+        }
+        let mut area = path.clone();
+        for &(xv, from, _) in spans.iter().rev() {
+            area.line_to((sx(xv), sy(from)));
+        }
+        area.close();
+        (path, area)
+    } else {
+        // Stroke path
+        let mut path = skia::Path::new();
+        path.move_to((sx(data[0].0), sy(data[0].1)));
+        for &(xv, yv) in data.iter().skip(1) {
+            path.line_to((sx(xv), sy(yv)));
+        }
+
+        // Fill area to baseline (single-color area)
+        let mut area = skia::Path::new();
+        area.move_to((sx(data[0].0), y0));
+        for &(xv, yv) in data.iter() {
+            area.line_to((sx(xv), sy(yv)));
+        }
+        area.line_to((sx(data.last().unwrap().0), y0));
+        area.close();
+        (path, area)
+    };
+
+    let mut fill = skia::Paint::default();
+    fill.set_anti_alias(antialias());
+    fill.set_style(skia::paint::Style::Fill);
+    fill.set_color(series.color.unwrap_or(theme.baseline_fill));
+    if stack_bases.is_none() && series.color.is_none() && theme.baseline_fill_neg != theme.baseline_fill {
+        // Two-color area: clip the same path above and below the baseline
+        let (l, t, r, b) = (l as f32, t as f32, r as f32, b as f32);
+        for (clip, color) in [((t, y0.clamp(t, b)), theme.baseline_fill), ((y0.clamp(t, b), b), theme.baseline_fill_neg)] {
+            canvas.save();
+            canvas.clip_rect(skia::Rect::from_ltrb(l, clip.0, r, clip.1), None, None);
+            fill.set_color(color);
+            canvas.draw_path(&area, &fill);
+            canvas.restore();
+        }
+    } else {
+        canvas.draw_path(&area, &fill);
+    }
+
+    let mut stroke = skia::Paint::default();
+    stroke.set_anti_alias(antialias());
+    stroke.set_style(skia::paint::Style::Stroke);
+    stroke.set_stroke_width(px(2.0));
+    stroke.set_color(theme.baseline_stroke);
+    canvas.draw_path(&path, &stroke);
+}
+
+/// Area polygon (device px) between the line through `pts` and the baseline row `y0`,
+/// keeping only the part above it (`above`, smaller y) or below it. Crossings are
+/// interpolated in pixel space so both sides meet exactly on the baseline.
+fn baseline_side_polygon(pts: &[(f32, f32)], y0: f32, above: bool) -> Vec<(f32, f32)> {
+    let clamp = |y: f32| if above { y.min(y0) } else { y.max(y0) };
+    let (Some(first), Some(last)) = (pts.first(), pts.last()) else { return Vec::new() };
+    let mut out = vec![(first.0, y0)];
+    for (i, &(x, y)) in pts.iter().enumerate() {
+        if i > 0 {
+            let (px, py) = pts[i - 1];
+            if (py - y0) * (y - y0) < 0.0 {
+                out.push((px + (x - px) * (y0 - py) / (y - py), y0));
+            }
+        }
+        out.push((x, clamp(y)));
+    }
+    out.push((last.0, y0));
+    out
+}
+
+/// Theme for an overlay series: lines and error bars stroke in the series' own `color`,
+/// or the crosshair color when unset, so overlays stand apart from the base series.
+fn overlay_theme(s: &Series, theme: &Theme) -> Theme {
+    Theme { line_stroke: s.color.unwrap_or(theme.crosshair), ..*theme }
+}
+
+/// Draw one series with the routine for its type, plus its extreme markers. `bases` are
+/// the stacking bases from `Chart::stack_bases` (None for unstacked and overlay series).
+fn draw_series(
+    canvas: &skia::Canvas,
+    plot: RectI32,
+    x_axis: &Axis, y_axis: &Axis,
+    s: &Series,
+    bases: Option<&[f64]>,
+    theme: &Theme,
+    opts: &RenderOptions,
+) {
+    let (l, t, r, b) = (plot.left, plot.top, plot.right, plot.bottom);
+    match s.series_type {
+        SeriesType::Line => draw_line_series(canvas, l, t, r, b, x_axis, y_axis, s, theme),
+        SeriesType::Candlestick => draw_candle_series(
+            canvas, l, t, r, b, x_axis, y_axis, s, theme, opts.candle_style, opts.candle_pattern_tint, opts.gradient_fills,
+        ),
+        SeriesType::Bar => draw_bar_series(canvas, l, t, r, b, x_axis, y_axis, s, theme),
+        SeriesType::Histogram => draw_histogram_series(canvas, l, t, r, b, x_axis, y_axis, s, theme, opts.gradient_fills),
+        SeriesType::Column => draw_column_series(canvas, l, t, r, b, x_axis, y_axis, s, theme, bases, opts.gradient_fills),
+        SeriesType::Baseline => draw_baseline_series(canvas, l, t, r, b, x_axis, y_axis, s, theme, bases),
+        SeriesType::ErrorBar => draw_error_bar_series(canvas, l, t, r, b, x_axis, y_axis, s, theme),
+        SeriesType::Band => draw_band_series(canvas, l, t, r, b, x_axis, y_axis, s, theme),
+    }
+    if s.highlight_extremes {
+        draw_extremes(canvas, l, t, r, b, x_axis, y_axis, s, opts);
     }
+}
+
+/// Fill the closed data-space `polygon` (from `FillBetween::polygon`) inside the plot.
+fn draw_fill_between(
+    canvas: &skia::Canvas,
+    l: i32, t: i32, r: i32, b: i32,
+    x_axis: &Axis, y_axis: &Axis,
+    polygon: &[(f64, f64)],
+    color: skia::Color,
+) {
+    let Some(&(x0, y0)) = polygon.first() else { return };
+    let xspan = (x_axis.max - x_axis.min).max(1e-9);
+    let ts = TimeScale::new(l as f32, x_axis.min, ((r - l) as f32) / (xspan as f32));
+    let vs = match y_axis.kind {
+        ScaleKind::Linear => ValueScale::new_linear(t as f32, b as f32, y_axis.min, y_axis.max),
+        ScaleKind::Log10 => ValueScale::new_log10(t as f32, b as f32, y_axis.min, y_axis.max),
+    };
 
-    // Fill area to baseline (single-color area)
     let mut area = skia::Path::new();
-    area.move_to((sx(data[0].0), y0));
-    for &(xv, yv) in data.iter() {
-        area.line_to((sx(xv), sy(yv)));
+    area.move_to((ts.to_px(x0), vs.to_px(y0)));
+    for &(xv, yv) in &polygon[1..] {
+        area.line_to((ts.to_px(xv), vs.to_px(yv)));
     }
-    area.line_to((sx(data.last().unwrap().0), y0));
     area.close();
 
     let mut fill = skia::Paint::default();
-    fill.set_anti_alias(true);
+    fill.set_anti_alias(antialias());
     fill.set_style(skia::paint::Style::Fill);
-    fill.set_color(theme.baseline_fill);
+    fill.set_color(color);
     canvas.draw_path(&area, &fill);
+}
 
-    let mut stroke = skia::Paint::default();
-    stroke.set_anti_alias(true);
-    stroke.set_style(skia::paint::Style::Stroke);
-    stroke.set_stroke_width(2.0);
-    stroke.set_color(theme.baseline_stroke);
-    canvas.draw_path(&path, &stroke);
+/// SVG path for the closed region between a band's upper (left to right) and lower (right to left) lines.
+fn band_area_svg_path(data: &[(f64, f64, f64)], sx: &dyn Fn(f64) -> f32, sy: &dyn Fn(f64) -> f32) -> String {
+    let mut d = String::new();
+    for (i, &(xv, _, hi)) in data.iter().enumerate() {
+        d.push_str(&format!("{}{} {}", if i == 0 { "M " } else { " L " }, sx(xv), sy(hi)));
+    }
+    for &(xv, lo, _) in data.iter().rev() {
+        d.push_str(&format!(" L {} {}", sx(xv), sy(lo)));
+    }
+    d.push_str(" Z");
+    d
+}
+
+fn draw_band_series(
+    canvas: &skia::Canvas,
+    l: i32, t: i32, r: i32, b: i32,
+    x_axis: &Axis, y_axis: &Axis,
+    series: &Series,
+    theme: &Theme,
+) {
+    let data = &series.data_band;
+    if data.len() < 2 { return; }
+
+    let xspan = (x_axis.max - x_axis.min).max(1e-9);
+    let ts = TimeScale::new(l as f32, x_axis.min, ((r - l) as f32) / (xspan as f32));
+    let vs = match y_axis.kind {
+        ScaleKind::Linear => ValueScale::new_linear(t as f32, b as f32, y_axis.min, y_axis.max),
+        ScaleKind::Log10 => ValueScale::new_log10(t as f32, b as f32, y_axis.min, y_axis.max),
+    };
+    let sx = |x: f64| -> f32 { ts.to_px(x) };
+    let sy = |y: f64| -> f32 { vs.to_px(y) };
+
+    // Closed region: upper edge forward, lower edge back
+    let mut area = skia::Path::new();
+    let mut upper = skia::Path::new();
+    let mut lower = skia::Path::new();
+    area.move_to((sx(data[0].0), sy(data[0].2)));
+    upper.move_to((sx(data[0].0), sy(data[0].2)));
+    lower.move_to((sx(data[0].0), sy(data[0].1)));
+    for &(xv, lo, hi) in data.iter().skip(1) {
+        area.line_to((sx(xv), sy(hi)));
+        upper.line_to((sx(xv), sy(hi)));
+        lower.line_to((sx(xv), sy(lo)));
+    }
+    for &(xv, lo, _) in data.iter().rev() {
+        area.line_to((sx(xv), sy(lo)));
+    }
+    area.close();
+
+    let mut fill = skia::Paint::default();
+    fill.set_anti_alias(antialias());
+    fill.set_style(skia::paint::Style::Fill);
+    fill.set_color(theme.band_fill);
+    canvas.draw_path(&area, &fill);
+
+    if series.band_strokes {
+        let mut stroke = skia::Paint::default();
+        stroke.set_anti_alias(antialias());
+        stroke.set_style(skia::paint::Style::Stroke);
+        stroke.set_stroke_width(px(1.0));
+        stroke.set_color(theme.band_stroke);
+        canvas.draw_path(&upper, &stroke);
+        canvas.draw_path(&lower, &stroke);
+    }
 }
 
-fn draw_tooltip(
+pub(crate) fn draw_tooltip(
     canvas: &skia::Canvas,
     l: i32, t: i32, r: i32, b: i32,
     x_axis: &Axis, y_axis: &Axis,
@@ -1245,33 +3314,48 @@ fn draw_tooltip(
     let title = if let Some(_) = detect_time_like(x_axis.min, x_axis.max) {
         format!("x {}", format_time_tick(xq, x_axis.min, x_axis.max))
     } else {
-        format!("x {}", format_tick(xq, x_axis.min, x_axis.max))
+        format!("x {}", x_axis.format_value(xq))
     };
     lines.push(title);
 
     match s.series_type {
-        SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline => {
+        SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline | SeriesType::Column => {
             if let Some((_, &(xv, yv))) = s.data_xy.iter().enumerate()
                 .min_by(|a, b| (a.1 .0 - xq).abs().partial_cmp(&(b.1 .0 - xq).abs()).unwrap_or(std::cmp::Ordering::Equal))
             {
-                lines.push(format!("y {}", format_tick(yv, y_axis.min, y_axis.max)));
+                lines.push(format!("y {}", y_axis.format_value(yv)));
                 let ypx = to_px_y(yv);
                 // Small marker to highlight nearest point
                 let mut p = skia::Paint::default();
-                p.set_anti_alias(true);
+                p.set_anti_alias(antialias());
                 p.set_style(skia::paint::Style::Fill);
                 p.set_color(opts.theme.line_stroke);
-                canvas.draw_circle((ts.to_px(xv), ypx), 3.0, &p);
+                canvas.draw_circle((ts.to_px(xv), ypx), px(3.0), &p);
             }
         }
         SeriesType::Candlestick | SeriesType::Bar => {
             if let Some(c) = s.data_ohlc.iter()
                 .min_by(|a, b| (a.t - xq).abs().partial_cmp(&(b.t - xq).abs()).unwrap_or(std::cmp::Ordering::Equal))
             {
-                lines.push(format!("O {}", format_tick(c.o, y_axis.min, y_axis.max)));
-                lines.push(format!("H {}", format_tick(c.h, y_axis.min, y_axis.max)));
-                lines.push(format!("L {}", format_tick(c.l, y_axis.min, y_axis.max)));
-                lines.push(format!("C {}", format_tick(c.c, y_axis.min, y_axis.max)));
+                lines.push(format!("O {}", y_axis.format_value(c.o)));
+                lines.push(format!("H {}", y_axis.format_value(c.h)));
+                lines.push(format!("L {}", y_axis.format_value(c.l)));
+                lines.push(format!("C {}", y_axis.format_value(c.c)));
+            }
+        }
+        SeriesType::ErrorBar => {
+            if let Some(&(_, yv, e)) = s.data_xyerr.iter()
+                .min_by(|a, b| (a.0 - xq).abs().partial_cmp(&(b.0 - xq).abs()).unwrap_or(std::cmp::Ordering::Equal))
+            {
+                lines.push(format!("y {} ± {}", y_axis.format_value(yv), y_axis.format_value(e.abs())));
+            }
+        }
+        SeriesType::Band => {
+            if let Some(&(_, lo, hi)) = s.data_band.iter()
+                .min_by(|a, b| (a.0 - xq).abs().partial_cmp(&(b.0 - xq).abs()).unwrap_or(std::cmp::Ordering::Equal))
+            {
+                lines.push(format!("U {}", y_axis.format_value(hi)));
+                lines.push(format!("L {}", y_axis.format_value(lo)));
             }
         }
     }
@@ -1279,9 +3363,9 @@ fn draw_tooltip(
     // Compose tooltip box near cursor
     let mut paint_text = skia::Paint::default();
     paint_text.set_color(opts.theme.axis_label);
-    paint_text.set_anti_alias(true);
+    paint_text.set_anti_alias(antialias());
     let text_size = 12.0 * opts.dpr.max(0.5);
-    let shaper = TextShaper::new();
+    let shaper = text_shaper();
 
     let padding = 6.0_f32 * opts.dpr.max(0.5);
     let mut w = 0f32;
@@ -1306,14 +3390,14 @@ fn draw_tooltip(
     let dark = opts.theme.name == "dark";
     let bg_col = if dark { skia::Color::from_argb(200, 32, 32, 36) } else { skia::Color::from_argb(220, 240, 240, 244) };
     bg.set_color(bg_col);
-    bg.set_anti_alias(true);
+    bg.set_anti_alias(antialias());
     canvas.draw_rect(rect, &bg);
 
     // Border
     let mut border = skia::Paint::default();
     border.set_style(skia::paint::Style::Stroke);
     border.set_color(opts.theme.axis_line);
-    border.set_stroke_width(1.0);
+    border.set_stroke_width(px(1.0));
     canvas.draw_rect(rect, &border);
 
     // Text lines
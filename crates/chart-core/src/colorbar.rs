@@ -0,0 +1,78 @@
+// File: crates/chart-core/src/colorbar.rs
+// Summary: Standalone vertical color bar (gradient strip with min/mid/max labels) for heatmap-style legends.
+
+use anyhow::Result;
+use skia_safe as skia;
+
+use crate::axis::format_tick;
+use crate::chart::RenderOptions;
+use crate::text::TextShaper;
+
+/// Strip width in logical pixels (scaled by DPR).
+const COLORBAR_WIDTH: f32 = 20.0;
+/// Tick length to the right of the strip, in logical pixels.
+const COLORBAR_TICK: f32 = 4.0;
+
+/// Render a vertical color bar and return PNG-encoded bytes.
+///
+/// The strip starts at `opts.insets.left` (scaled by `opts.dpr`), spans the height between the top and bottom
+/// insets and maps `min` (bottom) to `max` (top) through `gradient`, whose colors are
+/// spaced evenly from low to high values; a single color draws a flat strip. Ticks and
+/// labels for min/mid/max go to the right of the strip when `opts.draw_labels` is set.
+pub fn render_colorbar(opts: &RenderOptions, min: f64, max: f64, gradient: &[skia::Color]) -> Result<Vec<u8>> {
+    let Some(&low) = gradient.first() else { anyhow::bail!("colorbar gradient needs at least one color") };
+    let mut surface = skia::surfaces::raster_n32_premul((opts.width, opts.height))
+        .ok_or_else(|| anyhow::anyhow!("failed to create raster surface"))?;
+    let canvas = surface.canvas();
+    canvas.clear(opts.theme.background);
+
+    let dpr = opts.dpr.max(0.5);
+    let insets = opts.insets.scaled(dpr);
+    let left = insets.left as f32;
+    let right = (left + COLORBAR_WIDTH * dpr).min((opts.width - insets.right as i32) as f32);
+    let top = insets.top as f32;
+    let bottom = (opts.height - insets.bottom as i32) as f32;
+    if right <= left || bottom <= top { anyhow::bail!("colorbar has no room inside the insets"); }
+    let strip = skia::Rect::from_ltrb(left, top, right, bottom);
+
+    // Gradient runs bottom (low) to top (high); endpoints sit on the outer pixel centers
+    // so the first and last rows show the end colors exactly.
+    let mut fill = skia::Paint::default();
+    fill.set_style(skia::paint::Style::Fill);
+    fill.set_color(low);
+    fill.set_shader(skia::Shader::linear_gradient(
+        ((left, bottom - 0.5), (left, top + 0.5)),
+        gradient,
+        None,
+        skia::TileMode::Clamp,
+        None,
+        None,
+    ));
+    canvas.draw_rect(strip, &fill);
+
+    if opts.draw_labels {
+        let mut tick = skia::Paint::default();
+        tick.set_anti_alias(false);
+        tick.set_style(skia::paint::Style::Stroke);
+        tick.set_stroke_width(1.0);
+        tick.set_color(opts.theme.tick);
+        let shaper = if opts.deterministic { TextShaper::bundled() } else { TextShaper::new() };
+        let text_size = 12.0 * dpr;
+        let mid = min + (max - min) * 0.5;
+        for (v, y) in [(min, bottom), (mid, (top + bottom) * 0.5), (max, top)] {
+            let y = y.round() - 0.5;
+            canvas.draw_line((right, y), (right + COLORBAR_TICK * dpr, y), &tick);
+            let label = format_tick(v, min, max);
+            // draw_left takes a baseline; center the label on the tick
+            let x = right + (COLORBAR_TICK + 4.0) * dpr;
+            shaper.draw_left(canvas, &label, x, y + text_size * 0.35, text_size, opts.theme.axis_label, true);
+        }
+    }
+
+    let image = surface.image_snapshot();
+    #[allow(deprecated)]
+    let data = image
+        .encode_to_data(skia::EncodedImageFormat::PNG)
+        .ok_or_else(|| anyhow::anyhow!("encode PNG failed"))?;
+    Ok(data.as_bytes().to_vec())
+}
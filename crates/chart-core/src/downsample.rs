@@ -1,20 +1,26 @@
 // File: crates/chart-core/src/downsample.rs
-// Summary: Downsampling utilities (LTTB for XY; OHLC bucket aggregation).
+// Summary: Downsampling utilities (LTTB/M4 for XY; OHLC bucket and time-window aggregation).
 
 use crate::series::Candle;
 
 /// Largest-Triangle-Three-Buckets downsampling for XY series.
 /// Returns up to `threshold` points preserving overall shape.
 pub fn lttb(points: &[(f64, f64)], threshold: usize) -> Vec<(f64, f64)> {
+    lttb_indices(points, threshold).into_iter().map(|i| points[i]).collect()
+}
+
+/// Indices (strictly increasing, into `points`) selected by LTTB, so parallel arrays
+/// such as volumes can be subsampled consistently with the XY data.
+pub fn lttb_indices(points: &[(f64, f64)], threshold: usize) -> Vec<usize> {
     let n = points.len();
     if threshold == 0 || n == 0 { return Vec::new(); }
-    if threshold >= n || n <= 2 { return points.to_vec(); }
-    if threshold == 1 { return vec![points[0]]; }
+    if threshold >= n || n <= 2 { return (0..n).collect(); }
+    if threshold == 1 { return vec![0]; }
 
     let bucket_size = (n - 2) as f64 / (threshold - 2) as f64;
     let mut sampled = Vec::with_capacity(threshold);
     // Always include first
-    sampled.push(points[0]);
+    sampled.push(0);
 
     let mut a = 0usize; // a is the index of the selected point from previous bucket
 
@@ -54,15 +60,56 @@ pub fn lttb(points: &[(f64, f64)], threshold: usize) -> Vec<(f64, f64)> {
                 max_idx = k;
             }
         }
-        sampled.push(points[max_idx]);
+        sampled.push(max_idx);
         a = max_idx;
     }
 
     // Always include last
-    sampled.push(points[n - 1]);
+    sampled.push(n - 1);
     sampled
 }
 
+/// M4 downsampling for XY series sorted by x: the x range is split into `target / 4`
+/// equal-width columns and each column keeps its first, last, min, and max points (in
+/// original order). Unlike LTTB, every local extreme survives, so spikes stay visible.
+/// Returns at most `target` points; a `target` below 4 still keeps the first and last
+/// point so the series does not vanish.
+pub fn m4(points: &[(f64, f64)], target: usize) -> Vec<(f64, f64)> {
+    let n = points.len();
+    if target >= n { return points.to_vec(); }
+    let buckets = target / 4;
+    if buckets == 0 {
+        return if n == 1 { points.to_vec() } else { vec![points[0], points[n - 1]] };
+    }
+
+    let x0 = points[0].0;
+    let span = points[n - 1].0 - x0;
+    let column = |x: f64| -> usize {
+        if span > 0.0 { (((x - x0) / span) * buckets as f64).floor().clamp(0.0, (buckets - 1) as f64) as usize } else { 0 }
+    };
+
+    let mut out = Vec::with_capacity(target);
+    let mut start = 0usize;
+    while start < n {
+        let col = column(points[start].0);
+        let mut end = start + 1;
+        while end < n && column(points[end].0) == col { end += 1; }
+        let (mut lo, mut hi) = (start, start);
+        for k in start..end {
+            if points[k].1 < points[lo].1 { lo = k; }
+            if points[k].1 > points[hi].1 { hi = k; }
+        }
+        let mut keep = [start, lo, hi, end - 1];
+        keep.sort_unstable();
+        let mut last = None;
+        for k in keep {
+            if last != Some(k) { out.push(points[k]); last = Some(k); }
+        }
+        start = end;
+    }
+    out
+}
+
 /// Aggregate OHLC candles into fixed-size buckets of `bucket` width.
 /// For each bucket: open=first.open, close=last.close, high=max high, low=min low, t=first.t
 pub fn aggregate_ohlc_buckets(data: &[Candle], bucket: usize) -> Vec<Candle> {
@@ -85,3 +132,29 @@ pub fn aggregate_ohlc_buckets(data: &[Candle], bucket: usize) -> Vec<Candle> {
     }
     out
 }
+
+/// Aggregate OHLC candles into fixed time windows of `interval_secs` (e.g. 3600.0 for 1h),
+/// treating `Candle.t` as epoch seconds. Windows are aligned to multiples of the interval
+/// and each output candle is stamped with its window start. Input must be sorted by `t`;
+/// windows without candles produce no output and candles with non-finite `t` are skipped.
+pub fn aggregate_ohlc_by_time(data: &[Candle], interval_secs: f64) -> Vec<Candle> {
+    if !(interval_secs > 0.0 && interval_secs.is_finite()) { return data.to_vec(); }
+    let mut out: Vec<Candle> = Vec::new();
+    let mut current: Option<(f64, Candle)> = None; // (window key, running aggregate)
+    for c in data.iter().filter(|c| c.t.is_finite()) {
+        let key = (c.t / interval_secs).floor();
+        match current.as_mut() {
+            Some((k, agg)) if *k == key => {
+                agg.h = agg.h.max(c.h);
+                agg.l = agg.l.min(c.l);
+                agg.c = c.c;
+            }
+            _ => {
+                if let Some((_, agg)) = current.take() { out.push(agg); }
+                current = Some((key, Candle { t: key * interval_secs, ..*c }));
+            }
+        }
+    }
+    if let Some((_, agg)) = current { out.push(agg); }
+    out
+}
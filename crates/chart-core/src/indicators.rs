@@ -0,0 +1,37 @@
+// File: crates/chart-core/src/indicators.rs
+// Summary: Reusable indicator math (true range, ATR) shared by overlays and plugins.
+
+use crate::series::Candle;
+
+/// True range per candle: max(h - l, |h - prev_c|, |l - prev_c|).
+/// The first candle has no previous close, so its TR is h - l.
+pub fn true_range(candles: &[Candle]) -> Vec<f64> {
+    let mut out = Vec::with_capacity(candles.len());
+    for (i, c) in candles.iter().enumerate() {
+        let hl = c.h - c.l;
+        let tr = if i == 0 {
+            hl
+        } else {
+            let pc = candles[i - 1].c;
+            hl.max((c.h - pc).abs()).max((c.l - pc).abs())
+        };
+        out.push(tr);
+    }
+    out
+}
+
+/// Average true range with Wilder smoothing, as (t, atr).
+/// Seeded with the simple mean of the first `period` TRs; output starts at index `period - 1`.
+pub fn atr(candles: &[Candle], period: usize) -> Vec<(f64, f64)> {
+    if period == 0 || candles.len() < period { return Vec::new(); }
+    let tr = true_range(candles);
+    let p = period as f64;
+    let mut out = Vec::with_capacity(candles.len() - period + 1);
+    let mut cur = tr[..period].iter().sum::<f64>() / p;
+    out.push((candles[period - 1].t, cur));
+    for i in period..candles.len() {
+        cur = (cur * (p - 1.0) + tr[i]) / p;
+        out.push((candles[i].t, cur));
+    }
+    out
+}
@@ -13,12 +13,27 @@ pub mod theme;
 pub mod text;
 pub mod downsample;
 pub mod plugin;
+pub mod indicators;
+pub mod multi;
+pub mod pnf;
+pub mod colorbar;
+pub mod png;
+pub mod source;
+pub mod renderer;
+#[cfg(feature = "serde")]
+mod spec;
 
-pub use chart::{Chart, RenderOptions};
-pub use series::{Series, SeriesType};
-pub use axis::Axis;
-pub use view::ViewState;
-pub use theme::Theme;
+pub use chart::{BackgroundFit, CandleStyle, Chart, ChartBuilder, CrosshairMode, FillBetween, Frame, HitResult, Orientation, RenderOptions, RenderOptionsBuilder, Watermark};
+pub use series::{BarAlign, CandleColorFn, CandlePattern, GapMode, MarkerShape, Series, SeriesError, SeriesType};
+pub use axis::{Axis, LabelFormat};
+pub use multi::{render_grid_to_png_bytes, MultiChart, PanelCrosshair};
+pub use view::{Animator, FollowLatest, PanInertia, ViewState, ZoomConfig};
+pub use theme::{Theme, ThemeBuilder};
 pub use text::TextShaper;
-pub use downsample::{lttb, aggregate_ohlc_buckets};
-pub use plugin::{Indicator, IndicatorParams, Overlay, SmaOverlay, OverlayEvent, HvLineOverlay};
+pub use downsample::{lttb, lttb_indices, m4, aggregate_ohlc_buckets, aggregate_ohlc_by_time};
+pub use pnf::{point_and_figure, PointAndFigure};
+pub use colorbar::render_colorbar;
+pub use png::{read_text_chunks, PngMetadata};
+pub use source::{DataSource, LiveFeed, ReplaySource};
+pub use renderer::Renderer;
+pub use plugin::{Indicator, IndicatorParams, Overlay, OverlayParams, PaneTarget, RsiOverlay, SmaOverlay, BollingerOverlay, WmaIndicator, HmaIndicator, OverlayEvent, EventResult, HvLineOverlay, Trendline, TrendlineOverlay, OverlayLabel, RulerMeasure, RulerOverlay, DonchianOverlay};
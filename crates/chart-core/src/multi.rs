@@ -0,0 +1,220 @@
+// File: crates/chart-core/src/multi.rs
+// Summary: Stacked multi-pane layout (e.g., price above volume/RSI) sharing one X axis,
+//          plus grid "sprite sheets" of independent charts.
+
+use anyhow::Result;
+use skia_safe as skia;
+
+use crate::chart::{draw_background_image, draw_frame, draw_tooltip, draw_watermark, Chart, RenderFrame, RenderOptions};
+use crate::geometry::RectI32;
+use crate::plugin::PaneTarget;
+use crate::Axis;
+
+/// Vertical gap between stacked panels, in logical pixels (scaled by DPR).
+const PANEL_GAP: f32 = 8.0;
+
+/// Crosshair geometry for one panel: the shared vertical line and, for the panel
+/// under the cursor, the horizontal line.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PanelCrosshair {
+    pub rect: RectI32,
+    pub x_px: f32,
+    pub y_px: Option<f32>,
+}
+
+/// Vertically stacked charts. Each panel keeps its own Y axis; the X range is shared
+/// and X tick labels are only drawn under the bottom panel.
+#[derive(Default)]
+pub struct MultiChart {
+    pub panels: Vec<(Chart, f32)>, // (chart, height fraction); fractions are normalized
+}
+
+impl MultiChart {
+    pub fn new() -> Self {
+        Self { panels: Vec::new() }
+    }
+
+    /// Append a panel below the existing ones.
+    pub fn add_panel(&mut self, chart: Chart, height_frac: f32) {
+        self.panels.push((chart, height_frac));
+    }
+
+    /// `chart` as the top panel (height fraction 1.0) plus one panel of `pane_frac` per
+    /// overlay targeting `PaneTarget::Separate`, in overlay order. Each pane holds that
+    /// overlay's series as computed now, on the overlay's fixed Y range.
+    pub fn with_overlay_panes(chart: Chart, pane_frac: f32) -> Self {
+        let mut multi = Self::new();
+        let mut panes = Vec::new();
+        for ov in &chart.overlays {
+            if let PaneTarget::Separate { y_min, y_max } = ov.target_pane() {
+                let pane = Chart { series: ov.compute(&chart), x_axis: chart.x_axis.clone(), y_axis: Axis::new(ov.id(), y_min, y_max), ..Chart::new() };
+                panes.push(pane);
+            }
+        }
+        multi.add_panel(chart, 1.0);
+        for pane in panes {
+            multi.add_panel(pane, pane_frac);
+        }
+        multi
+    }
+
+    /// Union of all panel X ranges; used as the common horizontal axis.
+    pub fn shared_x_axis(&self) -> Axis {
+        let mut x_min = f64::INFINITY;
+        let mut x_max = f64::NEG_INFINITY;
+        for (c, _) in &self.panels {
+            x_min = x_min.min(c.x_axis.min);
+            x_max = x_max.max(c.x_axis.max);
+        }
+        let mut axis = self.panels.last().map(|(c, _)| c.x_axis.clone()).unwrap_or_else(Axis::default_x);
+        if x_min.is_finite() && x_max.is_finite() {
+            axis.min = x_min;
+            axis.max = x_max;
+        }
+        axis
+    }
+
+    /// Plot rectangles (device px) for each panel, top to bottom.
+    /// Insets frame the whole stack; panels split the remaining height by fraction.
+    pub fn panel_rects(&self, opts: &RenderOptions) -> Vec<RectI32> {
+        let n = self.panels.len();
+        if n == 0 { return Vec::new(); }
+        let mut insets = opts.insets;
+        // auto_left_inset: align all panels to the widest Y labels of any panel
+        if opts.auto_left_inset {
+            insets.left = self.panels.iter().map(|(c, _)| c.required_left_inset(opts)).max().unwrap_or(insets.left);
+        }
+        let insets = insets.scaled(opts.dpr);
+        let l = insets.left as i32;
+        let r = opts.width - insets.right as i32;
+        let t = insets.top as f32;
+        let b = (opts.height - insets.bottom as i32) as f32;
+        let gap = PANEL_GAP * opts.dpr.max(0.5);
+        let avail = (b - t - gap * (n - 1) as f32).max(n as f32);
+
+        let total: f32 = self.panels.iter().map(|(_, f)| f.max(0.0)).sum();
+        let mut rects = Vec::with_capacity(n);
+        let mut y = t;
+        for (i, (_, f)) in self.panels.iter().enumerate() {
+            let frac = if total > 0.0 { f.max(0.0) / total } else { 1.0 / n as f32 };
+            let top = y.round() as i32;
+            // last panel absorbs rounding so the stack ends exactly at the bottom inset
+            let bottom = if i + 1 == n { b as i32 } else { (y + avail * frac).round() as i32 };
+            rects.push(RectI32::from_ltrb(l, top, r, bottom.max(top + 1)));
+            y += avail * frac + gap;
+        }
+        rects
+    }
+
+    /// Per-panel crosshair layout for `opts.crosshair`. The x pixel is clamped once to the
+    /// shared plot width so every panel draws its vertical line at the same column.
+    pub fn crosshair_layout(&self, opts: &RenderOptions) -> Vec<PanelCrosshair> {
+        let Some((cx, cy)) = opts.crosshair else { return Vec::new() };
+        let rects = self.panel_rects(opts);
+        let Some(first) = rects.first() else { return Vec::new() };
+        let ix = cx.clamp(first.left as f32, (first.right - 1) as f32);
+        rects
+            .into_iter()
+            .map(|rect| {
+                let inside = cy >= rect.top as f32 && cy < rect.bottom as f32;
+                PanelCrosshair { rect, x_px: ix, y_px: if inside { Some(cy) } else { None } }
+            })
+            .collect()
+    }
+
+    /// Draw all panels onto an existing canvas.
+    pub fn draw_onto_canvas(&self, canvas: &skia::Canvas, opts: &RenderOptions) {
+        let _frame = RenderFrame::enter(opts);
+        canvas.clear(opts.theme.background);
+        draw_background_image(canvas, opts);
+        let x_axis = self.shared_x_axis();
+        let rects = self.panel_rects(opts);
+        // One watermark centered over the whole stack
+        if let (Some(wm), Some(first), Some(bottom)) = (&opts.watermark, rects.first(), rects.last()) {
+            let stack = RectI32::from_ltrb(first.left, first.top, first.right, bottom.bottom);
+            draw_watermark(canvas, stack, wm, &opts.theme);
+        }
+        let last = rects.len().saturating_sub(1);
+        for (i, ((chart, _), rect)) in self.panels.iter().zip(rects).enumerate() {
+            chart.draw_plot(canvas, opts, rect, &x_axis, &chart.y_axis, i == last);
+        }
+
+        self.draw_crosshair(canvas, opts, &x_axis);
+        if let Some(frame) = &opts.frame {
+            draw_frame(canvas, opts.width, opts.height, frame);
+        }
+    }
+
+    /// Synchronized crosshair: one vertical column through all panels.
+    fn draw_crosshair(&self, canvas: &skia::Canvas, opts: &RenderOptions, x_axis: &Axis) {
+        let layout = self.crosshair_layout(opts);
+        if layout.is_empty() { return; }
+        let mut paint = skia::Paint::default();
+        paint.set_anti_alias(false);
+        paint.set_style(skia::paint::Style::Stroke);
+        paint.set_color(opts.theme.crosshair);
+        paint.set_stroke_width(opts.dpr.max(0.5));
+        for (ch, (chart, _)) in layout.iter().zip(&self.panels) {
+            let r = ch.rect;
+            if opts.crosshair_mode.vertical() {
+                canvas.draw_line((ch.x_px, r.top as f32), (ch.x_px, r.bottom as f32), &paint);
+            }
+            if let Some(iy) = ch.y_px.filter(|_| opts.crosshair_mode.horizontal()) {
+                canvas.draw_line((r.left as f32, iy), (r.right as f32, iy), &paint);
+            }
+            if opts.show_tooltip {
+                // Panels not under the cursor anchor their tooltip at the panel top
+                let iy = ch.y_px.unwrap_or(r.top as f32);
+                draw_tooltip(canvas, r.left, r.top, r.right, r.bottom, x_axis, &chart.y_axis, &chart.series, ch.x_px, iy, opts);
+            }
+        }
+    }
+
+    /// Render the stacked panels and return PNG-encoded bytes (headless).
+    pub fn render_to_png_bytes(&self, opts: &RenderOptions) -> Result<Vec<u8>> {
+        let mut surface = skia::surfaces::raster_n32_premul((opts.width, opts.height))
+            .ok_or_else(|| anyhow::anyhow!("failed to create raster surface"))?;
+        self.draw_onto_canvas(surface.canvas(), opts);
+        let image = surface.image_snapshot();
+        #[allow(deprecated)]
+        let data = image
+            .encode_to_data(skia::EncodedImageFormat::PNG)
+            .ok_or_else(|| anyhow::anyhow!("encode PNG failed"))?;
+        Ok(data.as_bytes().to_vec())
+    }
+}
+
+/// Render `charts` row-major into a grid of `cols` columns with `cell_w` x `cell_h` cells
+/// on one surface and return PNG-encoded bytes. Each cell is drawn with `opts` resized to
+/// the cell; unused cells in the last row keep the theme background.
+pub fn render_grid_to_png_bytes(charts: &[Chart], opts: &RenderOptions, cols: usize, cell_w: i32, cell_h: i32) -> Result<Vec<u8>> {
+    if charts.is_empty() || cols == 0 || cell_w <= 0 || cell_h <= 0 {
+        anyhow::bail!("grid needs at least one chart, one column and a positive cell size");
+    }
+    let rows = charts.len().div_ceil(cols);
+    let (width, height) = (cell_w * cols as i32, cell_h * rows as i32);
+    let mut surface = skia::surfaces::raster_n32_premul((width, height))
+        .ok_or_else(|| anyhow::anyhow!("failed to create raster surface"))?;
+    let canvas = surface.canvas();
+    canvas.clear(opts.theme.background);
+
+    let mut cell_opts = opts.clone();
+    cell_opts.width = cell_w;
+    cell_opts.height = cell_h;
+    for (i, chart) in charts.iter().enumerate() {
+        let (x, y) = ((i % cols) as i32 * cell_w, (i / cols) as i32 * cell_h);
+        canvas.save();
+        canvas.translate((x as f32, y as f32));
+        // Clip so each chart's background clear stays inside its cell
+        canvas.clip_rect(skia::Rect::from_wh(cell_w as f32, cell_h as f32), None, None);
+        chart.draw_onto_canvas(canvas, &cell_opts);
+        canvas.restore();
+    }
+
+    let image = surface.image_snapshot();
+    #[allow(deprecated)]
+    let data = image
+        .encode_to_data(skia::EncodedImageFormat::PNG)
+        .ok_or_else(|| anyhow::anyhow!("encode PNG failed"))?;
+    Ok(data.as_bytes().to_vec())
+}
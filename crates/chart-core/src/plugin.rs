@@ -1,9 +1,9 @@
 // File: crates/chart-core/src/plugin.rs
 // Summary: Plugin trait definitions (overlay & indicator) with minimal, renderer-agnostic API.
 
-use crate::series::{Series, SeriesType};
+use crate::series::{Candle, Series, SeriesType};
 use crate::Chart;
-use std::cell::RefCell;
+use std::sync::Mutex;
 
 #[derive(Clone, Copy, Debug)]
 pub struct IndicatorParams {
@@ -14,6 +14,24 @@ impl Default for IndicatorParams {
     fn default() -> Self { Self { period: 14 } }
 }
 
+/// Tunable overlay settings, applied with `Chart::set_overlay_params` so a frontend can
+/// change e.g. an indicator's period without recreating the overlay.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OverlayParams {
+    pub period: usize,
+}
+
+/// Where an overlay's series are drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum PaneTarget {
+    /// On the chart's own plot, sharing its Y axis.
+    #[default]
+    Main,
+    /// In a pane of its own below the chart with a fixed Y range (e.g. 0..100 for RSI).
+    /// Skipped when the chart renders alone; see `MultiChart::with_overlay_panes`.
+    Separate { y_min: f64, y_max: f64 },
+}
+
 /// Indicator transforms input series into a derived series (typically a Line series).
 pub trait Indicator {
     fn id(&self) -> &'static str;
@@ -25,8 +43,31 @@ pub trait Overlay {
     fn id(&self) -> &'static str;
     /// Compute one or more series to render as overlays on top of the chart.
     fn compute(&self, chart: &Chart) -> Vec<Series>;
-    /// Handle user interaction in world space (chart coordinates).
-    fn handle_event(&self, _evt: &OverlayEvent, _chart: &Chart) {}
+    /// Handle user interaction in world space (chart coordinates). Return `Consumed` when
+    /// the overlay acted on the event so the frontend skips its own pan/zoom handling.
+    fn handle_event(&self, _evt: &OverlayEvent, _chart: &Chart) -> EventResult { EventResult::Ignored }
+    /// Current parameters; `None` for overlays without tunable settings.
+    fn params(&self) -> Option<OverlayParams> { None }
+    /// Replace the parameters used by later `compute` calls. Ignored by default.
+    fn set_params(&mut self, _params: OverlayParams) {}
+    /// Pane for this overlay's series; oscillators on their own scale return `Separate`.
+    fn target_pane(&self) -> PaneTarget { PaneTarget::Main }
+    /// Text badges drawn above the overlay's series (when labels are enabled).
+    fn labels(&self, _chart: &Chart) -> Vec<OverlayLabel> { Vec::new() }
+    /// User drawings (e.g. anchors) to persist across reloads; None for overlays without any.
+    #[cfg(feature = "serde")]
+    fn serialize_state(&self) -> Option<serde_json::Value> { None }
+    /// Replace the drawings with what `serialize_state` produced. Ignored by default.
+    #[cfg(feature = "serde")]
+    fn restore_state(&mut self, _state: &serde_json::Value) -> anyhow::Result<()> { Ok(()) }
+}
+
+/// Text badge placed by an overlay: centered on `x`, sitting just above `y` (world coordinates).
+#[derive(Clone, Debug, PartialEq)]
+pub struct OverlayLabel {
+    pub x: f64,
+    pub y: f64,
+    pub text: String,
 }
 
 /// Helper: simple SMA over (x, y) pairs, returns averaged (x, yavg) points.
@@ -74,7 +115,7 @@ impl Indicator for SmaIndicator {
 
     fn compute(&self, input: &Series, params: &IndicatorParams) -> Series {
         match input.series_type {
-            SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline => {
+            SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline | SeriesType::Column => {
                 let xy = sma_xy(&input.data_xy, params.period);
                 Series::with_data(SeriesType::Line, xy)
             }
@@ -83,10 +124,74 @@ impl Indicator for SmaIndicator {
                 let xy = sma_candles(&oh, params.period);
                 Series::with_data(SeriesType::Line, xy)
             }
+            SeriesType::ErrorBar => {
+                let pts = input.data_xyerr.iter().map(|&(x, y, _)| (x, y)).collect::<Vec<_>>();
+                Series::with_data(SeriesType::Line, sma_xy(&pts, params.period))
+            }
+            SeriesType::Band => {
+                let mid = input.data_band.iter().map(|&(x, lo, hi)| (x, (lo + hi) * 0.5)).collect::<Vec<_>>();
+                Series::with_data(SeriesType::Line, sma_xy(&mid, params.period))
+            }
         }
     }
 }
 
+/// Helper: linearly weighted moving average; the newest point in each window has weight `period`.
+pub fn wma_xy(data: &[(f64, f64)], period: usize) -> Vec<(f64, f64)> {
+    if period == 0 || data.len() < period { return Vec::new(); }
+    let denom = (period * (period + 1)) as f64 / 2.0;
+    data.windows(period)
+        .map(|w| {
+            let num: f64 = w.iter().enumerate().map(|(k, &(_, y))| (k + 1) as f64 * y).sum();
+            (w[period - 1].0, num / denom)
+        })
+        .collect()
+}
+
+/// Helper: Hull moving average, WMA(sqrt(n)) of `2 * WMA(n/2) - WMA(n)`.
+/// Output starts at index `n + floor(sqrt(n)) - 2`.
+pub fn hma_xy(data: &[(f64, f64)], period: usize) -> Vec<(f64, f64)> {
+    if period == 0 { return Vec::new(); }
+    let half = (period / 2).max(1);
+    let sq = ((period as f64).sqrt().floor() as usize).max(1);
+    let w_half = wma_xy(data, half);
+    let w_full = wma_xy(data, period);
+    // w_full[k] and w_half[k + period - half] share the same x
+    let raw: Vec<(f64, f64)> = w_full
+        .iter()
+        .enumerate()
+        .map(|(k, &(x, full))| (x, 2.0 * w_half[k + period - half].1 - full))
+        .collect();
+    wma_xy(&raw, sq)
+}
+
+/// XY points an indicator should read: data_xy for XY types, closes for candles.
+fn indicator_input(input: &Series) -> Vec<(f64, f64)> {
+    input.iter_xy().collect()
+}
+
+/// Weighted moving average indicator.
+pub struct WmaIndicator;
+
+impl Indicator for WmaIndicator {
+    fn id(&self) -> &'static str { "wma" }
+
+    fn compute(&self, input: &Series, params: &IndicatorParams) -> Series {
+        Series::with_data(SeriesType::Line, wma_xy(&indicator_input(input), params.period))
+    }
+}
+
+/// Hull moving average indicator.
+pub struct HmaIndicator;
+
+impl Indicator for HmaIndicator {
+    fn id(&self) -> &'static str { "hma" }
+
+    fn compute(&self, input: &Series, params: &IndicatorParams) -> Series {
+        Series::with_data(SeriesType::Line, hma_xy(&indicator_input(input), params.period))
+    }
+}
+
 /// Simple SMA overlay that computes a moving average over the first series in the chart.
 pub struct SmaOverlay {
     pub period: usize,
@@ -94,13 +199,15 @@ pub struct SmaOverlay {
 
 impl Overlay for SmaOverlay {
     fn id(&self) -> &'static str { "sma_overlay" }
+    fn params(&self) -> Option<OverlayParams> { Some(OverlayParams { period: self.period }) }
+    fn set_params(&mut self, params: OverlayParams) { self.period = params.period; }
 
     fn compute(&self, chart: &Chart) -> Vec<Series> {
         let p = if self.period.max(1) == 0 { 14 } else { self.period.max(1) };
         let params = IndicatorParams { period: p };
         let sma = SmaIndicator;
         // Prefer XY input; if none, derive from candles close.
-        if let Some(s) = chart.series.iter().find(|s| matches!(s.series_type, SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline)) {
+        if let Some(s) = chart.series.iter().find(|s| matches!(s.series_type, SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline | SeriesType::Column)) {
             return vec![sma.compute(s, &params)];
         }
         if let Some(s) = chart.series.iter().find(|s| matches!(s.series_type, SeriesType::Candlestick | SeriesType::Bar)) {
@@ -110,6 +217,120 @@ impl Overlay for SmaOverlay {
     }
 }
 
+/// Helper: Wilder's RSI (0..100) over (x, y) pairs. Averages are seeded with the simple
+/// mean of the first `period` changes, so output starts at index `period`. A window with
+/// no losses reads 100 (50 if flat).
+pub fn rsi_xy(data: &[(f64, f64)], period: usize) -> Vec<(f64, f64)> {
+    if period == 0 || data.len() <= period { return Vec::new(); }
+    let p = period as f64;
+    let rsi = |gain: f64, loss: f64| {
+        if loss == 0.0 { if gain == 0.0 { 50.0 } else { 100.0 } } else { 100.0 - 100.0 / (1.0 + gain / loss) }
+    };
+    let (mut gain, mut loss) = data[..=period].windows(2).fold((0.0, 0.0), |(g, l), w| {
+        let d = w[1].1 - w[0].1;
+        (g + d.max(0.0), l + (-d).max(0.0))
+    });
+    gain /= p;
+    loss /= p;
+    let mut out = Vec::with_capacity(data.len() - period);
+    out.push((data[period].0, rsi(gain, loss)));
+    for w in data[period..].windows(2) {
+        let d = w[1].1 - w[0].1;
+        gain = (gain * (p - 1.0) + d.max(0.0)) / p;
+        loss = (loss * (p - 1.0) + (-d).max(0.0)) / p;
+        out.push((w[1].0, rsi(gain, loss)));
+    }
+    out
+}
+
+/// RSI of the first series (closes for candles), drawn in a separate 0..100 pane.
+pub struct RsiOverlay {
+    pub period: usize,
+}
+
+impl Overlay for RsiOverlay {
+    fn id(&self) -> &'static str { "rsi_overlay" }
+    fn params(&self) -> Option<OverlayParams> { Some(OverlayParams { period: self.period }) }
+    fn set_params(&mut self, params: OverlayParams) { self.period = params.period; }
+    fn target_pane(&self) -> PaneTarget { PaneTarget::Separate { y_min: 0.0, y_max: 100.0 } }
+
+    fn compute(&self, chart: &Chart) -> Vec<Series> {
+        let Some(s) = chart.series.first() else { return Vec::new() };
+        let rsi = rsi_xy(&indicator_input(s), self.period.max(1));
+        if rsi.is_empty() { return Vec::new(); }
+        vec![Series::with_data(SeriesType::Line, rsi)]
+    }
+}
+
+/// Helper: Bollinger bands as (x, lower, upper): the SMA of y over `period` points plus
+/// and minus `k` population standard deviations. Output starts at index `period - 1`.
+pub fn bollinger_xy(data: &[(f64, f64)], period: usize, k: f64) -> Vec<(f64, f64, f64)> {
+    if period == 0 || data.len() < period { return Vec::new(); }
+    data.windows(period)
+        .map(|w| {
+            let mean = w.iter().map(|p| p.1).sum::<f64>() / period as f64;
+            let var = w.iter().map(|p| (p.1 - mean) * (p.1 - mean)).sum::<f64>() / period as f64;
+            let d = k * var.sqrt();
+            (w[period - 1].0, mean - d, mean + d)
+        })
+        .collect()
+}
+
+/// Bollinger bands over the first series (closes for candles): a band plus its SMA midline.
+pub struct BollingerOverlay {
+    pub period: usize,
+    pub k: f64, // band half-width in standard deviations
+}
+
+impl Overlay for BollingerOverlay {
+    fn id(&self) -> &'static str { "bollinger_overlay" }
+    fn params(&self) -> Option<OverlayParams> { Some(OverlayParams { period: self.period }) }
+    fn set_params(&mut self, params: OverlayParams) { self.period = params.period; }
+
+    fn compute(&self, chart: &Chart) -> Vec<Series> {
+        let Some(s) = chart.series.first() else { return Vec::new() };
+        let band = bollinger_xy(&indicator_input(s), self.period.max(1), self.k);
+        if band.is_empty() { return Vec::new(); }
+        let mid = band.iter().map(|&(x, lo, hi)| (x, (lo + hi) * 0.5)).collect();
+        vec![Series::with_band(band), Series::with_data(SeriesType::Line, mid)]
+    }
+}
+
+/// Helper: Donchian channel as (t, lowest low, highest high) over a rolling window.
+/// Output starts at index `period - 1` (no partial windows).
+pub fn donchian(candles: &[Candle], period: usize) -> Vec<(f64, f64, f64)> {
+    if period == 0 || candles.len() < period { return Vec::new(); }
+    candles
+        .windows(period)
+        .map(|w| {
+            let lo = w.iter().map(|c| c.l).fold(f64::INFINITY, f64::min);
+            let hi = w.iter().map(|c| c.h).fold(f64::NEG_INFINITY, f64::max);
+            (w[period - 1].t, lo, hi)
+        })
+        .collect()
+}
+
+/// Donchian channel overlay over the first candle/bar series: a band plus its midline.
+pub struct DonchianOverlay {
+    pub period: usize,
+}
+
+impl Overlay for DonchianOverlay {
+    fn id(&self) -> &'static str { "donchian_overlay" }
+    fn params(&self) -> Option<OverlayParams> { Some(OverlayParams { period: self.period }) }
+    fn set_params(&mut self, params: OverlayParams) { self.period = params.period; }
+
+    fn compute(&self, chart: &Chart) -> Vec<Series> {
+        let Some(s) = chart.series.iter().find(|s| matches!(s.series_type, SeriesType::Candlestick | SeriesType::Bar)) else {
+            return Vec::new();
+        };
+        let band = donchian(&s.data_ohlc, self.period.max(1));
+        if band.is_empty() { return Vec::new(); }
+        let mid = band.iter().map(|&(t, lo, hi)| (t, (lo + hi) * 0.5)).collect();
+        vec![Series::with_band(band), Series::with_data(SeriesType::Line, mid)]
+    }
+}
+
 /// Overlay event in world coordinates (x/y are chart values, not pixels).
 pub enum OverlayEvent {
     PointerDown { x: f64, y: f64 },
@@ -117,20 +338,38 @@ pub enum OverlayEvent {
     PointerMove { x: f64, y: f64 },
 }
 
+/// Outcome of `Overlay::handle_event`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EventResult {
+    #[default]
+    Ignored,
+    Consumed,
+}
+
+impl EventResult {
+    pub fn is_consumed(self) -> bool { self == EventResult::Consumed }
+}
+
 /// Horizontal guide line overlay; click sets Y, renders a line across current X range.
+/// The Y sits behind a mutex so one instance can be shared (`Arc`) with a render thread.
 pub struct HvLineOverlay {
-    y: RefCell<Option<f64>>,
+    y: Mutex<Option<f64>>,
 }
 
 impl HvLineOverlay {
-    pub fn new() -> Self { Self { y: RefCell::new(None) } }
+    pub fn new() -> Self { Self { y: Mutex::new(None) } }
+
+    /// Current guide Y; a poisoned lock still holds a plain `Option<f64>`, so read through it.
+    fn y(&self) -> std::sync::MutexGuard<'_, Option<f64>> {
+        self.y.lock().unwrap_or_else(|e| e.into_inner())
+    }
 }
 
 impl Overlay for HvLineOverlay {
     fn id(&self) -> &'static str { "hv_line" }
 
     fn compute(&self, chart: &Chart) -> Vec<Series> {
-        if let Some(y) = *self.y.borrow() {
+        if let Some(y) = *self.y() {
             let x0 = chart.x_axis.min;
             let x1 = chart.x_axis.max;
             return vec![Series::with_data(SeriesType::Line, vec![(x0, y), (x1, y)])];
@@ -138,17 +377,233 @@ impl Overlay for HvLineOverlay {
         Vec::new()
     }
 
-    fn handle_event(&self, evt: &OverlayEvent, _chart: &Chart) {
+    fn handle_event(&self, evt: &OverlayEvent, _chart: &Chart) -> EventResult {
         match *evt {
-            OverlayEvent::PointerDown { y, .. } => { *self.y.borrow_mut() = Some(y); }
-            _ => {}
+            OverlayEvent::PointerDown { y, .. } => {
+                *self.y() = Some(y);
+                EventResult::Consumed
+            }
+            _ => EventResult::Ignored,
         }
     }
 }
 
+/// A fixed trendline between two world-space anchors.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trendline {
+    pub a: (f64, f64),
+    pub b: (f64, f64),
+}
+
+impl Trendline {
+    /// Endpoints sorted by x; with `extend`, pushed out to `x_min`/`x_max` along the
+    /// line's slope (vertical lines are left as drawn).
+    pub fn endpoints(&self, extend: Option<(f64, f64)>) -> [(f64, f64); 2] {
+        let (p, q) = if self.a.0 <= self.b.0 { (self.a, self.b) } else { (self.b, self.a) };
+        match extend {
+            Some((x_min, x_max)) if q.0 > p.0 => {
+                let slope = (q.1 - p.1) / (q.0 - p.0);
+                [(x_min, p.1 + slope * (x_min - p.0)), (x_max, p.1 + slope * (x_max - p.0))]
+            }
+            _ => [p, q],
+        }
+    }
+}
+
+#[derive(Default)]
+struct TrendlineState {
+    lines: Vec<Trendline>,
+    pending: Option<Trendline>, // anchor A plus the dragged preview end
+}
+
+/// Trendline drawing tool: `PointerDown` sets anchor A, `PointerMove` drags a preview,
+/// `PointerUp` fixes anchor B. Every finished line is kept and drawn; a click without
+/// movement is discarded.
+#[derive(Default)]
+pub struct TrendlineOverlay {
+    /// Extend each line across the full X axis range (rays in both directions).
+    pub extend: bool,
+    state: Mutex<TrendlineState>,
+}
+
+impl TrendlineOverlay {
+    pub fn new() -> Self { Self::default() }
+
+    /// Builder-style: draw lines across the whole X range instead of anchor to anchor.
+    pub fn with_extend(mut self, extend: bool) -> Self {
+        self.extend = extend;
+        self
+    }
+
+    /// Finished trendlines in drawing order.
+    pub fn lines(&self) -> Vec<Trendline> { self.state().lines.clone() }
+
+    /// Drop all trendlines, including one being dragged.
+    pub fn clear(&self) { *self.state() = TrendlineState::default(); }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, TrendlineState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Overlay for TrendlineOverlay {
+    fn id(&self) -> &'static str { "trendline" }
+
+    fn compute(&self, chart: &Chart) -> Vec<Series> {
+        let extend = self.extend.then_some((chart.x_axis.min, chart.x_axis.max));
+        let state = self.state();
+        state
+            .lines
+            .iter()
+            .chain(&state.pending)
+            .map(|line| Series::with_data(SeriesType::Line, line.endpoints(extend).to_vec()))
+            .collect()
+    }
+
+    fn handle_event(&self, evt: &OverlayEvent, _chart: &Chart) -> EventResult {
+        let mut state = self.state();
+        match *evt {
+            OverlayEvent::PointerDown { x, y } => {
+                state.pending = Some(Trendline { a: (x, y), b: (x, y) });
+            }
+            OverlayEvent::PointerMove { x, y } => {
+                let Some(line) = state.pending.as_mut() else { return EventResult::Ignored };
+                line.b = (x, y);
+            }
+            OverlayEvent::PointerUp { x, y } => {
+                let Some(mut line) = state.pending.take() else { return EventResult::Ignored };
+                line.b = (x, y);
+                if line.a != line.b { state.lines.push(line); }
+            }
+        }
+        EventResult::Consumed
+    }
+
+    /// Finished lines as `[{"a":[x,y],"b":[x,y]}, ...]`; a line being dragged is not saved.
+    #[cfg(feature = "serde")]
+    fn serialize_state(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.state().lines).ok()
+    }
+
+    #[cfg(feature = "serde")]
+    fn restore_state(&mut self, state: &serde_json::Value) -> anyhow::Result<()> {
+        let lines = <Vec<Trendline> as serde::Deserialize>::deserialize(state)?;
+        *self.state() = TrendlineState { lines, pending: None };
+        Ok(())
+    }
+}
+
+/// Price/time distance between two world points, as shown by `RulerOverlay`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RulerMeasure {
+    pub dx: f64,
+    pub dy: f64,
+    /// `dy` relative to the start point's value; None when that value is zero.
+    pub percent: Option<f64>,
+    /// Bars of the chart's first series after the earlier point, up to and including the later one.
+    pub bars: usize,
+}
+
+impl RulerMeasure {
+    pub fn new(a: (f64, f64), b: (f64, f64), chart: &Chart) -> Self {
+        let (lo, hi) = if a.0 <= b.0 { (a.0, b.0) } else { (b.0, a.0) };
+        let bars = chart.series.first().map_or(0, |s| s.iter_xy().filter(|&(x, _)| x > lo && x <= hi).count());
+        let dy = b.1 - a.1;
+        Self { dx: b.0 - a.0, dy, percent: (a.1 != 0.0).then(|| dy / a.1.abs() * 100.0), bars }
+    }
+
+    /// Badge text, e.g. `+1.50 (+3.00%), 5 bars, dx 5`.
+    pub fn label(&self) -> String {
+        let pct = self.percent.map(|p| format!(" ({p:+.2}%)")).unwrap_or_default();
+        format!("{:+.2}{pct}, {} bars, dx {}", self.dy, self.bars, self.dx)
+    }
+}
+
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct RulerState {
+    a: (f64, f64),
+    b: (f64, f64),
+    #[cfg_attr(feature = "serde", serde(skip))]
+    dragging: bool,
+}
+
+/// Measure tool: drag from one point to another to shade the spanned box and label it
+/// with the `RulerMeasure` between them. The last measurement stays until the next
+/// `PointerDown` or `clear`.
+#[derive(Default)]
+pub struct RulerOverlay {
+    state: Mutex<Option<RulerState>>,
+}
+
+impl RulerOverlay {
+    pub fn new() -> Self { Self::default() }
+
+    /// Current (or last finished) measurement.
+    pub fn measure(&self, chart: &Chart) -> Option<RulerMeasure> {
+        self.state().map(|st| RulerMeasure::new(st.a, st.b, chart))
+    }
+
+    pub fn clear(&self) { *self.state() = None; }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, Option<RulerState>> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Overlay for RulerOverlay {
+    fn id(&self) -> &'static str { "ruler" }
+
+    fn compute(&self, _chart: &Chart) -> Vec<Series> {
+        let Some(RulerState { a, b, .. }) = *self.state() else { return Vec::new() };
+        let (lo, hi) = (a.1.min(b.1), a.1.max(b.1));
+        let mut span = vec![(a.0, lo, hi), (b.0, lo, hi)];
+        span.sort_by(|p, q| p.0.total_cmp(&q.0));
+        vec![Series::with_band(span), Series::with_data(SeriesType::Line, vec![a, b])]
+    }
+
+    fn labels(&self, chart: &Chart) -> Vec<OverlayLabel> {
+        let Some(RulerState { a, b, .. }) = *self.state() else { return Vec::new() };
+        let text = RulerMeasure::new(a, b, chart).label();
+        vec![OverlayLabel { x: (a.0 + b.0) * 0.5, y: a.1.max(b.1), text }]
+    }
+
+    fn handle_event(&self, evt: &OverlayEvent, _chart: &Chart) -> EventResult {
+        let mut state = self.state();
+        match *evt {
+            OverlayEvent::PointerDown { x, y } => {
+                *state = Some(RulerState { a: (x, y), b: (x, y), dragging: true });
+            }
+            OverlayEvent::PointerMove { x, y } | OverlayEvent::PointerUp { x, y } => {
+                let Some(st) = state.as_mut().filter(|st| st.dragging) else { return EventResult::Ignored };
+                st.b = (x, y);
+                if matches!(evt, OverlayEvent::PointerUp { .. }) {
+                    st.dragging = false;
+                    // A click without a drag leaves nothing to show
+                    if st.a == st.b { *state = None; }
+                }
+            }
+        }
+        EventResult::Consumed
+    }
+
+    /// The last measurement's endpoints as `{"a":[x,y],"b":[x,y]}`.
+    #[cfg(feature = "serde")]
+    fn serialize_state(&self) -> Option<serde_json::Value> {
+        self.state().and_then(|st| serde_json::to_value(st).ok())
+    }
+
+    #[cfg(feature = "serde")]
+    fn restore_state(&mut self, state: &serde_json::Value) -> anyhow::Result<()> {
+        *self.state() = Some(<RulerState as serde::Deserialize>::deserialize(state)?);
+        Ok(())
+    }
+}
+
 // Allow sharing a single overlay instance across frames
 impl Overlay for std::sync::Arc<HvLineOverlay> {
     fn id(&self) -> &'static str { HvLineOverlay::id(self) }
     fn compute(&self, chart: &Chart) -> Vec<Series> { HvLineOverlay::compute(self, chart) }
-    fn handle_event(&self, evt: &OverlayEvent, chart: &Chart) { HvLineOverlay::handle_event(self, evt, chart) }
+    fn handle_event(&self, evt: &OverlayEvent, chart: &Chart) -> EventResult { HvLineOverlay::handle_event(self, evt, chart) }
 }
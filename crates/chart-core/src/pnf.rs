@@ -0,0 +1,92 @@
+// File: crates/chart-core/src/pnf.rs
+// Summary: Point-and-figure column computation (close-only method).
+
+/// Column direction: rising X boxes or falling O boxes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PnfKind {
+    X,
+    O,
+}
+
+/// One P&F column spanning box levels `low..=high` (price = level * box_size).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PnfColumn {
+    pub kind: PnfKind,
+    pub low: i64,
+    pub high: i64,
+}
+
+impl PnfColumn {
+    /// Number of boxes stacked in this column.
+    pub fn len(&self) -> usize { (self.high - self.low + 1).max(0) as usize }
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+}
+
+/// Point-and-figure result: columns in time order plus the parameters used.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointAndFigure {
+    pub box_size: f64,
+    pub reversal: usize,
+    pub columns: Vec<PnfColumn>,
+}
+
+impl PointAndFigure {
+    /// Box centers for columns of `kind` as (column index, price) pairs,
+    /// suitable for plotting as markers.
+    pub fn box_points(&self, kind: PnfKind) -> Vec<(f64, f64)> {
+        let mut out = Vec::new();
+        for (i, col) in self.columns.iter().enumerate() {
+            if col.kind != kind { continue; }
+            for level in col.low..=col.high {
+                out.push((i as f64, level as f64 * self.box_size));
+            }
+        }
+        out
+    }
+}
+
+/// Build P&F columns from closing prices.
+/// A column extends while price keeps moving by at least one box in its direction and
+/// flips once price retraces `reversal` boxes. Non-finite closes are skipped; an invalid
+/// `box_size` (<= 0) or `reversal` of 0 yields no columns.
+pub fn point_and_figure(closes: &[f64], box_size: f64, reversal: usize) -> PointAndFigure {
+    let mut pnf = PointAndFigure { box_size, reversal, columns: Vec::new() };
+    if !(box_size > 0.0 && box_size.is_finite()) || reversal == 0 { return pnf; }
+    let rev = reversal as i64;
+    let up_level = |p: f64| (p / box_size).floor() as i64;
+    let down_level = |p: f64| (p / box_size).ceil() as i64;
+
+    let mut prices = closes.iter().copied().filter(|p| p.is_finite());
+    let Some(first) = prices.next() else { return pnf };
+    let base = up_level(first);
+
+    for p in prices {
+        match pnf.columns.last_mut() {
+            // Direction not established yet: wait for a one-box move away from the first close
+            None => {
+                if up_level(p) > base {
+                    pnf.columns.push(PnfColumn { kind: PnfKind::X, low: base, high: up_level(p) });
+                } else if down_level(p) < base {
+                    pnf.columns.push(PnfColumn { kind: PnfKind::O, low: down_level(p), high: base });
+                }
+            }
+            Some(col) if col.kind == PnfKind::X => {
+                if up_level(p) > col.high {
+                    col.high = up_level(p);
+                } else if down_level(p) <= col.high - rev {
+                    let high = col.high - 1;
+                    pnf.columns.push(PnfColumn { kind: PnfKind::O, low: down_level(p), high });
+                }
+            }
+            Some(col) => {
+                if down_level(p) < col.low {
+                    col.low = down_level(p);
+                } else if up_level(p) >= col.low + rev {
+                    let low = col.low + 1;
+                    pnf.columns.push(PnfColumn { kind: PnfKind::X, low, high: up_level(p) });
+                }
+            }
+        }
+    }
+    pnf
+}
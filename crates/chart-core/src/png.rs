@@ -0,0 +1,112 @@
+// File: crates/chart-core/src/png.rs
+// Summary: PNG text-chunk metadata (title, creation time, series count) injected into
+//          Skia-encoded PNG bytes, plus a reader for the same chunks.
+
+use anyhow::Result;
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+
+/// Self-describing text for exported PNGs, written by `Chart::render_to_png_bytes` when
+/// set on `RenderOptions::png_metadata`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PngMetadata {
+    pub title: String,
+    pub timestamp: Option<chrono::DateTime<chrono::Utc>>, // None: time of rendering
+}
+
+impl PngMetadata {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self { title: title.into(), timestamp: None }
+    }
+
+    /// Builder-style: fix the creation time instead of using the render time.
+    pub fn with_timestamp(mut self, timestamp: chrono::DateTime<chrono::Utc>) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Keyword/text pairs as written: `Title`, `Creation Time` (RFC 3339) and `Series Count`.
+    pub(crate) fn entries(&self, series_count: usize) -> Vec<(&'static str, String)> {
+        let time = self.timestamp.unwrap_or_else(chrono::Utc::now);
+        vec![
+            ("Title", self.title.clone()),
+            ("Creation Time", time.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)),
+            ("Series Count", series_count.to_string()),
+        ]
+    }
+}
+
+/// Insert text chunks right after `IHDR`. Latin-1 text goes into `tEXt`; anything else
+/// into uncompressed `iTXt` (UTF-8).
+pub(crate) fn insert_text_chunks(png: &[u8], entries: &[(&str, String)]) -> Result<Vec<u8>> {
+    // Signature + IHDR (4 length + 4 type + 13 data + 4 crc)
+    const IHDR_END: usize = 8 + 25;
+    if png.len() < IHDR_END || png[..8] != SIGNATURE || &png[12..16] != b"IHDR" {
+        anyhow::bail!("not a PNG stream");
+    }
+    let mut out = Vec::with_capacity(png.len() + entries.len() * 64);
+    out.extend_from_slice(&png[..IHDR_END]);
+    for (keyword, text) in entries {
+        let mut data = keyword.as_bytes().to_vec();
+        data.push(0);
+        if text.chars().all(|c| (c as u32) < 0x100) {
+            data.extend(text.chars().map(|c| c as u8));
+            write_chunk(&mut out, b"tEXt", &data);
+        } else {
+            // compression flag, method, empty language tag, empty translated keyword
+            data.extend_from_slice(&[0, 0, 0, 0]);
+            data.extend_from_slice(text.as_bytes());
+            write_chunk(&mut out, b"iTXt", &data);
+        }
+    }
+    out.extend_from_slice(&png[IHDR_END..]);
+    Ok(out)
+}
+
+/// Keyword/text pairs from the `tEXt` and uncompressed `iTXt` chunks of a PNG, in file order.
+pub fn read_text_chunks(png: &[u8]) -> Vec<(String, String)> {
+    let mut out = Vec::new();
+    if png.len() < 8 || png[..8] != SIGNATURE { return out; }
+    let mut i = 8;
+    while i + 12 <= png.len() {
+        let len = u32::from_be_bytes([png[i], png[i + 1], png[i + 2], png[i + 3]]) as usize;
+        let Some(data) = png.get(i + 8..i + 8 + len) else { break };
+        let kind = &png[i + 4..i + 8];
+        if let Some(nul) = data.iter().position(|&b| b == 0) {
+            let keyword: String = data[..nul].iter().map(|&b| b as char).collect();
+            let rest = &data[nul + 1..];
+            match kind {
+                b"tEXt" => out.push((keyword, rest.iter().map(|&b| b as char).collect())),
+                // skip compressed iTXt; otherwise step over the language tag and translated keyword
+                b"iTXt" if rest.len() >= 2 && rest[0] == 0 => {
+                    let text = rest[2..].splitn(3, |&b| b == 0).nth(2).unwrap_or_default();
+                    out.push((keyword, String::from_utf8_lossy(text).into_owned()));
+                }
+                _ => {}
+            }
+        }
+        i += 12 + len;
+    }
+    out
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let crc = crc32(&out[start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// CRC-32 (IEEE, reflected) as required for PNG chunk trailers.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &b in bytes {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
@@ -0,0 +1,80 @@
+// File: crates/chart-core/src/renderer.rs
+// Summary: Reusable raster target for repeated renders (animation loops, live feeds);
+//          keeps one Skia surface and pixel buffer until the output size changes.
+
+use anyhow::Result;
+use skia_safe as skia;
+
+use crate::chart::{Chart, RenderOptions};
+use crate::png::insert_text_chunks;
+
+/// Owns a raster surface sized to the last `RenderOptions` it rendered with.
+/// `Chart::render_to_rgba8` allocates a surface per call; a `Renderer` only
+/// reallocates when `opts.width`/`opts.height` change.
+#[derive(Default)]
+pub struct Renderer {
+    surface: Option<(skia::Surface, i32, i32)>,
+    pixels: Vec<u8>,
+    allocations: usize,
+}
+
+impl Renderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Draw `chart` onto the owned surface, creating or resizing it first if needed.
+    pub fn render(&mut self, chart: &Chart, opts: &RenderOptions) -> Result<&mut skia::Surface> {
+        let size = (opts.width, opts.height);
+        if !matches!(&self.surface, Some((_, w, h)) if (*w, *h) == size) {
+            let surface = skia::surfaces::raster_n32_premul(size)
+                .ok_or_else(|| anyhow::anyhow!("failed to create raster surface"))?;
+            self.surface = Some((surface, opts.width, opts.height));
+            self.allocations += 1;
+        }
+        let (surface, _, _) = self.surface.as_mut().expect("surface allocated above");
+        chart.draw_onto_canvas(surface.canvas(), opts);
+        Ok(surface)
+    }
+
+    /// Like `Chart::render_to_rgba8`, but reusing the surface and pixel buffer.
+    /// Rows are tightly packed (`opts.width * 4` bytes).
+    pub fn render_to_rgba8(&mut self, chart: &Chart, opts: &RenderOptions) -> Result<&[u8]> {
+        let row_bytes = (opts.width as usize) * 4;
+        let len = row_bytes * (opts.height as usize);
+        // Take the buffer so the surface borrow below does not overlap it
+        let mut pixels = std::mem::take(&mut self.pixels);
+        pixels.resize(len, 0);
+        let info = skia::ImageInfo::new(
+            (opts.width, opts.height),
+            skia::ColorType::RGBA8888,
+            skia::AlphaType::Premul,
+            None,
+        );
+        let surface = self.render(chart, opts)?;
+        let ok = surface.read_pixels(&info, pixels.as_mut_slice(), row_bytes, (0, 0));
+        self.pixels = pixels;
+        if !ok {
+            anyhow::bail!("read_pixels failed");
+        }
+        Ok(&self.pixels)
+    }
+
+    /// Like `Chart::render_to_png_bytes` (including `opts.png_metadata`), reusing the surface.
+    pub fn render_to_png_bytes(&mut self, chart: &Chart, opts: &RenderOptions) -> Result<Vec<u8>> {
+        let image = self.render(chart, opts)?.image_snapshot();
+        #[allow(deprecated)]
+        let data = image
+            .encode_to_data(skia::EncodedImageFormat::PNG)
+            .ok_or_else(|| anyhow::anyhow!("encode PNG failed"))?;
+        match &opts.png_metadata {
+            Some(meta) => insert_text_chunks(data.as_bytes(), &meta.entries(chart.series.len())),
+            None => Ok(data.as_bytes().to_vec()),
+        }
+    }
+
+    /// Number of surfaces created so far; stays put while the size is unchanged.
+    pub fn allocations(&self) -> usize {
+        self.allocations
+    }
+}
@@ -134,3 +134,23 @@ impl ValueScale {
         }
     }
 }
+
+/// Map many (x, y) points to pixels at once, replacing the contents of `out`. The scale
+/// coefficients and the linear/log choice are resolved once, then points go through a
+/// tight loop the compiler can vectorize. Results are bit-identical to calling
+/// `TimeScale::to_px` and `ValueScale::to_px` per point.
+pub fn map_points(ts: &TimeScale, vs: &ValueScale, points: &[(Logical, Value)], out: &mut Vec<(f32, f32)>) {
+    out.clear();
+    out.reserve(points.len());
+    let (left, start, spacing) = (ts.left_px, ts.start_logical, ts.bar_spacing);
+    let (bottom, height) = (vs.bottom_px, vs.bottom_px - vs.top_px);
+    if vs.log {
+        let (lo, span) = (vs.log_min, (vs.log_max - vs.log_min).max(1e-12));
+        out.extend(points.iter().map(|&(x, y)| {
+            (left + ((x - start) as f32) * spacing, bottom - ((y.max(1e-12).log10() - lo) / span) as f32 * height)
+        }));
+    } else {
+        let (vmin, span) = (vs.vmin, (vs.vmax - vs.vmin).max(1e-12));
+        out.extend(points.iter().map(|&(x, y)| (left + ((x - start) as f32) * spacing, bottom - ((y - vmin) / span) as f32 * height)));
+    }
+}
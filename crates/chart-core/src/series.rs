@@ -1,20 +1,75 @@
 // File: crates/chart-core/src/series.rs
-// Summary: Series model for line, candlestick, bar, histogram, and baseline data.
+// Summary: Series model for line, candlestick, bar, histogram, baseline, error-bar, band, and column data.
 // Notes:
 // - This file intentionally keeps the original `Series` layout to maintain
 //   compatibility with existing rendering code. New, safer constructors and
 //   helpers are provided to tighten invariants without breaking callers.
 
-#[derive(Clone, Copy, Debug)]
+use std::sync::Arc;
+
+use skia_safe as skia;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SeriesType {
     Line,
     Candlestick,
     Bar,         // OHLC bar (no filled body)
     Histogram,   // (x, y) bars from baseline (0.0)
     Baseline,    // area relative to baseline value (default 0.0)
+    ErrorBar,    // (x, y, yerr) points with vertical ±yerr whiskers
+    Band,        // filled region between lower and upper lines over shared x
+    Column,      // (x, y) categorical bars from baseline with configurable width/gap
+}
+
+/// Validation failure for series data, pointing at the first offending input index.
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum SeriesError {
+    #[error("x at index {index} is not finite")]
+    NonFiniteX { index: usize },
+    #[error("duplicate x value {x} at index {index}")]
+    DuplicateX { index: usize, x: f64 },
+    #[error("candle at index {index}: {reason}")]
+    InvalidCandle { index: usize, reason: &'static str },
+}
+
+/// Where a histogram bar sits relative to its x value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BarAlign {
+    /// Bar starts at x (x is the start of a time bucket).
+    Left,
+    #[default]
+    Center,
+    /// Bar ends at x.
+    Right,
+}
+
+/// How line rendering treats non-finite (NaN/inf) points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GapMode {
+    /// End the line at a gap and resume after it.
+    #[default]
+    Break,
+    /// Skip missing points and join their finite neighbours directly.
+    Connect,
+    /// Fill missing y values linearly from the neighbours (render-time only).
+    Interpolate,
+}
+
+/// Point marker drawn at each vertex of a line series with `show_markers`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MarkerShape {
+    #[default]
+    Circle,
+    Square,
+    Diamond,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Candle {
     pub t: f64,  // time/index (displayed on X)
     pub o: f64,
@@ -36,29 +91,318 @@ impl Candle {
     }
 }
 
+/// Body-to-range ratio below which a candle is a doji.
+pub const DOJI_BODY_FRAC: f64 = 0.1;
+/// Body-to-range ratio at or above which a candle is a marubozu (little or no wick).
+pub const MARUBOZU_BODY_FRAC: f64 = 0.95;
+
+/// Per-candle shape classification used for pattern highlighting.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CandlePattern {
+    Normal,
+    Doji,
+    BullMarubozu,
+    BearMarubozu,
+}
+
+impl Candle {
+    /// Classify by body size relative to the high-low range.
+    /// A zero-range candle counts as a doji.
+    pub fn pattern(&self) -> CandlePattern {
+        let range = self.h - self.l;
+        let body = (self.c - self.o).abs();
+        if range <= 0.0 { return CandlePattern::Doji; }
+        let ratio = body / range;
+        if ratio < DOJI_BODY_FRAC {
+            CandlePattern::Doji
+        } else if ratio >= MARUBOZU_BODY_FRAC {
+            if self.c >= self.o { CandlePattern::BullMarubozu } else { CandlePattern::BearMarubozu }
+        } else {
+            CandlePattern::Normal
+        }
+    }
+}
+
+/// Per-candle `(body, wick)` colors from the candle's index in `data_ohlc` and the
+/// candle itself, e.g. to tint bars by an external strategy signal.
+pub type CandleColorFn = Arc<dyn Fn(usize, &Candle) -> (skia::Color, skia::Color) + Send + Sync>;
+
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Series {
     pub series_type: SeriesType,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::spec::gap_xy"))]
     pub data_xy: Vec<(f64, f64)>,     // used by Line/Histogram/Baseline
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::spec::gap_candles"))]
     pub data_ohlc: Vec<Candle>,       // used by Candlestick/Bar
     pub baseline: Option<f64>,        // used by Baseline/Histogram (origin)
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::spec::gap_xyz"))]
+    pub data_xyerr: Vec<(f64, f64, f64)>, // used by ErrorBar: (x, y, yerr)
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::spec::gap_xyz"))]
+    pub data_band: Vec<(f64, f64, f64)>,  // used by Band: (x, lower, upper)
+    pub band_strokes: bool,               // Band: stroke the lower/upper boundaries
+    pub column_width: Option<f64>,        // Column: slot width in x units (default: min x spacing)
+    pub column_gap: f64,                  // Column: fraction of the slot left empty (0..1)
+    pub bar_gap_frac: f64,                // Histogram: fraction of the point spacing left empty (0..1)
+    pub bar_align: BarAlign,              // Histogram: bar position relative to its x
+    pub stack_group: Option<String>,      // Column/Baseline: stack on earlier series of the same group
+    #[cfg_attr(feature = "serde", serde(with = "crate::spec::opt_color"))]
+    pub color: Option<skia::Color>,  // Column/Baseline fill, overlay line stroke (theme color when None)
+    pub gap_mode: GapMode,                // Line: handling of NaN/inf points
+    pub z_index: i32,                     // paint order: higher draws later (on top); ties keep insertion order
+    pub opacity: f32,                     // 0..1, multiplies the alpha of every color this series draws with
+    pub show_markers: bool,               // Line: draw `marker_shape` at each finite point
+    pub marker_shape: MarkerShape,
+    pub highlight_extremes: bool,         // mark and label the highest and lowest point (candles: high/low)
+    #[cfg_attr(feature = "serde", serde(with = "crate::spec::opt_threshold"))]
+    pub threshold_color: Option<(f64, skia::Color, skia::Color)>, // Line: (threshold, above, below) stroke colors
+    pub include_in_autoscale: bool,       // false: drawn, but ignored when fitting axes/views to the data
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub candle_color_fn: Option<CandleColorFn>, // Candlestick: per-candle colors instead of theme up/down
+}
+
+/// An empty Line series; also fills in fields missing from serialized series.
+impl Default for Series {
+    fn default() -> Self {
+        Self::new(SeriesType::Line)
+    }
 }
 
 impl Series {
     pub fn new(series_type: SeriesType) -> Self {
-        Self { series_type, data_xy: Vec::new(), data_ohlc: Vec::new(), baseline: None }
+        Self { series_type, data_xy: Vec::new(), data_ohlc: Vec::new(), baseline: None, data_xyerr: Vec::new(), data_band: Vec::new(), band_strokes: true, column_width: None, column_gap: 0.2, bar_gap_frac: 0.2, bar_align: BarAlign::Center, stack_group: None, color: None, gap_mode: GapMode::Break, z_index: 0, opacity: 1.0, show_markers: false, marker_shape: MarkerShape::Circle, highlight_extremes: false, threshold_color: None, include_in_autoscale: true, candle_color_fn: None }
     }
 
     pub fn with_data(series_type: SeriesType, data: Vec<(f64, f64)>) -> Self {
-        Self { series_type, data_xy: data, data_ohlc: Vec::new(), baseline: None }
+        Self { data_xy: data, ..Self::new(series_type) }
+    }
+
+    /// Like `with_data`, but sorts points by ascending x (stable, so equal x keep their order).
+    /// Rendering and LTTB assume sorted x; unsorted input draws zig-zags.
+    pub fn with_sorted_data(series_type: SeriesType, mut data: Vec<(f64, f64)>) -> Self {
+        data.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self::with_data(series_type, data)
+    }
+
+    /// Validating constructor: rejects non-finite or duplicate x values, then sorts by x.
+    /// Error indices refer to positions in `data` as passed in.
+    pub fn try_with_data(series_type: SeriesType, data: Vec<(f64, f64)>) -> Result<Self, SeriesError> {
+        if let Some(index) = data.iter().position(|p| !p.0.is_finite()) {
+            return Err(SeriesError::NonFiniteX { index });
+        }
+        let mut order: Vec<usize> = (0..data.len()).collect();
+        order.sort_by(|&a, &b| data[a].0.total_cmp(&data[b].0));
+        if let Some(w) = order.windows(2).find(|w| data[w[0]].0 == data[w[1]].0) {
+            let index = w[0].max(w[1]);
+            return Err(SeriesError::DuplicateX { index, x: data[index].0 });
+        }
+        let sorted = order.into_iter().map(|i| data[i]).collect();
+        Ok(Self::with_data(series_type, sorted))
     }
 
     pub fn from_candles(candles: Vec<Candle>) -> Self {
-        Self { series_type: SeriesType::Candlestick, data_xy: Vec::new(), data_ohlc: candles, baseline: None }
+        Self { data_ohlc: candles, ..Self::new(SeriesType::Candlestick) }
     }
 
     pub fn from_candles_as(series_type: SeriesType, candles: Vec<Candle>) -> Self {
-        Self { series_type, data_xy: Vec::new(), data_ohlc: candles, baseline: None }
+        Self { data_ohlc: candles, ..Self::new(series_type) }
+    }
+
+    /// Streaming update for candle/bar series: a candle at the last candle's `t` replaces it
+    /// (the bar still forming), a later one is appended. Returns false, leaving the data
+    /// unchanged, for a candle older than the last one.
+    pub fn push_candle(&mut self, candle: Candle) -> bool {
+        match self.data_ohlc.last_mut() {
+            Some(last) if candle.t < last.t => false,
+            Some(last) if candle.t == last.t => {
+                *last = candle;
+                true
+            }
+            _ => {
+                self.data_ohlc.push(candle);
+                true
+            }
+        }
+    }
+
+    /// Band series from (x, lower, upper) triples.
+    pub fn with_band(data: Vec<(f64, f64, f64)>) -> Self {
+        Self { data_band: data, ..Self::new(SeriesType::Band) }
+    }
+
+    /// Band series from separate lower/upper lines; pairs are zipped by index (x from `lower`).
+    pub fn band_from(lower: &[(f64, f64)], upper: &[(f64, f64)]) -> Self {
+        let data = lower.iter().zip(upper).map(|(&(x, lo), &(_, hi))| (x, lo, hi)).collect();
+        Self::with_band(data)
+    }
+
+    /// Toggle the boundary strokes drawn on top of a band's fill.
+    pub fn with_band_strokes(mut self, on: bool) -> Self {
+        self.band_strokes = on;
+        self
+    }
+
+    /// Column (vertical bar) series from (x, y) pairs, drawn from the baseline (default 0.0).
+    pub fn with_columns(data: Vec<(f64, f64)>) -> Self {
+        Self { data_xy: data, ..Self::new(SeriesType::Column) }
+    }
+
+    /// Column slot width in x units; bars fill the slot minus `column_gap`.
+    pub fn with_column_width(mut self, width: f64) -> Self {
+        self.column_width = Some(width);
+        self
+    }
+
+    /// Fraction of each column slot left empty between neighbours (clamped to 0..0.95).
+    pub fn with_column_gap(mut self, gap_frac: f64) -> Self {
+        self.column_gap = gap_frac.clamp(0.0, 0.95);
+        self
+    }
+
+    /// Histogram: fraction of the spacing between points left empty between bars
+    /// (default 0.2; 0 makes neighbouring bars abut). Clamped to 0..0.95.
+    pub fn with_bar_gap(mut self, gap_frac: f64) -> Self {
+        self.bar_gap_frac = gap_frac.clamp(0.0, 0.95);
+        self
+    }
+
+    /// Histogram: place bars left of, centered on, or right of their x.
+    pub fn with_bar_align(mut self, align: BarAlign) -> Self {
+        self.bar_align = align;
+        self
+    }
+
+    /// Stack this Column/Baseline series on top of earlier series with the same group name.
+    pub fn with_stack_group(mut self, group: impl Into<String>) -> Self {
+        self.stack_group = Some(group.into());
+        self
+    }
+
+    /// Override the fill color (Column/Baseline), e.g. to tell stacked layers apart. Series
+    /// returned by an overlay use it as their line color instead of the crosshair color.
+    pub fn with_color(mut self, color: skia::Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Choose how line rendering handles NaN gaps.
+    pub fn with_gap_mode(mut self, mode: GapMode) -> Self {
+        self.gap_mode = mode;
+        self
+    }
+
+    /// Builder-style: mark each point of a line series with `shape`.
+    pub fn with_markers(mut self, shape: MarkerShape) -> Self {
+        self.show_markers = true;
+        self.marker_shape = shape;
+        self
+    }
+
+    /// Builder-style: stroke a line series in `above` where it is above `threshold` and
+    /// `below` elsewhere, e.g. green/red around zero for P&L.
+    pub fn with_threshold_color(mut self, threshold: f64, above: skia::Color, below: skia::Color) -> Self {
+        self.threshold_color = Some((threshold, above, below));
+        self
+    }
+
+    /// Builder-style: mark and label the series' global maximum and minimum.
+    pub fn with_highlight_extremes(mut self, on: bool) -> Self {
+        self.highlight_extremes = on;
+        self
+    }
+
+    /// Builder-style: keep this series (e.g. a flat reference line or a huge-range volume)
+    /// from driving autoscale. It is still drawn, and may end up outside the fitted range.
+    pub fn with_autoscale(mut self, include: bool) -> Self {
+        self.include_in_autoscale = include;
+        self
+    }
+
+    /// Builder-style: color each candle (body, wick) by `f(index, candle)` instead of the
+    /// theme's up/down colors. Takes precedence over hollow style and pattern tint.
+    pub fn with_candle_colors(mut self, f: impl Fn(usize, &Candle) -> (skia::Color, skia::Color) + Send + Sync + 'static) -> Self {
+        self.candle_color_fn = Some(Arc::new(f));
+        self
+    }
+
+    /// Builder-style: paint order relative to other series (default 0; higher is on top).
+    pub fn with_z_index(mut self, z: i32) -> Self {
+        self.z_index = z;
+        self
+    }
+
+    /// Builder-style: de-emphasize (or hide, at 0.0) this series; clamped to 0..1.
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// XY points as drawn by line rendering: runs of finite points split or bridged
+    /// according to `gap_mode`. The stored data is never modified.
+    pub fn line_runs(&self) -> Vec<Vec<(f64, f64)>> {
+        let finite = |&(x, y): &(f64, f64)| x.is_finite() && y.is_finite();
+        let data = &self.data_xy;
+        match self.gap_mode {
+            GapMode::Break => data.split(|p| !finite(p)).filter(|run| !run.is_empty()).map(<[_]>::to_vec).collect(),
+            GapMode::Connect => {
+                let run: Vec<(f64, f64)> = data.iter().copied().filter(finite).collect();
+                if run.is_empty() { Vec::new() } else { vec![run] }
+            }
+            GapMode::Interpolate => {
+                let mut run = Vec::with_capacity(data.len());
+                let mut prev: Option<(f64, f64)> = None;
+                for (i, &(x, y)) in data.iter().enumerate() {
+                    if !x.is_finite() { continue; }
+                    if y.is_finite() {
+                        run.push((x, y));
+                        prev = Some((x, y));
+                        continue;
+                    }
+                    // Needs a finite neighbour on both sides; leading/trailing gaps are dropped
+                    let next = data[i + 1..].iter().copied().find(finite);
+                    if let (Some((x0, y0)), Some((x1, y1))) = (prev, next) {
+                        let f = if x1 != x0 { (x - x0) / (x1 - x0) } else { 0.0 };
+                        run.push((x, y0 + (y1 - y0) * f));
+                    }
+                }
+                if run.is_empty() { Vec::new() } else { vec![run] }
+            }
+        }
+    }
+
+    /// `line_runs` split where the line crosses `threshold`, each piece tagged with whether
+    /// it lies above. Crossings get an interpolated point on the threshold that ends one
+    /// piece and starts the next; points exactly on the threshold don't split a piece.
+    pub fn threshold_runs(&self, threshold: f64) -> Vec<(bool, Vec<(f64, f64)>)> {
+        let mut out = Vec::new();
+        for run in self.line_runs() {
+            // A run starting on the threshold takes the side it heads to
+            let mut above = run.iter().find(|p| p.1 != threshold).is_none_or(|p| p.1 > threshold);
+            let mut piece = vec![run[0]];
+            for w in run.windows(2) {
+                let ((x0, y0), (x1, y1)) = (w[0], w[1]);
+                if y1 != threshold && (y1 > threshold) != above {
+                    let c = (x0 + (threshold - y0) / (y1 - y0) * (x1 - x0), threshold);
+                    if piece.last() != Some(&c) { piece.push(c); }
+                    out.push((above, std::mem::replace(&mut piece, vec![c])));
+                    above = !above;
+                }
+                piece.push((x1, y1));
+            }
+            out.push((above, piece));
+        }
+        out
+    }
+
+    /// Whether this series takes part in stacking (has a group and a stackable type).
+    pub(crate) fn is_stacked(&self) -> bool {
+        self.stack_group.is_some() && matches!(self.series_type, SeriesType::Column | SeriesType::Baseline)
+    }
+
+    /// Error-bar series from (x, y, yerr) triples; whiskers span y ± |yerr|.
+    pub fn with_errors(data: Vec<(f64, f64, f64)>) -> Self {
+        Self { data_xyerr: data, ..Self::new(SeriesType::ErrorBar) }
     }
 
     pub fn with_baseline(mut self, baseline: f64) -> Self {
@@ -69,17 +413,77 @@ impl Series {
     /// Get baseline value or default (0.0) when not set.
     pub fn baseline_value(&self) -> f64 { self.baseline.unwrap_or(0.0) }
 
+    /// Number of points in the storage used by `series_type` (candles for OHLC types,
+    /// triples for error bars and bands, `data_xy` otherwise).
+    pub fn len(&self) -> usize {
+        match self.series_type {
+            SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline | SeriesType::Column => self.data_xy.len(),
+            SeriesType::Candlestick | SeriesType::Bar => self.data_ohlc.len(),
+            SeriesType::ErrorBar => self.data_xyerr.len(),
+            SeriesType::Band => self.data_band.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// One (x, y) per point whatever the storage: (t, close) for candles, the center for
+    /// error bars and the band midpoint for bands. Non-finite points are passed through.
+    pub fn iter_xy(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        let it: Box<dyn Iterator<Item = (f64, f64)> + '_> = match self.series_type {
+            SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline | SeriesType::Column => Box::new(self.data_xy.iter().copied()),
+            SeriesType::Candlestick | SeriesType::Bar => Box::new(self.data_ohlc.iter().map(|c| (c.t, c.c))),
+            SeriesType::ErrorBar => Box::new(self.data_xyerr.iter().map(|&(x, y, _)| (x, y))),
+            SeriesType::Band => Box::new(self.data_band.iter().map(|&(x, lo, hi)| (x, (lo + hi) * 0.5))),
+        };
+        it
+    }
+
+    /// (min, max) of the finite x values (candle times for OHLC). `None` without any.
+    pub fn x_range(&self) -> Option<(f64, f64)> {
+        self.extents().map(|p| p.0).filter(|x| x.is_finite()).fold(None, |acc, x| match acc {
+            None => Some((x, x)),
+            Some((lo, hi)) => Some((lo.min(x), hi.max(x))),
+        })
+    }
+
+    /// (min, max) of the finite y values as autoscale sees them: candle lows/highs, error
+    /// bar whiskers, band edges. `None` without any finite point.
+    pub fn y_range(&self) -> Option<(f64, f64)> {
+        self.extreme_points().map(|(lo, hi)| (lo.1, hi.1))
+    }
+
+    /// Most recent value for readouts: the close of the last candle, otherwise the y of the
+    /// last finite point (upper edge for bands). `None` without usable data.
+    pub fn last_value(&self) -> Option<f64> {
+        match self.series_type {
+            SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline | SeriesType::Column => {
+                self.data_xy.iter().rev().map(|p| p.1).find(|y| y.is_finite())
+            }
+            SeriesType::Candlestick | SeriesType::Bar => self.data_ohlc.iter().rev().map(|c| c.c).find(|c| c.is_finite()),
+            SeriesType::ErrorBar => self.data_xyerr.iter().rev().map(|p| p.1).find(|y| y.is_finite()),
+            SeriesType::Band => self.data_band.iter().rev().map(|p| p.2).find(|y| y.is_finite()),
+        }
+    }
+
     /// Downsample XY data (Line/Histogram/Baseline) using LTTB to at most `max_points`.
     pub fn downsample_xy_lttb(&self, max_points: usize) -> Self {
         use crate::downsample::lttb;
         match self.series_type {
-            SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline => {
+            SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline | SeriesType::Column => {
                 let data = if self.data_xy.len() > max_points && max_points >= 2 {
                     lttb(&self.data_xy, max_points)
                 } else {
                     self.data_xy.clone()
                 };
-                Series { series_type: self.series_type, data_xy: data, data_ohlc: Vec::new(), baseline: self.baseline }
+                Series {
+                    data_xy: data,
+                    baseline: self.baseline,
+                    column_width: self.column_width,
+                    column_gap: self.column_gap,
+                    bar_gap_frac: self.bar_gap_frac,
+                    bar_align: self.bar_align,
+                    ..Series::new(self.series_type)
+                }
             }
             _ => self.clone(),
         }
@@ -91,9 +495,153 @@ impl Series {
         match self.series_type {
             SeriesType::Candlestick | SeriesType::Bar => {
                 let data = if bucket > 1 { aggregate_ohlc_buckets(&self.data_ohlc, bucket) } else { self.data_ohlc.clone() };
-                Series { series_type: self.series_type, data_xy: Vec::new(), data_ohlc: data, baseline: self.baseline }
+                Series { data_ohlc: data, baseline: self.baseline, ..Series::new(self.series_type) }
             }
             _ => self.clone(),
         }
     }
+
+    /// Rebase values to percent change from `base` (default: the first finite y, or the
+    /// first close for OHLC), so a doubled value becomes 100.0. Deviations such as error
+    /// bars scale by the same factor. Returns an unchanged clone if the base is 0 or missing.
+    pub fn to_percent_change(&self, base: Option<f64>) -> Self {
+        let first = match self.series_type {
+            SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline | SeriesType::Column => {
+                self.data_xy.iter().map(|p| p.1).find(|y| y.is_finite())
+            }
+            SeriesType::Candlestick | SeriesType::Bar => self.data_ohlc.iter().map(|c| c.c).find(|c| c.is_finite()),
+            SeriesType::ErrorBar => self.data_xyerr.iter().map(|p| p.1).find(|y| y.is_finite()),
+            SeriesType::Band => self.data_band.iter().map(|p| p.1).find(|y| y.is_finite()),
+        };
+        let base = match base.or(first) {
+            Some(b) if b.is_finite() && b != 0.0 => b,
+            _ => return self.clone(),
+        };
+        let pct = |v: f64| (v / base - 1.0) * 100.0;
+        let scale = 100.0 / base.abs();
+        let mut out = self.clone();
+        out.data_xy.iter_mut().for_each(|p| p.1 = pct(p.1));
+        out.data_ohlc.iter_mut().for_each(|c| {
+            c.o = pct(c.o);
+            c.h = pct(c.h);
+            c.l = pct(c.l);
+            c.c = pct(c.c);
+        });
+        out.data_xyerr.iter_mut().for_each(|p| {
+            p.1 = pct(p.1);
+            p.2 *= scale;
+        });
+        out.data_band.iter_mut().for_each(|p| {
+            p.1 = pct(p.1);
+            p.2 = pct(p.2);
+        });
+        out.baseline = self.baseline.map(pct);
+        out
+    }
+
+    /// Log returns `ln(y_t / y_{t-1})` keyed by the later x; the first point is dropped.
+    /// XY series keep their type, OHLC series become a Line of close-to-close returns, and
+    /// other types yield an empty Line. Steps touching a non-positive or non-finite value are skipped.
+    pub fn to_log_returns(&self) -> Series {
+        let values: Vec<(f64, f64)> = match self.series_type {
+            SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline | SeriesType::Column => self.data_xy.clone(),
+            SeriesType::Candlestick | SeriesType::Bar => self.data_ohlc.iter().map(|c| (c.t, c.c)).collect(),
+            SeriesType::ErrorBar | SeriesType::Band => Vec::new(),
+        };
+        let valid = |v: f64| v.is_finite() && v > 0.0;
+        let data = values
+            .windows(2)
+            .filter(|w| valid(w[0].1) && valid(w[1].1))
+            .map(|w| (w[1].0, (w[1].1 / w[0].1).ln()))
+            .collect();
+        let series_type = match self.series_type {
+            SeriesType::Histogram | SeriesType::Baseline | SeriesType::Column => self.series_type,
+            _ => SeriesType::Line,
+        };
+        Series::with_data(series_type, data)
+    }
+
+    /// Per-point (x, y_low, y_high) used for autoscale, whatever the storage kind.
+    pub(crate) fn extents(&self) -> Box<dyn Iterator<Item = (f64, f64, f64)> + '_> {
+        match self.series_type {
+            SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline | SeriesType::Column => {
+                Box::new(self.data_xy.iter().map(|&(x, y)| (x, y, y)))
+            }
+            SeriesType::Candlestick | SeriesType::Bar => Box::new(self.data_ohlc.iter().map(|c| (c.t, c.l, c.h))),
+            SeriesType::ErrorBar => Box::new(self.data_xyerr.iter().map(|&(x, y, e)| (x, y - e.abs(), y + e.abs()))),
+            SeriesType::Band => Box::new(self.data_band.iter().map(|&(x, lo, hi)| (x, lo.min(hi), lo.max(hi)))),
+        }
+    }
+
+    /// Lowest and highest points as ((x, low), (x, high)) over `extents`, so candles use
+    /// their lows/highs and error bars their whiskers. The first point wins ties.
+    pub(crate) fn extreme_points(&self) -> Option<((f64, f64), (f64, f64))> {
+        let mut out: Option<((f64, f64), (f64, f64))> = None;
+        for (x, lo, hi) in self.extents().filter(|p| p.0.is_finite() && p.1.is_finite() && p.2.is_finite()) {
+            let (min, max) = out.get_or_insert(((x, lo), (x, hi)));
+            if lo < min.1 { *min = (x, lo); }
+            if hi > max.1 { *max = (x, hi); }
+        }
+        out
+    }
+
+    /// Baseline value that autoscale should keep in view (XY area/bar types only).
+    pub(crate) fn extent_baseline(&self) -> Option<f64> {
+        match self.series_type {
+            SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline | SeriesType::Column => self.baseline,
+            _ => None,
+        }
+    }
+
+    /// Check every candle against the `Candle::try_new` invariants and require a finite `t`.
+    /// Reports the first bad index.
+    pub fn validate_candles(&self) -> Result<(), SeriesError> {
+        for (index, c) in self.data_ohlc.iter().enumerate() {
+            if !c.t.is_finite() { return Err(SeriesError::NonFiniteX { index }); }
+            Candle::try_new(c.t, c.o, c.h, c.l, c.c).map_err(|reason| SeriesError::InvalidCandle { index, reason })?;
+        }
+        Ok(())
+    }
+
+    /// Classify every candle (empty for XY series).
+    pub fn classify_candles(&self) -> Vec<CandlePattern> {
+        self.data_ohlc.iter().map(Candle::pattern).collect()
+    }
+}
+
+/// JSON shapes accepted by `Series::from_json`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum JsonSeries {
+    Points(Vec<[f64; 2]>),
+    Candles(Vec<JsonCandle>),
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JsonCandle {
+    t: f64,
+    o: f64,
+    h: f64,
+    l: f64,
+    c: f64,
+}
+
+#[cfg(feature = "serde")]
+impl Series {
+    /// Build a series from JSON, detecting the shape: an array of `[x, y]` pairs gives a
+    /// Line, an array of `{"t", "o", "h", "l", "c"}` objects a Candlestick series. An empty
+    /// array is an empty Line. Anything else (mixed shapes, missing or extra keys) is an error.
+    pub fn from_json(src: &str) -> anyhow::Result<Series> {
+        let parsed: JsonSeries = serde_json::from_str(src)
+            .map_err(|e| anyhow::anyhow!("expected an array of [x, y] pairs or {{t, o, h, l, c}} objects: {e}"))?;
+        Ok(match parsed {
+            JsonSeries::Points(points) => Series::with_data(SeriesType::Line, points.into_iter().map(|[x, y]| (x, y)).collect()),
+            JsonSeries::Candles(candles) => {
+                Series::from_candles(candles.into_iter().map(|c| Candle { t: c.t, o: c.o, h: c.h, l: c.l, c: c.c }).collect())
+            }
+        })
+    }
 }
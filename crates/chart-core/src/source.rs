@@ -0,0 +1,71 @@
+// File: crates/chart-core/src/source.rs
+// Summary: Live data feeds: the DataSource polling trait, a replaying mock source and the
+//          adapter that streams polled candles into a chart and keeps the view scrolled.
+
+use crate::series::Candle;
+use crate::{Chart, ViewState};
+
+/// Producer of live candles, polled once per frame or timer tick.
+pub trait DataSource {
+    /// Candles that are new or updated since the previous poll, oldest first. A candle at
+    /// the same `t` as the last one delivered updates that bar. Empty when nothing changed.
+    fn poll(&mut self) -> Vec<Candle>;
+}
+
+/// Mock source replaying a fixed candle list, `per_poll` candles per call.
+pub struct ReplaySource {
+    candles: Vec<Candle>,
+    pos: usize,
+    per_poll: usize,
+}
+
+impl ReplaySource {
+    pub fn new(candles: Vec<Candle>, per_poll: usize) -> Self {
+        Self { candles, pos: 0, per_poll: per_poll.max(1) }
+    }
+
+    /// True once every candle has been delivered.
+    pub fn is_exhausted(&self) -> bool { self.pos >= self.candles.len() }
+}
+
+impl DataSource for ReplaySource {
+    fn poll(&mut self) -> Vec<Candle> {
+        let end = (self.pos + self.per_poll).min(self.candles.len());
+        let out = self.candles[self.pos..end].to_vec();
+        self.pos = end;
+        out
+    }
+}
+
+/// Streams a `DataSource` into one candle/bar series of a chart (via `Series::push_candle`).
+pub struct LiveFeed<S> {
+    pub source: S,
+    pub series_index: usize,
+}
+
+impl<S: DataSource> LiveFeed<S> {
+    pub fn new(source: S, series_index: usize) -> Self {
+        Self { source, series_index }
+    }
+
+    /// Poll once and apply the candles; returns how many were accepted (older-than-last
+    /// candles are dropped). If the view showed the latest bar at its right edge, it
+    /// scrolls by the same distance the last bar moved, keeping its span; a view panned
+    /// back into history stays put. A series that was empty gets the view fitted.
+    pub fn update(&mut self, chart: &mut Chart, view: &mut ViewState) -> usize {
+        let candles = self.source.poll();
+        let Some(series) = chart.series.get_mut(self.series_index) else { return 0 };
+        let before = series.data_ohlc.last().map(|c| c.t);
+        let applied = candles.into_iter().filter(|&c| series.push_candle(c)).count();
+        let after = series.data_ohlc.last().map(|c| c.t);
+        match (before, after) {
+            (Some(old), Some(new)) if new > old && view.x_max >= old => {
+                view.x_min += new - old;
+                view.x_max += new - old;
+            }
+            (None, Some(_)) => *view = ViewState::from_chart(chart),
+            _ => {}
+        }
+        applied
+    }
+}
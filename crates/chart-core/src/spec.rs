@@ -0,0 +1,140 @@
+// File: crates/chart-core/src/spec.rs
+// Summary: JSON chart specs (serde feature): series, axes and fills plus a theme name,
+//          and the "#RRGGBBAA" color encoding shared by the serde derives.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use skia_safe as skia;
+
+use crate::chart::{Chart, FillBetween};
+use crate::series::{Candle, Series};
+use crate::theme::{self, parse_hex_color, Theme};
+use crate::Axis;
+
+fn color_to_hex(c: skia::Color) -> String {
+    format!("#{:02X}{:02X}{:02X}{:02X}", c.r(), c.g(), c.b(), c.a())
+}
+
+fn hex_to_color<E: serde::de::Error>(s: &str) -> Result<skia::Color, E> {
+    parse_hex_color(s).ok_or_else(|| E::custom(format!("invalid color '{s}', expected #RRGGBB or #RRGGBBAA")))
+}
+
+/// `skia::Color` as a hex string.
+pub(crate) mod color {
+    use super::*;
+
+    pub fn serialize<S: serde::Serializer>(c: &skia::Color, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&color_to_hex(*c))
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(d: D) -> Result<skia::Color, D::Error> {
+        hex_to_color(&String::deserialize(d)?)
+    }
+}
+
+/// `Option<skia::Color>` as a hex string or null.
+pub(crate) mod opt_color {
+    use super::*;
+
+    pub fn serialize<S: serde::Serializer>(c: &Option<skia::Color>, s: S) -> Result<S::Ok, S::Error> {
+        c.map(color_to_hex).serialize(s)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Option<skia::Color>, D::Error> {
+        Option::<String>::deserialize(d)?.map(|s| hex_to_color(&s)).transpose()
+    }
+}
+
+/// `Series::threshold_color` as `[threshold, "#above", "#below"]` or null.
+pub(crate) mod opt_threshold {
+    use super::*;
+
+    pub fn serialize<S: serde::Serializer>(v: &Option<(f64, skia::Color, skia::Color)>, s: S) -> Result<S::Ok, S::Error> {
+        v.map(|(th, above, below)| (th, color_to_hex(above), color_to_hex(below))).serialize(s)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Option<(f64, skia::Color, skia::Color)>, D::Error> {
+        Option::<(f64, String, String)>::deserialize(d)?
+            .map(|(th, above, below)| Ok((th, hex_to_color(&above)?, hex_to_color(&below)?)))
+            .transpose()
+    }
+}
+
+// serde_json writes NaN/inf as null; gap points read back as NaN.
+fn gap(v: Option<f64>) -> f64 {
+    v.unwrap_or(f64::NAN)
+}
+
+/// `Vec<(x, y)>` with null coordinates read as NaN.
+pub(crate) fn gap_xy<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Vec<(f64, f64)>, D::Error> {
+    Ok(Vec::<(Option<f64>, Option<f64>)>::deserialize(d)?.into_iter().map(|(x, y)| (gap(x), gap(y))).collect())
+}
+
+/// `Vec<(f64, f64, f64)>` (error bars, bands) with null values read as NaN.
+pub(crate) fn gap_xyz<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Vec<(f64, f64, f64)>, D::Error> {
+    Ok(Vec::<(Option<f64>, Option<f64>, Option<f64>)>::deserialize(d)?
+        .into_iter()
+        .map(|(x, a, b)| (gap(x), gap(a), gap(b)))
+        .collect())
+}
+
+#[derive(Deserialize)]
+struct GapCandle {
+    t: Option<f64>,
+    o: Option<f64>,
+    h: Option<f64>,
+    l: Option<f64>,
+    c: Option<f64>,
+}
+
+/// `Vec<Candle>` with null fields read as NaN.
+pub(crate) fn gap_candles<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Vec<Candle>, D::Error> {
+    Ok(Vec::<GapCandle>::deserialize(d)?
+        .into_iter()
+        .map(|c| Candle { t: gap(c.t), o: gap(c.o), h: gap(c.h), l: gap(c.l), c: gap(c.c) })
+        .collect())
+}
+
+#[derive(Serialize, Deserialize)]
+struct ChartSpec {
+    theme: String,
+    x_axis: Axis,
+    y_axis: Axis,
+    series: Vec<Series>,
+    #[serde(default)]
+    nice_bounds: bool,
+    #[serde(default)]
+    fills: Vec<FillBetween>,
+}
+
+impl Chart {
+    /// Serialize the chart definition (series, axes, fills) with `theme`'s name, e.g. to
+    /// save it or hand it to a rendering service. Overlays are computed from code and are
+    /// not included. JSON has no NaN/inf: gap points are written as null and reload as NaN.
+    pub fn to_json(&self, theme: &Theme) -> Result<String> {
+        let spec = ChartSpec {
+            theme: theme.name.to_string(),
+            x_axis: self.x_axis.clone(),
+            y_axis: self.y_axis.clone(),
+            series: self.series.clone(),
+            nice_bounds: self.nice_bounds,
+            fills: self.fills.clone(),
+        };
+        Ok(serde_json::to_string(&spec)?)
+    }
+
+    /// Inverse of `to_json`. The theme is looked up with `theme::find`, so an unknown
+    /// name falls back to the dark preset.
+    pub fn from_json(src: &str) -> Result<(Chart, Theme)> {
+        let spec: ChartSpec = serde_json::from_str(src)?;
+        let chart = Chart {
+            series: spec.series,
+            x_axis: spec.x_axis,
+            y_axis: spec.y_axis,
+            nice_bounds: spec.nice_bounds,
+            fills: spec.fills,
+            ..Chart::new()
+        };
+        Ok((chart, theme::find(&spec.theme)))
+    }
+}
@@ -2,10 +2,21 @@
 // Summary: Simple text shaper/renderer using Skia textlayout with sensible defaults.
 
 use skia_safe as skia;
-use skia::textlayout::{FontCollection, Paragraph, ParagraphBuilder, ParagraphStyle, TextStyle};
+use skia::textlayout::{FontCollection, Paragraph, ParagraphBuilder, ParagraphStyle, TextStyle, TypefaceFontProvider};
+
+/// DejaVu Sans Mono, embedded so deterministic renders don't depend on installed fonts
+/// (license: assets/DejaVu-LICENSE.txt).
+const BUNDLED_FONT: &[u8] = include_bytes!("../assets/DejaVuSansMono.ttf");
+const BUNDLED_FAMILY: &str = "Constellation Bundled Mono";
+
+thread_local! {
+    // Parsed once per thread; typefaces are reference-counted, so every bundled shaper shares it.
+    static BUNDLED_TYPEFACE: Option<skia::Typeface> = skia::FontMgr::default().new_from_data(BUNDLED_FONT, None);
+}
 
 pub struct TextShaper {
     fonts: FontCollection,
+    bundled: bool,
 }
 
 impl TextShaper {
@@ -13,14 +24,29 @@ impl TextShaper {
         let mut fc = FontCollection::new();
         // Use system manager fallback
         fc.set_default_font_manager(skia::FontMgr::default(), None);
-        Self { fonts: fc }
+        Self { fonts: fc, bundled: false }
+    }
+
+    /// Shaper that only uses the embedded font, with fallback and hinting off, so text
+    /// metrics and glyphs are identical on every platform.
+    pub fn bundled() -> Self {
+        let mut provider = TypefaceFontProvider::new();
+        if let Some(tf) = BUNDLED_TYPEFACE.with(Clone::clone) {
+            provider.register_typeface(tf, Some(BUNDLED_FAMILY));
+        }
+        let mut fc = FontCollection::new();
+        fc.set_asset_font_manager(Some(provider.into()));
+        fc.disable_font_fallback();
+        Self { fonts: fc, bundled: true }
     }
 
-    fn make_style(size: f32, color: skia::Color, mono_numeric: bool) -> TextStyle {
+    fn make_style(&self, size: f32, color: skia::Color, mono_numeric: bool) -> TextStyle {
         let mut ts = TextStyle::new();
         ts.set_font_size(size.max(1.0));
         ts.set_color(color);
-        if mono_numeric {
+        if self.bundled {
+            ts.set_font_families(&[BUNDLED_FAMILY]);
+        } else if mono_numeric {
             // Prefer monospaced/tabular-number families for numeric alignment
             ts.set_font_families(&["Roboto Mono", "Consolas", "Menlo", "DejaVu Sans Mono", "monospace"]);
         } else {
@@ -32,8 +58,9 @@ impl TextShaper {
     pub fn layout(&self, text: &str, size: f32, color: skia::Color, mono_numeric: bool) -> Paragraph {
         let mut pstyle = ParagraphStyle::new();
         pstyle.set_text_align(skia::textlayout::TextAlign::Left);
+        if self.bundled { pstyle.turn_hinting_off(); }
         let mut builder = ParagraphBuilder::new(&pstyle, &self.fonts);
-        let style = Self::make_style(size, color, mono_numeric);
+        let style = self.make_style(size, color, mono_numeric);
         builder.push_style(&style);
         builder.add_text(text);
         let mut paragraph = builder.build();
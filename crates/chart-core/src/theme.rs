@@ -1,6 +1,8 @@
 // File: crates/chart-core/src/theme.rs
 // Summary: Light/Dark theming for chart rendering colors.
 
+use std::sync::RwLock;
+
 use skia_safe as skia;
 
 #[derive(Clone, Copy, Debug)]
@@ -15,9 +17,14 @@ pub struct Theme {
     pub line_stroke: skia::Color,
     pub candle_up: skia::Color,
     pub candle_down: skia::Color,
+    pub candle_up_border: skia::Color,   // wicks and body outlines of up candles
+    pub candle_down_border: skia::Color, // wicks and body outlines of down candles
     pub histogram: skia::Color,
     pub baseline_stroke: skia::Color,
     pub baseline_fill: skia::Color,
+    pub baseline_fill_neg: skia::Color, // baseline area below the baseline; equal to baseline_fill for one color
+    pub band_fill: skia::Color,
+    pub band_stroke: skia::Color,
 }
 
 impl Theme {
@@ -33,9 +40,14 @@ impl Theme {
             line_stroke: skia::Color::from_argb(255, 64, 160, 255),
             candle_up: skia::Color::from_argb(255, 40, 200, 120),
             candle_down: skia::Color::from_argb(255, 220, 80, 80),
+            candle_up_border: skia::Color::from_argb(255, 40, 200, 120),
+            candle_down_border: skia::Color::from_argb(255, 220, 80, 80),
             histogram: skia::Color::from_argb(255, 96, 156, 255),
             baseline_stroke: skia::Color::from_argb(255, 64, 160, 255),
             baseline_fill: skia::Color::from_argb(96, 64, 160, 255),
+            baseline_fill_neg: skia::Color::from_argb(96, 64, 160, 255),
+            band_fill: skia::Color::from_argb(48, 64, 160, 255),
+            band_stroke: skia::Color::from_argb(200, 64, 160, 255),
         }
     }
 
@@ -51,9 +63,14 @@ impl Theme {
             line_stroke: skia::Color::from_argb(255, 32, 120, 200),
             candle_up: skia::Color::from_argb(255, 20, 160, 90),
             candle_down: skia::Color::from_argb(255, 200, 60, 60),
+            candle_up_border: skia::Color::from_argb(255, 20, 160, 90),
+            candle_down_border: skia::Color::from_argb(255, 200, 60, 60),
             histogram: skia::Color::from_argb(255, 40, 120, 200),
             baseline_stroke: skia::Color::from_argb(255, 32, 120, 200),
             baseline_fill: skia::Color::from_argb(80, 32, 120, 200),
+            baseline_fill_neg: skia::Color::from_argb(80, 32, 120, 200),
+            band_fill: skia::Color::from_argb(48, 32, 120, 200),
+            band_stroke: skia::Color::from_argb(200, 32, 120, 200),
         }
     }
 
@@ -70,9 +87,14 @@ impl Theme {
             line_stroke: skia::Color::from_argb(255, 0x26, 0x8b, 0xd2), // blue
             candle_up: skia::Color::from_argb(255, 0x2a, 0xa1, 0x98),   // cyan/green
             candle_down: skia::Color::from_argb(255, 0xdc, 0x32, 0x2f), // red
+            candle_up_border: skia::Color::from_argb(255, 0x2a, 0xa1, 0x98),
+            candle_down_border: skia::Color::from_argb(255, 0xdc, 0x32, 0x2f),
             histogram: skia::Color::from_argb(255, 0x26, 0x8b, 0xd2),
             baseline_stroke: skia::Color::from_argb(255, 0x26, 0x8b, 0xd2),
             baseline_fill: skia::Color::from_argb(96, 0x26, 0x8b, 0xd2),
+            baseline_fill_neg: skia::Color::from_argb(96, 0x26, 0x8b, 0xd2),
+            band_fill: skia::Color::from_argb(48, 0x26, 0x8b, 0xd2),
+            band_stroke: skia::Color::from_argb(200, 0x26, 0x8b, 0xd2),
         }
     }
 
@@ -88,9 +110,14 @@ impl Theme {
             line_stroke: skia::Color::from_argb(255, 0x26, 0x8b, 0xd2),
             candle_up: skia::Color::from_argb(255, 0x2a, 0xa1, 0x98),
             candle_down: skia::Color::from_argb(255, 0xdc, 0x32, 0x2f),
+            candle_up_border: skia::Color::from_argb(255, 0x2a, 0xa1, 0x98),
+            candle_down_border: skia::Color::from_argb(255, 0xdc, 0x32, 0x2f),
             histogram: skia::Color::from_argb(255, 0x26, 0x8b, 0xd2),
             baseline_stroke: skia::Color::from_argb(255, 0x26, 0x8b, 0xd2),
             baseline_fill: skia::Color::from_argb(80, 0x26, 0x8b, 0xd2),
+            baseline_fill_neg: skia::Color::from_argb(80, 0x26, 0x8b, 0xd2),
+            band_fill: skia::Color::from_argb(48, 0x26, 0x8b, 0xd2),
+            band_stroke: skia::Color::from_argb(200, 0x26, 0x8b, 0xd2),
         }
     }
 
@@ -106,10 +133,201 @@ impl Theme {
             line_stroke: skia::Color::from_argb(255, 0x00, 0xff, 0xff),
             candle_up: skia::Color::from_argb(255, 0x00, 0xff, 0x00),
             candle_down: skia::Color::from_argb(255, 0xff, 0x00, 0x00),
+            candle_up_border: skia::Color::from_argb(255, 0x00, 0xff, 0x00),
+            candle_down_border: skia::Color::from_argb(255, 0xff, 0x00, 0x00),
             histogram: skia::Color::from_argb(255, 0x00, 0xaa, 0xff),
             baseline_stroke: skia::Color::from_argb(255, 0x00, 0xaa, 0xff),
             baseline_fill: skia::Color::from_argb(120, 0x00, 0xaa, 0xff),
+            baseline_fill_neg: skia::Color::from_argb(120, 0x00, 0xaa, 0xff),
+            band_fill: skia::Color::from_argb(48, 0x00, 0xff, 0xff),
+            band_stroke: skia::Color::from_argb(200, 0x00, 0xff, 0xff),
+        }
+    }
+}
+
+impl Theme {
+    /// Start a `ThemeBuilder` from the dark preset.
+    pub fn builder() -> ThemeBuilder {
+        ThemeBuilder::new()
+    }
+
+    /// Recolor the accent-driven fields (line, baseline, histogram, band, up candles) to
+    /// `accent`, keeping each field's own alpha so fills stay translucent.
+    pub fn with_accent(mut self, accent: skia::Color) -> Self {
+        let tint = |c: skia::Color| accent.with_a(c.a());
+        self.line_stroke = tint(self.line_stroke);
+        self.baseline_stroke = tint(self.baseline_stroke);
+        // A one-color baseline area stays one color
+        if self.baseline_fill_neg == self.baseline_fill {
+            self.baseline_fill_neg = tint(self.baseline_fill_neg);
+        }
+        self.baseline_fill = tint(self.baseline_fill);
+        self.histogram = tint(self.histogram);
+        self.band_fill = tint(self.band_fill);
+        self.band_stroke = tint(self.band_stroke);
+        self.candle_up = tint(self.candle_up);
+        self.candle_up_border = tint(self.candle_up_border);
+        self
+    }
+}
+
+/// Parse `"#RRGGBB"` or `"#RRGGBBAA"` into a color; `None` for anything else.
+pub fn parse_hex_color(s: &str) -> Option<skia::Color> {
+    let hex = s.trim().strip_prefix('#')?;
+    if !hex.is_ascii() || !(hex.len() == 6 || hex.len() == 8) { return None; }
+    let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    let (r, g, b) = (byte(0)?, byte(2)?, byte(4)?);
+    let a = if hex.len() == 8 { byte(6)? } else { 255 };
+    Some(skia::Color::from_argb(a, r, g, b))
+}
+
+/// On-disk theme layout: every color is optional and falls back to the `base` preset.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeConfig {
+    name: Option<String>,
+    base: Option<String>,
+    background: Option<String>,
+    grid: Option<String>,
+    axis_line: Option<String>,
+    axis_label: Option<String>,
+    tick: Option<String>,
+    crosshair: Option<String>,
+    line_stroke: Option<String>,
+    candle_up: Option<String>,
+    candle_down: Option<String>,
+    candle_up_border: Option<String>,
+    candle_down_border: Option<String>,
+    histogram: Option<String>,
+    baseline_stroke: Option<String>,
+    baseline_fill: Option<String>,
+    baseline_fill_neg: Option<String>,
+    band_fill: Option<String>,
+    band_stroke: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+impl Theme {
+    /// Load a theme from TOML, e.g.
+    ///
+    /// ```toml
+    /// name = "brand"
+    /// base = "light"          # preset supplying omitted colors (default: dark)
+    /// background = "#101820"
+    /// band_fill = "#2080c030"
+    /// ```
+    ///
+    /// Colors are `"#RRGGBB"` or `"#RRGGBBAA"`. Unknown keys, unknown base themes and
+    /// malformed colors are errors. A custom `name` is leaked to obtain the `&'static str`, so this is meant
+    /// for loading a handful of themes at startup, not in a loop.
+    pub fn from_toml_str(src: &str) -> anyhow::Result<Theme> {
+        let cfg: ThemeConfig = toml::from_str(src)?;
+        let mut theme = match cfg.base.as_deref() {
+            Some(base) => themes()
+                .into_iter()
+                .find(|t| t.name.eq_ignore_ascii_case(base))
+                .ok_or_else(|| anyhow::anyhow!("unknown base theme '{base}'"))?,
+            None => Theme::dark(),
+        };
+        if let Some(name) = cfg.name {
+            theme.name = Box::leak(name.into_boxed_str());
         }
+        let fields = [
+            ("background", cfg.background, &mut theme.background),
+            ("grid", cfg.grid, &mut theme.grid),
+            ("axis_line", cfg.axis_line, &mut theme.axis_line),
+            ("axis_label", cfg.axis_label, &mut theme.axis_label),
+            ("tick", cfg.tick, &mut theme.tick),
+            ("crosshair", cfg.crosshair, &mut theme.crosshair),
+            ("line_stroke", cfg.line_stroke, &mut theme.line_stroke),
+            ("candle_up", cfg.candle_up, &mut theme.candle_up),
+            ("candle_down", cfg.candle_down, &mut theme.candle_down),
+            ("candle_up_border", cfg.candle_up_border, &mut theme.candle_up_border),
+            ("candle_down_border", cfg.candle_down_border, &mut theme.candle_down_border),
+            ("histogram", cfg.histogram, &mut theme.histogram),
+            ("baseline_stroke", cfg.baseline_stroke, &mut theme.baseline_stroke),
+            ("baseline_fill", cfg.baseline_fill, &mut theme.baseline_fill),
+            ("baseline_fill_neg", cfg.baseline_fill_neg, &mut theme.baseline_fill_neg),
+            ("band_fill", cfg.band_fill, &mut theme.band_fill),
+            ("band_stroke", cfg.band_stroke, &mut theme.band_stroke),
+        ];
+        for (key, value, slot) in fields {
+            let Some(value) = value else { continue };
+            *slot = parse_hex_color(&value)
+                .ok_or_else(|| anyhow::anyhow!("theme field `{key}`: invalid color {value:?}"))?;
+        }
+        Ok(theme)
+    }
+}
+
+/// Chainable theme construction; every field not set keeps the starting theme's value.
+#[derive(Clone, Copy, Debug)]
+pub struct ThemeBuilder {
+    theme: Theme,
+}
+
+macro_rules! theme_setters {
+    ($($field:ident),* $(,)?) => {
+        $(
+            pub fn $field(mut self, color: skia::Color) -> Self {
+                self.theme.$field = color;
+                self
+            }
+        )*
+    };
+}
+
+impl ThemeBuilder {
+    /// Builder seeded with `Theme::dark()`.
+    pub fn new() -> Self {
+        Self::from_theme(Theme::dark())
+    }
+
+    /// Builder seeded with an existing theme (e.g. a preset to tweak).
+    pub fn from_theme(theme: Theme) -> Self {
+        Self { theme }
+    }
+
+    pub fn name(mut self, name: &'static str) -> Self {
+        self.theme.name = name;
+        self
+    }
+
+    /// See `Theme::with_accent`.
+    pub fn accent(mut self, accent: skia::Color) -> Self {
+        self.theme = self.theme.with_accent(accent);
+        self
+    }
+
+    theme_setters!(
+        background,
+        grid,
+        axis_line,
+        axis_label,
+        tick,
+        crosshair,
+        line_stroke,
+        candle_up,
+        candle_down,
+        candle_up_border,
+        candle_down_border,
+        histogram,
+        baseline_stroke,
+        baseline_fill,
+        baseline_fill_neg,
+        band_fill,
+        band_stroke,
+    );
+
+    pub fn build(self) -> Theme {
+        self.theme
+    }
+}
+
+impl Default for ThemeBuilder {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -124,8 +342,33 @@ pub fn presets() -> Vec<Theme> {
     ]
 }
 
-/// Find a theme by its `name`, falling back to dark.
+/// Application themes added at runtime via `register_theme`.
+static REGISTRY: RwLock<Vec<Theme>> = RwLock::new(Vec::new());
+
+/// Make `theme` available to `find` and `themes`. A registered theme replaces an earlier
+/// registration with the same name (case-insensitive) and shadows a preset of that name.
+pub fn register_theme(theme: Theme) {
+    let mut reg = REGISTRY.write().unwrap_or_else(|e| e.into_inner());
+    match reg.iter_mut().find(|t| t.name.eq_ignore_ascii_case(theme.name)) {
+        Some(slot) => *slot = theme,
+        None => reg.push(theme),
+    }
+}
+
+/// Built-in presets followed by registered themes, in registration order.
+/// Presets shadowed by a registered theme of the same name are omitted.
+pub fn themes() -> Vec<Theme> {
+    let reg = REGISTRY.read().unwrap_or_else(|e| e.into_inner());
+    let mut out: Vec<Theme> = presets()
+        .into_iter()
+        .filter(|p| !reg.iter().any(|t| t.name.eq_ignore_ascii_case(p.name)))
+        .collect();
+    out.extend(reg.iter().copied());
+    out
+}
+
+/// Find a theme by its `name` among registered themes and presets, falling back to dark.
 pub fn find(name: &str) -> Theme {
-    for t in presets() { if t.name.eq_ignore_ascii_case(name) { return t; } }
+    for t in themes() { if t.name.eq_ignore_ascii_case(name) { return t; } }
     Theme::dark()
 }
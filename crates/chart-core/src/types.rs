@@ -6,7 +6,7 @@ pub const WIDTH: i32 = 1024;
 /// Default surface height in pixels.
 pub const HEIGHT: i32 = 640;
 
-/// Screen margins, in pixels.
+/// Screen margins, in logical pixels; renderers multiply them by `RenderOptions::dpr`.
 /// Contract: all fields are non-negative.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Insets {
@@ -25,6 +25,11 @@ impl Insets {
     pub const fn hsum(&self) -> u32 { self.left + self.right }
     /// Total vertical inset (top + bottom).
     pub const fn vsum(&self) -> u32 { self.top + self.bottom }
+    /// Insets in device pixels for a device pixel ratio, each side rounded to whole pixels.
+    pub fn scaled(&self, dpr: f32) -> Self {
+        let s = |v: u32| (v as f32 * dpr.max(0.5)).round() as u32;
+        Self::new(s(self.left), s(self.right), s(self.top), s(self.bottom))
+    }
 }
 
 impl Default for Insets {
@@ -1,12 +1,15 @@
 // File: crates/chart-core/src/view.rs
 // First-class view state: visible ranges and helpers for pan/zoom/autoscale.
 
+use std::time::Duration;
+
 use crate::{Chart};
-use crate::series::SeriesType;
+use crate::axis::ScaleKind;
 use crate::types::Insets;
 // Removed unused imports to tidy warnings
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ViewState {
     pub x_min: f64,
     pub x_max: f64,
@@ -14,40 +17,40 @@ pub struct ViewState {
     pub y_max: f64,
 }
 
+/// Default symmetric y padding used by `from_chart` and `autoscale_y_visible`.
+pub const DEFAULT_PADDING_FRAC: f64 = 0.02;
+
 impl ViewState {
+    /// Fit all series with no x margin and `DEFAULT_PADDING_FRAC` of y margin.
     pub fn from_chart(chart: &Chart) -> Self {
+        Self::from_chart_with(chart, 0.0, DEFAULT_PADDING_FRAC)
+    }
+
+    /// Fit all series, then widen each range by the given fraction of its span on both
+    /// sides (e.g. a larger `y_margin_frac` leaves headroom for annotation labels).
+    /// Negative fractions count as zero.
+    pub fn from_chart_with(chart: &Chart, x_margin_frac: f64, y_margin_frac: f64) -> Self {
         let mut x_min = f64::INFINITY;
         let mut x_max = f64::NEG_INFINITY;
         let mut y_min = f64::INFINITY;
         let mut y_max = f64::NEG_INFINITY;
-        for s in &chart.series {
-            match s.series_type {
-                SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline => {
-                    for &(x, y) in &s.data_xy {
-                        x_min = x_min.min(x);
-                        x_max = x_max.max(x);
-                        y_min = y_min.min(y);
-                        y_max = y_max.max(y);
-                    }
-                    if let Some(b) = s.baseline { y_min = y_min.min(b); y_max = y_max.max(b); }
-                }
-                SeriesType::Candlestick | SeriesType::Bar => {
-                    for c in &s.data_ohlc {
-                        x_min = x_min.min(c.t);
-                        x_max = x_max.max(c.t);
-                        y_min = y_min.min(c.l);
-                        y_max = y_max.max(c.h);
-                    }
-                }
+        for (s, extents) in chart.series.iter().zip(chart.series_extents()).filter(|(s, _)| s.include_in_autoscale) {
+            for (x, lo, hi) in extents {
+                x_min = x_min.min(x);
+                x_max = x_max.max(x);
+                y_min = y_min.min(lo);
+                y_max = y_max.max(hi);
             }
+            if let Some(b) = s.extent_baseline() { y_min = y_min.min(b); y_max = y_max.max(b); }
         }
         if !x_min.is_finite() || !x_max.is_finite() || !y_min.is_finite() || !y_max.is_finite() {
             return Self { x_min: 0.0, x_max: 1.0, y_min: 0.0, y_max: 1.0 };
         }
         if (x_max - x_min).abs() < 1e-9 { x_max = x_min + 1.0; }
         if (y_max - y_min).abs() < 1e-9 { y_max = y_min + 1.0; }
-        let ym = (y_max - y_min) * 0.02;
-        Self { x_min, x_max, y_min: y_min - ym, y_max: y_max + ym }
+        let xm = (x_max - x_min) * x_margin_frac.max(0.0);
+        let ym = (y_max - y_min) * y_margin_frac.max(0.0);
+        Self { x_min: x_min - xm, x_max: x_max + xm, y_min: y_min - ym, y_max: y_max + ym }
     }
 
     pub fn apply_to_chart(&self, chart: &mut Chart) {
@@ -68,6 +71,26 @@ impl ViewState {
         self.y_min += wy; self.y_max += wy;
     }
 
+    /// Keep the world x at the center of the view fixed while the visible x span grows or
+    /// shrinks with the window width, so bars keep their on-screen size across a resize.
+    /// Non-positive widths leave the view unchanged.
+    pub fn rescale_for_new_width(&mut self, old_width: i32, new_width: i32) {
+        if old_width <= 0 || new_width <= 0 { return; }
+        let center = (self.x_min + self.x_max) * 0.5;
+        let half = (self.x_max - self.x_min) * 0.5 * new_width as f64 / old_width as f64;
+        self.x_min = center - half;
+        self.x_max = center + half;
+    }
+
+    /// Pin the right edge to the newest x of any series and show `window_span` before it,
+    /// as for a realtime chart. Y is left alone. Returns false (view unchanged) without data.
+    pub fn follow_latest(&mut self, chart: &Chart, window_span: f64) -> bool {
+        let Some(last) = chart.series.iter().filter_map(|s| s.x_range()).map(|r| r.1).reduce(f64::max) else { return false };
+        self.x_max = last;
+        self.x_min = last - window_span.abs();
+        true
+    }
+
     pub fn zoom_at_pixel(&mut self, scroll: f64, cursor_x: f64, cursor_y: f64, width: i32, height: i32, insets: &Insets) {
         let w = width as f64; let h = height as f64;
         let l = insets.left as f64; let rpx = w - insets.right as f64;
@@ -85,41 +108,281 @@ impl ViewState {
     }
 
     pub fn autoscale_y_visible(&mut self, chart: &Chart) -> bool {
-        if let Some((ymin, ymax)) = visible_y_range(chart, self.x_min, self.x_max) {
-            let m = (ymax - ymin) * 0.02;
+        self.autoscale_y_visible_padded(chart, DEFAULT_PADDING_FRAC)
+    }
+
+    /// Like `autoscale_y_visible`, with an explicit padding fraction of the visible y span.
+    pub fn autoscale_y_visible_padded(&mut self, chart: &Chart, pad_frac: f64) -> bool {
+        self.set_y_range(visible_y_range(chart, self.x_min, self.x_max), pad_frac)
+    }
+
+    /// Like `autoscale_y_visible`, but bands and lines computed by the chart's overlays
+    /// also count, so e.g. a Bollinger upper band isn't clipped.
+    pub fn autoscale_y_visible_with_overlays(&mut self, chart: &Chart) -> bool {
+        self.set_y_range(visible_y_range_with_overlays(chart, self.x_min, self.x_max), DEFAULT_PADDING_FRAC)
+    }
+
+    fn set_y_range(&mut self, range: Option<(f64, f64)>, pad_frac: f64) -> bool {
+        if let Some((ymin, ymax)) = range {
+            let m = (ymax - ymin) * pad_frac.max(0.0);
             self.y_min = ymin - m;
             self.y_max = ymax + m;
             true
         } else { false }
     }
+
+    /// Interpolate every bound linearly: `t = 0` gives `self`, `t = 1` gives `other`.
+    /// `t` is not clamped, so easing curves that overshoot work too.
+    pub fn lerp(&self, other: &ViewState, t: f64) -> ViewState {
+        self.lerp_scaled(other, t, ScaleKind::Linear, ScaleKind::Linear)
+    }
+
+    /// Like `lerp`, but bounds of a `Log10` axis move geometrically so zooms on log axes
+    /// look uniform. Falls back to linear for a pair of bounds that isn't strictly positive.
+    pub fn lerp_scaled(&self, other: &ViewState, t: f64, x_kind: ScaleKind, y_kind: ScaleKind) -> ViewState {
+        fn mix(a: f64, b: f64, t: f64, kind: ScaleKind) -> f64 {
+            match kind {
+                ScaleKind::Log10 if a > 0.0 && b > 0.0 => (a.ln() + (b.ln() - a.ln()) * t).exp(),
+                _ => a + (b - a) * t,
+            }
+        }
+        ViewState {
+            x_min: mix(self.x_min, other.x_min, t, x_kind),
+            x_max: mix(self.x_max, other.x_max, t, x_kind),
+            y_min: mix(self.y_min, other.y_min, t, y_kind),
+            y_max: mix(self.y_max, other.y_max, t, y_kind),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ViewState {
+    /// Serialize the visible ranges, e.g. to persist zoom/pan between sessions:
+    /// `{"x_min":0.0,"x_max":10.0,"y_min":-1.0,"y_max":1.0}`.
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Inverse of `to_json`. All four bounds are required.
+    pub fn from_json(src: &str) -> anyhow::Result<ViewState> {
+        Ok(serde_json::from_str(src)?)
+    }
+}
+
+/// Cubic ease-out: fast start, gentle settle. Maps 0..1 onto 0..1.
+pub fn ease_out_cubic(t: f64) -> f64 {
+    let u = 1.0 - t.clamp(0.0, 1.0);
+    1.0 - u * u * u
+}
+
+/// Time-based transition between two views, e.g. animating a reset to fit-all.
+/// The caller tracks elapsed time and applies `sample` each frame.
+#[derive(Clone, Copy, Debug)]
+pub struct Animator {
+    pub from: ViewState,
+    pub to: ViewState,
+    pub duration: Duration,
+    pub easing: fn(f64) -> f64,
+}
+
+impl Animator {
+    /// Animate `from` -> `to` over `duration` with `ease_out_cubic`.
+    pub fn new(from: ViewState, to: ViewState, duration: Duration) -> Self {
+        Self { from, to, duration, easing: ease_out_cubic }
+    }
+
+    /// Builder-style: replace the easing curve (input and output in 0..1).
+    pub fn with_easing(mut self, easing: fn(f64) -> f64) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// View at `elapsed`; exactly `to` once the duration has passed.
+    pub fn sample(&self, elapsed: Duration) -> ViewState {
+        if self.is_finished(elapsed) { return self.to; }
+        let t = elapsed.as_secs_f64() / self.duration.as_secs_f64();
+        self.from.lerp(&self.to, (self.easing)(t))
+    }
+
+    pub fn is_finished(&self, elapsed: Duration) -> bool {
+        elapsed >= self.duration
+    }
+}
+
+/// Realtime mode: keeps calling `ViewState::follow_latest` as data streams in, until the
+/// user pans or zooms away from the pinned range, which turns `following` off.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FollowLatest {
+    pub following: bool,
+    pub window_span: f64,
+    pinned: Option<(f64, f64)>, // x range set by the last update, to spot user changes
+}
+
+impl FollowLatest {
+    /// Start following with a fixed visible x span.
+    pub fn new(window_span: f64) -> Self {
+        Self { following: true, window_span, pinned: None }
+    }
+
+    /// Follow again, e.g. from a "go to realtime" button.
+    pub fn resume(&mut self) {
+        self.following = true;
+        self.pinned = None;
+    }
+
+    /// Call after new data arrives (or every frame). Returns true if the view moved.
+    pub fn update(&mut self, view: &mut ViewState, chart: &Chart) -> bool {
+        if !self.following { return false; }
+        if self.pinned.is_some_and(|p| p != (view.x_min, view.x_max)) {
+            self.following = false;
+            self.pinned = None;
+            return false;
+        }
+        let before = (view.x_min, view.x_max);
+        if !view.follow_latest(chart, self.window_span) { return false; }
+        self.pinned = Some((view.x_min, view.x_max));
+        before != (view.x_min, view.x_max)
+    }
+}
+
+/// Mouse-wheel zoom tuning shared by the frontends: turns wheel deltas into the `scroll`
+/// argument of `ViewState::zoom_at_pixel` (positive zooms in).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZoomConfig {
+    pub sensitivity: f64, // multiplier on the default step (0.1 per wheel notch)
+    pub invert: bool,     // reverse the direction, e.g. for natural scrolling
+}
+
+impl Default for ZoomConfig {
+    fn default() -> Self { Self { sensitivity: 1.0, invert: false } }
+}
+
+impl ZoomConfig {
+    /// Scroll for a wheel step of `lines` notches.
+    pub fn scroll_for_lines(&self, lines: f64) -> f64 {
+        self.apply(lines * 0.1)
+    }
+
+    /// Scroll for a trackpad step of `pixels`; 24 px match one notch.
+    pub fn scroll_for_pixels(&self, pixels: f64) -> f64 {
+        self.apply(pixels / 240.0)
+    }
+
+    fn apply(&self, scroll: f64) -> f64 {
+        let scroll = scroll * self.sensitivity;
+        if self.invert { -scroll } else { scroll }
+    }
+}
+
+/// Momentum for drag panning. Feed pointer deltas to `track` while dragging, call
+/// `release` on button up, then pan by whatever `tick` returns each frame until it
+/// yields None.
+#[derive(Clone, Copy, Debug)]
+pub struct PanInertia {
+    pub vx: f64, // px per second
+    pub vy: f64,
+    pub friction: f64,  // exponential decay rate per second: v *= exp(-friction * dt)
+    pub min_speed: f64, // px/s below which the motion stops
+}
+
+impl Default for PanInertia {
+    fn default() -> Self { Self { vx: 0.0, vy: 0.0, friction: 5.0, min_speed: 20.0 } }
+}
+
+impl PanInertia {
+    /// Drag releases coast only if the pointer moved within this long before release.
+    const RELEASE_WINDOW: Duration = Duration::from_millis(100);
+
+    pub fn new() -> Self { Self::default() }
+
+    /// Record a drag step of `dx`/`dy` px taken over `dt`. Velocity is smoothed over the
+    /// recent steps so one jittery event doesn't decide the fling.
+    pub fn track(&mut self, dx: f64, dy: f64, dt: Duration) {
+        let dt = dt.as_secs_f64().max(1e-3);
+        self.vx = self.vx * 0.5 + dx / dt * 0.5;
+        self.vy = self.vy * 0.5 + dy / dt * 0.5;
+    }
+
+    /// Pointer released `idle` after the last tracked move; holding still first cancels the fling.
+    pub fn release(&mut self, idle: Duration) {
+        if idle > Self::RELEASE_WINDOW { self.stop(); }
+    }
+
+    pub fn stop(&mut self) {
+        self.vx = 0.0;
+        self.vy = 0.0;
+    }
+
+    pub fn is_moving(&self) -> bool {
+        self.vx.hypot(self.vy) >= self.min_speed
+    }
+
+    /// Advance by `dt`: the pixels to pan this frame (for `ViewState::pan_by_pixels`), or
+    /// None once the velocity has decayed below `min_speed` (it is then zeroed).
+    pub fn tick(&mut self, dt: Duration) -> Option<(f64, f64)> {
+        if !self.is_moving() {
+            self.stop();
+            return None;
+        }
+        let dt = dt.as_secs_f64();
+        let step = (self.vx * dt, self.vy * dt);
+        let k = (-self.friction.max(0.0) * dt).exp();
+        self.vx *= k;
+        self.vy *= k;
+        Some(step)
+    }
 }
 
 pub fn visible_y_range(chart: &Chart, x_min: f64, x_max: f64) -> Option<(f64, f64)> {
-    let mut y_min = f64::INFINITY;
-    let mut y_max = f64::NEG_INFINITY;
-    let mut any = false;
-    for s in &chart.series {
-        match s.series_type {
-            SeriesType::Line | SeriesType::Histogram | SeriesType::Baseline => {
-                for &(x, y) in &s.data_xy {
-                    if x >= x_min && x <= x_max {
-                        y_min = y_min.min(y);
-                        y_max = y_max.max(y);
-                        any = true;
-                    }
-                }
-                if let Some(b) = s.baseline { y_min = y_min.min(b); y_max = y_max.max(b); }
-            }
-            SeriesType::Candlestick | SeriesType::Bar => {
-                for c in &s.data_ohlc {
-                    if c.t >= x_min && c.t <= x_max {
-                        y_min = y_min.min(c.l);
-                        y_max = y_max.max(c.h);
-                        any = true;
-                    }
-                }
+    let mut range = VisibleRange::new(x_min, x_max);
+    for (s, extents) in chart.series.iter().zip(chart.series_extents()) {
+        range.add(s, extents);
+    }
+    range.finish()
+}
+
+/// `visible_y_range` plus the series computed by the chart's main-pane overlays.
+pub fn visible_y_range_with_overlays(chart: &Chart, x_min: f64, x_max: f64) -> Option<(f64, f64)> {
+    let mut range = VisibleRange::new(x_min, x_max);
+    for (s, extents) in chart.series.iter().zip(chart.series_extents()) {
+        range.add(s, extents);
+    }
+    for ov in chart.main_overlays() {
+        for s in &ov.compute(chart) {
+            range.add(s, s.extents());
+        }
+    }
+    range.finish()
+}
+
+/// Running y extent of the points inside an x window. Baselines always count, but only
+/// visible points make the range non-empty. Series excluded from autoscale are skipped.
+struct VisibleRange {
+    x_min: f64,
+    x_max: f64,
+    y_min: f64,
+    y_max: f64,
+    any: bool,
+}
+
+impl VisibleRange {
+    fn new(x_min: f64, x_max: f64) -> Self {
+        Self { x_min, x_max, y_min: f64::INFINITY, y_max: f64::NEG_INFINITY, any: false }
+    }
+
+    fn add(&mut self, s: &crate::Series, extents: impl Iterator<Item = (f64, f64, f64)>) {
+        if !s.include_in_autoscale { return; }
+        for (x, lo, hi) in extents {
+            if x >= self.x_min && x <= self.x_max {
+                self.y_min = self.y_min.min(lo);
+                self.y_max = self.y_max.max(hi);
+                self.any = true;
             }
         }
+        if let Some(b) = s.extent_baseline() { self.y_min = self.y_min.min(b); self.y_max = self.y_max.max(b); }
+    }
+
+    fn finish(self) -> Option<(f64, f64)> {
+        if self.any { Some((self.y_min, self.y_max)) } else { None }
     }
-    if any { Some((y_min, y_max)) } else { None }
 }
@@ -0,0 +1,38 @@
+// File: crates/chart-core/tests/async_render.rs
+// Purpose: Async PNG rendering (tokio feature) matches the synchronous path.
+#![cfg(feature = "tokio")]
+
+use chart_core::{Axis, Chart, RenderOptions, Series, SmaOverlay};
+use chart_core::series::SeriesType;
+use skia_safe::Color;
+
+#[test]
+fn async_render_matches_sync_bytes() {
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 20.0);
+    chart.y_axis = Axis::new("Y", -1.5, 1.5);
+    chart.add_series(Series::with_data(SeriesType::Line, (0..=20).map(|i| (i as f64, (i as f64 * 0.5).sin())).collect()));
+    chart.add_overlay(SmaOverlay { period: 3 });
+    let opts = RenderOptions { draw_labels: false, ..Default::default() };
+
+    let sync = chart.render_to_png_bytes(&opts).expect("sync render");
+    let rt = tokio::runtime::Builder::new_current_thread().build().expect("runtime");
+    let got = rt.block_on(async { chart.render_to_png_bytes_async(&opts).await }).expect("async render");
+    assert_eq!(got, sync);
+}
+
+#[test]
+fn async_render_keeps_fills() {
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 10.0);
+    chart.y_axis = Axis::new("Y", 0.0, 4.0);
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 3.0), (10.0, 3.5)]));
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 1.0), (10.0, 0.5)]));
+    chart.fill_between(0, 1, Color::from_argb(128, 40, 160, 220));
+    let opts = RenderOptions { draw_labels: false, ..Default::default() };
+
+    let sync = chart.render_to_png_bytes(&opts).expect("sync render");
+    let rt = tokio::runtime::Builder::new_current_thread().build().expect("runtime");
+    let got = rt.block_on(async { chart.render_to_png_bytes_async(&opts).await }).expect("async render");
+    assert_eq!(got, sync);
+}
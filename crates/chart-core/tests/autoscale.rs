@@ -1,7 +1,8 @@
 // File: crates/chart-core/tests/autoscale.rs
 // Purpose: Validate autoscale over mixed series types.
 
-use chart_core::{Chart, Series};
+use chart_core::{Axis, BollingerOverlay, Chart, Series, ViewState};
+use chart_core::view::visible_y_range_with_overlays;
 use chart_core::series::{Candle, SeriesType};
 
 #[test]
@@ -29,3 +30,128 @@ fn autoscale_mixed_series() {
     assert!(chart.y_axis.max >= 6.0 - 1e-9);
 }
 
+#[test]
+fn autoscale_padding_expands_span() {
+    let mut chart = Chart::new();
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 10.0), (4.0, 20.0)]));
+
+    // 10% on each side: y span 10 -> 12, x span 4 -> 4.8
+    chart.autoscale_axes_padded(0.1, 0.1);
+    assert!((chart.y_axis.min - 9.0).abs() < 1e-9);
+    assert!((chart.y_axis.max - 21.0).abs() < 1e-9);
+    assert!((chart.x_axis.min + 0.4).abs() < 1e-9);
+    assert!((chart.x_axis.max - 4.4).abs() < 1e-9);
+
+    let v = ViewState::from_chart_with(&chart, 0.0, 0.1);
+    assert!(((v.y_max - v.y_min) - 12.0).abs() < 1e-9);
+    assert!((v.x_min - 0.0).abs() < 1e-9 && (v.x_max - 4.0).abs() < 1e-9);
+
+    let mut v2 = ViewState { x_min: 0.0, x_max: 4.0, y_min: 0.0, y_max: 1.0 };
+    assert!(v2.autoscale_y_visible_padded(&chart, 0.1));
+    assert!((v2.y_min - 9.0).abs() < 1e-9 && (v2.y_max - 21.0).abs() < 1e-9);
+}
+
+#[test]
+fn autoscale_includes_error_whiskers() {
+    let mut chart = Chart::new();
+    chart.add_series(Series::with_errors(vec![(0.0, 5.0, 2.0), (1.0, 6.0, -0.5)]));
+    chart.autoscale_axes(0.0);
+    assert!((chart.y_axis.min - 3.0).abs() < 1e-9);
+    assert!((chart.y_axis.max - 7.0).abs() < 1e-9);
+}
+
+#[test]
+fn autoscale_includes_band_edges() {
+    let mut chart = Chart::new();
+    chart.add_series(Series::band_from(&[(0.0, 1.0), (1.0, 0.5)], &[(0.0, 2.0), (1.0, 4.0)]));
+    chart.autoscale_axes(0.0);
+    assert!((chart.y_axis.min - 0.5).abs() < 1e-9);
+    assert!((chart.y_axis.max - 4.0).abs() < 1e-9);
+}
+
+#[test]
+fn autoscale_zero_base_anchors_y_at_zero() {
+    let mut chart = Chart::new();
+    chart.add_series(Series::with_data(SeriesType::Histogram, vec![(0.0, 40.0), (1.0, 100.0), (2.0, 60.0)]));
+    chart.autoscale_with_zero_base(0.1);
+    assert_eq!(chart.y_axis.min, 0.0);
+    assert!((chart.y_axis.max - 110.0).abs() < 1e-9);
+    assert!((chart.x_axis.min - 0.0).abs() < 1e-9 && (chart.x_axis.max - 2.0).abs() < 1e-9);
+
+    // All-negative data pins the top instead
+    let mut neg = Chart::new();
+    neg.add_series(Series::with_data(SeriesType::Line, vec![(0.0, -5.0), (1.0, -15.0)]));
+    neg.autoscale_with_zero_base(0.1);
+    assert_eq!(neg.y_axis.max, 0.0);
+    assert!((neg.y_axis.min + 16.5).abs() < 1e-9);
+}
+
+#[test]
+fn nice_bounds_snap_to_step_multiples() {
+    let mut axis = Axis::new("Price", 18.37, 92.1);
+    axis.snap_to_nice_bounds();
+    // span 73.73 over ~6 ticks -> step 20
+    assert_eq!((axis.min, axis.max), (0.0, 100.0));
+
+    let mut chart = Chart::new();
+    chart.nice_bounds = true;
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 18.37), (9.5, 92.1)]));
+    chart.autoscale_axes(0.0);
+    assert_eq!((chart.y_axis.min, chart.y_axis.max), (0.0, 100.0));
+    assert_eq!((chart.x_axis.min, chart.x_axis.max), (0.0, 10.0));
+}
+
+#[test]
+fn from_chart_with_scales_spans_by_margin() {
+    let mut chart = Chart::new();
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 10.0), (4.0, 20.0)]));
+
+    let span = |v: ViewState| (v.x_max - v.x_min, v.y_max - v.y_min);
+    let (x0, y0) = span(ViewState::from_chart_with(&chart, 0.0, 0.0));
+    assert!((x0 - 4.0).abs() < 1e-9 && (y0 - 10.0).abs() < 1e-9);
+    let (x1, y1) = span(ViewState::from_chart_with(&chart, 0.25, 0.5));
+    assert!((x1 - 6.0).abs() < 1e-9 && (y1 - 20.0).abs() < 1e-9);
+    // Negative margins don't shrink the fit
+    assert_eq!(span(ViewState::from_chart_with(&chart, -1.0, -1.0)), (x0, y0));
+
+    // from_chart keeps its defaults: no x margin, 2% y margin
+    assert_eq!(ViewState::from_chart(&chart), ViewState::from_chart_with(&chart, 0.0, 0.02));
+    assert!((span(ViewState::from_chart(&chart)).1 - 10.4).abs() < 1e-9);
+}
+
+#[test]
+fn autoscale_with_overlays_includes_bollinger_band() {
+    let mut chart = Chart::new();
+    // Alternating 10/12 closes: 2σ bands reach 13 and 9, outside the price range
+    chart.add_series(Series::with_data(SeriesType::Line, (0..20).map(|i| (i as f64, if i % 2 == 0 { 10.0 } else { 12.0 })).collect()));
+    chart.add_overlay(BollingerOverlay { period: 4, k: 2.0 });
+
+    let mut price_only = ViewState { x_min: 0.0, x_max: 19.0, y_min: 0.0, y_max: 1.0 };
+    assert!(price_only.autoscale_y_visible(&chart));
+    assert!(price_only.y_max < 13.0);
+
+    let mut with_overlays = price_only;
+    assert!(with_overlays.autoscale_y_visible_with_overlays(&chart));
+    assert!(with_overlays.y_max >= 13.0 && with_overlays.y_min <= 9.0);
+    assert_eq!(visible_y_range_with_overlays(&chart, 0.0, 19.0), Some((9.0, 13.0)));
+    // Nothing visible in the window: no range, whatever the overlays compute
+    assert_eq!(visible_y_range_with_overlays(&chart, 50.0, 60.0), None);
+}
+
+#[test]
+fn excluded_series_does_not_drive_autoscale() {
+    let mut chart = Chart::new();
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 10.0), (4.0, 20.0)]));
+    chart.autoscale_axes(0.0);
+    let fit = (chart.x_axis.min, chart.x_axis.max, chart.y_axis.min, chart.y_axis.max);
+    let view = ViewState::from_chart(&chart);
+    let visible = chart_core::view::visible_y_range(&chart, 0.0, 4.0);
+
+    // Extreme values in both x and y, only drawn
+    chart.add_series(Series::with_data(SeriesType::Histogram, vec![(-100.0, 1e6), (2.0, -1e6), (100.0, 5e5)]).with_autoscale(false));
+    chart.autoscale_axes(0.0);
+    assert_eq!((chart.x_axis.min, chart.x_axis.max, chart.y_axis.min, chart.y_axis.max), fit);
+    assert_eq!(ViewState::from_chart(&chart), view);
+    assert_eq!(chart_core::view::visible_y_range(&chart, 0.0, 4.0), visible);
+    assert_eq!(visible_y_range_with_overlays(&chart, 0.0, 4.0), visible);
+}
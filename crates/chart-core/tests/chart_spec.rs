@@ -0,0 +1,74 @@
+// File: crates/chart-core/tests/chart_spec.rs
+// Purpose: JSON chart specs (serde feature) reload to a chart that renders identically.
+#![cfg(feature = "serde")]
+
+use chart_core::series::{Candle, SeriesType};
+use chart_core::{Axis, Chart, LabelFormat, MarkerShape, RenderOptions, Series, Theme};
+use skia_safe::Color;
+
+#[test]
+fn reloaded_chart_renders_identical_bytes() {
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("Day", 0.0, 6.0);
+    chart.y_axis = Axis::new("Price", 0.0, 12.0);
+    chart.y_axis.label_format = LabelFormat::Fixed(2);
+    chart.y_axis.line_color = Some(Color::from_argb(200, 10, 120, 240));
+    chart.add_series(Series::from_candles(vec![
+        Candle { t: 1.0, o: 5.0, h: 8.0, l: 4.0, c: 7.5 },
+        Candle { t: 2.0, o: 7.5, h: 9.0, l: 6.0, c: 6.25 },
+    ]));
+    chart.add_series(
+        Series::with_data(SeriesType::Line, vec![(0.0, 2.0), (3.0, 1.0 / 3.0), (6.0, 11.0)])
+            .with_markers(MarkerShape::Diamond)
+            .with_threshold_color(5.0, Color::GREEN, Color::RED),
+    );
+    chart.add_series(Series::with_columns(vec![(4.0, 3.0), (5.0, 4.5)]).with_color(Color::from_rgb(90, 60, 200)));
+    chart.fill_between(1, 2, Color::from_argb(80, 200, 0, 200));
+
+    let theme = Theme::light();
+    let json = chart.to_json(&theme).expect("serialize");
+    let (loaded, loaded_theme) = Chart::from_json(&json).expect("deserialize");
+    assert_eq!(loaded_theme.name, "light");
+    assert_eq!(loaded.series.len(), 3);
+    assert_eq!(loaded.series[1].threshold_color, chart.series[1].threshold_color);
+
+    let render = |c: &Chart, theme: Theme| {
+        let opts = RenderOptions { theme, draw_labels: false, ..Default::default() };
+        c.render_to_png_bytes(&opts).expect("render")
+    };
+    assert_eq!(render(&loaded, loaded_theme), render(&chart, theme));
+}
+
+#[test]
+fn from_json_fills_optional_fields_and_rejects_bad_colors() {
+    let json = r##"{
+        "theme": "no-such-theme",
+        "x_axis": {"label": "X", "min": 0, "max": 1, "kind": "Linear", "label_format": "Auto"},
+        "y_axis": {"label": "Y", "min": 0, "max": 1, "kind": "Log10", "label_format": {"Fixed": 1}},
+        "series": [{"series_type": "Line", "data_xy": [[0, 0.5], [1, 0.75]]}]
+    }"##;
+    let (chart, theme) = Chart::from_json(json).expect("minimal spec");
+    assert_eq!(theme.name, Theme::dark().name, "unknown theme falls back to dark");
+    assert_eq!(chart.series[0].data_xy, vec![(0.0, 0.5), (1.0, 0.75)]);
+    assert_eq!(chart.series[0].opacity, 1.0);
+    assert!(chart.fills.is_empty());
+
+    let bad = json.replace(r#""series_type": "Line","#, r##""series_type": "Line", "color": "blue","##);
+    assert!(Chart::from_json(&bad).is_err());
+}
+
+#[test]
+fn gap_points_round_trip_as_nan() {
+    let mut chart = Chart::new();
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 1.0), (1.0, f64::NAN), (2.0, f64::INFINITY), (3.0, 2.0)]));
+    chart.add_series(Series::from_candles(vec![Candle { t: 1.0, o: f64::NAN, h: 2.0, l: 0.5, c: 1.5 }]));
+
+    let json = chart.to_json(&Theme::dark()).expect("serialize");
+    let (loaded, _) = Chart::from_json(&json).expect("deserialize");
+    let ys: Vec<f64> = loaded.series[0].data_xy.iter().map(|p| p.1).collect();
+    assert_eq!((ys[0], ys[3]), (1.0, 2.0));
+    assert!(ys[1].is_nan() && ys[2].is_nan(), "{ys:?}");
+    let candle = loaded.series[1].data_ohlc[0];
+    assert!(candle.o.is_nan());
+    assert_eq!((candle.t, candle.h, candle.l, candle.c), (1.0, 2.0, 0.5, 1.5));
+}
@@ -0,0 +1,23 @@
+// File: crates/chart-core/tests/colorbar.rs
+// Purpose: Standalone color bar output (size and gradient orientation).
+
+use chart_core::types::Insets;
+use chart_core::{render_colorbar, RenderOptions};
+use skia_safe::Color;
+
+#[test]
+fn colorbar_matches_size_and_puts_gradient_end_on_top() {
+    let low = Color::from_argb(255, 0x20, 0x30, 0xC0);
+    let high = Color::from_argb(255, 0xF0, 0xD0, 0x10);
+    let opts = RenderOptions { width: 96, height: 300, insets: Insets::new(8, 8, 10, 10), ..Default::default() };
+    let bytes = render_colorbar(&opts, 0.0, 250.0, &[low, Color::from_argb(255, 0x30, 0xA0, 0x60), high]).expect("render colorbar");
+
+    let img = image::load_from_memory(&bytes).expect("decode").to_rgba8();
+    assert_eq!(img.dimensions(), (96, 300));
+    let x = 8 + 10; // middle of the 20px strip
+    let rgb = |y: u32| { let p = img.get_pixel(x, y); (p[0], p[1], p[2]) };
+    assert_eq!(rgb(10), (high.r(), high.g(), high.b()), "top row shows the gradient end");
+    assert_eq!(rgb(289), (low.r(), low.g(), low.b()), "bottom row shows the gradient start");
+
+    assert!(render_colorbar(&opts, 0.0, 1.0, &[]).is_err());
+}
@@ -0,0 +1,150 @@
+// File: crates/chart-core/tests/coords.rs
+// Purpose: Verify data<->pixel mapping matches render scales and round-trips, and the
+//          pixel regions invalidated by view changes.
+
+use chart_core::{Axis, Chart, RenderOptions, ViewState};
+use chart_core::axis::ScaleKind;
+
+fn approx(a: f64, b: f64, rel: f64) -> bool {
+    (a - b).abs() <= rel * a.abs().max(b.abs()).max(1.0)
+}
+
+#[test]
+fn linear_round_trip() {
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 100.0);
+    chart.y_axis = Axis::new("Y", -5.0, 5.0);
+    let opts = RenderOptions::default();
+
+    // Axis extremes land on plot edges
+    let (px0, py0) = chart.data_to_pixel(&opts, 0.0, -5.0);
+    assert!((px0 - opts.insets.left as f32).abs() < 1e-3);
+    assert!((py0 - (opts.height - opts.insets.bottom as i32) as f32).abs() < 1e-3);
+
+    for &(x, y) in &[(0.0, 0.0), (12.5, -3.25), (77.0, 4.9), (100.0, 5.0)] {
+        let (px, py) = chart.data_to_pixel(&opts, x, y);
+        let (rx, ry) = chart.pixel_to_data(&opts, px, py);
+        assert!(approx(rx, x, 1e-4), "x {} -> {}", x, rx);
+        assert!(approx(ry, y, 1e-4), "y {} -> {}", y, ry);
+    }
+}
+
+#[test]
+fn log_round_trip() {
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 1_700_000_000.0, 1_700_086_400.0);
+    chart.y_axis = Axis::new("Y", 1.0, 10_000.0);
+    chart.y_axis.kind = ScaleKind::Log10;
+    let opts = RenderOptions::default();
+
+    // 100 is the geometric midpoint of 1..10^4
+    let (_, py_mid) = chart.data_to_pixel(&opts, chart.x_axis.min, 100.0);
+    let mid = (opts.insets.top as f32 + (opts.height - opts.insets.bottom as i32) as f32) * 0.5;
+    assert!((py_mid - mid).abs() < 0.5);
+
+    for &y in &[1.0, 3.7, 100.0, 9_999.0] {
+        let x = 1_700_040_000.0;
+        let (px, py) = chart.data_to_pixel(&opts, x, y);
+        let (rx, ry) = chart.pixel_to_data(&opts, px, py);
+        assert!(approx(ry, y, 1e-4), "y {} -> {}", y, ry);
+        // f32 pixel precision bounds x error to a fraction of a pixel's worth of data
+        assert!((rx - x).abs() < 86_400.0 / 900.0, "x {} -> {}", x, rx);
+    }
+}
+
+#[test]
+fn hit_test_finds_nearest_point_and_candle() {
+    use chart_core::{Series, SeriesType};
+    use chart_core::series::Candle;
+
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 10.0);
+    chart.y_axis = Axis::new("Y", 0.0, 10.0);
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(1.0, 1.0), (2.0, 8.0), (3.0, 2.0)]));
+    chart.add_series(Series::from_candles(vec![
+        Candle { t: 6.0, o: 3.0, h: 5.0, l: 2.0, c: 4.0 },
+        Candle { t: 7.0, o: 4.0, h: 6.0, l: 3.0, c: 5.5 },
+    ]));
+    let opts = RenderOptions::default();
+
+    let (px, py) = chart.data_to_pixel(&opts, 2.0, 8.0);
+    let hit = chart.hit_test(&opts, px + 2.0, py - 1.0, 6.0).expect("hit near line point");
+    assert_eq!((hit.series_index, hit.point_index), (0, 1));
+    assert!(hit.distance_px < 3.0);
+
+    // Candle hit: nearest x within the high-low extent
+    let (cx, cy) = chart.data_to_pixel(&opts, 7.0, 4.5);
+    let hit = chart.hit_test(&opts, cx - 3.0, cy, 10.0).expect("hit on candle");
+    assert_eq!((hit.series_index, hit.point_index), (1, 1));
+    assert_eq!(hit.y, 5.5);
+
+    // Far above the candle's high: no hit
+    let (_, top) = chart.data_to_pixel(&opts, 7.0, 9.5);
+    assert!(chart.hit_test(&opts, cx, top, 10.0).is_none());
+}
+
+#[test]
+fn equal_axis_scale_matches_unit_pixels() {
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", -1.0, 1.0);
+    chart.y_axis = Axis::new("Y", -1.0, 1.0);
+    let opts = RenderOptions { equal_axis_scale: true, ..Default::default() };
+
+    let (x0, y0) = chart.data_to_pixel(&opts, 0.0, 0.0);
+    let (x1, _) = chart.data_to_pixel(&opts, 1.0, 0.0);
+    let (_, y1) = chart.data_to_pixel(&opts, 0.0, 1.0);
+    assert!(((x1 - x0) - (y0 - y1)).abs() < 1e-3, "dx {} vs dy {}", x1 - x0, y0 - y1);
+
+    // The wide plot widens X; Y keeps the requested range
+    let (xa, ya) = chart.display_axes(&opts);
+    assert!(xa.min < -1.0 && xa.max > 1.0);
+    assert_eq!((ya.min, ya.max), (-1.0, 1.0));
+}
+
+#[test]
+fn pure_pan_dirties_only_revealed_strip() {
+    let chart = Chart::new();
+    let opts = RenderOptions::default();
+    let plot = chart.plot_rect(&opts);
+    let old = ViewState { x_min: 0.0, x_max: 100.0, y_min: 0.0, y_max: 10.0 };
+    assert_eq!(chart.dirty_rect(&old, &old, &opts), None);
+
+    // 10% of the span to the right: a strip of ~10% plot width at the right edge
+    let panned = ViewState { x_min: 10.0, x_max: 110.0, ..old };
+    let r = chart.dirty_rect(&old, &panned, &opts).unwrap();
+    let expect_w = (plot.width() as f64 * 0.1).ceil() as i32 + 1;
+    assert_eq!((r.left, r.right, r.top), (plot.right - expect_w, plot.right, plot.top));
+    assert_eq!(r.bottom, opts.height);
+
+    // Back to the left: strip at the left edge
+    let r = chart.dirty_rect(&panned, &old, &opts).unwrap();
+    assert_eq!((r.left, r.width()), (plot.left, expect_w));
+
+    // Vertical pan toward larger y: strip at the top, reaching into the Y labels
+    let up = ViewState { y_min: 1.0, y_max: 11.0, ..old };
+    let r = chart.dirty_rect(&old, &up, &opts).unwrap();
+    assert_eq!((r.left, r.top, r.right), (0, plot.top, plot.right));
+    assert!(r.height() < plot.height() / 5);
+
+    // Zoom, diagonal pan and a jump past the plot width repaint the whole plot
+    let zoomed = ViewState { x_max: 50.0, ..old };
+    assert_eq!(chart.dirty_rect(&old, &zoomed, &opts), Some(plot));
+    let diagonal = ViewState { x_min: 10.0, x_max: 110.0, y_min: 1.0, y_max: 11.0 };
+    assert_eq!(chart.dirty_rect(&old, &diagonal, &opts), Some(plot));
+    let jump = ViewState { x_min: 500.0, x_max: 600.0, ..old };
+    assert_eq!(chart.dirty_rect(&old, &jump, &opts), Some(plot));
+}
+
+#[test]
+fn bulk_mapping_matches_per_point_scales() {
+    use chart_core::scale::{map_points, TimeScale, ValueScale};
+
+    let pts: Vec<(f64, f64)> = (0..1000).map(|i| (1.7e9 + i as f64 * 60.0, 1.0 + (i as f64 * 0.1).sin().abs() * 500.0)).collect();
+    let ts = TimeScale::new(60.0, 1.7e9, 0.0123);
+    let mut out = vec![(0.0, 0.0); 3];
+    for vs in [ValueScale::new_linear(20.0, 460.0, 1.0, 501.0), ValueScale::new_log10(20.0, 460.0, 1.0, 501.0)] {
+        map_points(&ts, &vs, &pts, &mut out);
+        let want: Vec<(f32, f32)> = pts.iter().map(|&(x, y)| (ts.to_px(x), vs.to_px(y))).collect();
+        assert_eq!(out, want);
+    }
+}
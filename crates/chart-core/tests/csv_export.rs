@@ -0,0 +1,32 @@
+// File: crates/chart-core/tests/csv_export.rs
+// Purpose: CSV export (csv feature) reloads to the same series data.
+#![cfg(feature = "csv")]
+
+use chart_core::series::{Candle, SeriesType};
+use chart_core::{Chart, Series};
+
+#[test]
+fn export_then_import_round_trips_points_and_candles() {
+    let mut chart = Chart::new();
+    chart.add_series(Series::from_candles(vec![
+        Candle { t: 0.0, o: 10.0, h: 12.5, l: 9.75, c: 11.0 },
+        Candle { t: 1.0, o: 11.0, h: 11.2, l: 8.1, c: 0.1 + 0.2 },
+    ]));
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 1.0 / 3.0), (1.0, f64::NAN), (2.5, -4e-12)]));
+
+    let path = std::env::temp_dir().join(format!("chart_core_export_{}.csv", std::process::id()));
+    chart.export_csv(&path).expect("export");
+    let text = std::fs::read_to_string(&path).expect("read back");
+    assert!(text.starts_with("series,x,y,o,h,l,c\n"));
+
+    let loaded = Chart::import_csv(&path).expect("import");
+    std::fs::remove_file(&path).ok();
+    assert_eq!(loaded.series.len(), 2);
+    assert_eq!(loaded.series[0].series_type, SeriesType::Candlestick);
+    assert_eq!(loaded.series[0].data_ohlc, chart.series[0].data_ohlc);
+    assert_eq!(loaded.series[1].series_type, SeriesType::Line);
+    let (a, b) = (&loaded.series[1].data_xy, &chart.series[1].data_xy);
+    assert_eq!(a.len(), b.len());
+    // NaN != NaN, so compare bit patterns
+    assert!(a.iter().zip(b).all(|(p, q)| p.0 == q.0 && p.1.to_bits() == q.1.to_bits()));
+}
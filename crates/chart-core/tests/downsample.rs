@@ -0,0 +1,80 @@
+// File: crates/chart-core/tests/downsample.rs
+// Purpose: Downsampling and aggregation helpers (LTTB indices, M4, time-window OHLC).
+
+use chart_core::{aggregate_ohlc_by_time, lttb, lttb_indices, m4};
+use chart_core::series::Candle;
+
+fn candle(t: f64, o: f64, h: f64, l: f64, c: f64) -> Candle {
+    Candle { t, o, h, l, c }
+}
+
+fn tohlc(c: &Candle) -> (f64, f64, f64, f64, f64) {
+    (c.t, c.o, c.h, c.l, c.c)
+}
+
+#[test]
+fn minute_candles_roll_up_across_hour_boundary() {
+    let hour = 3_600.0;
+    let t0 = 1_700_000_000.0 - 1_700_000_000.0 % hour; // aligned to an hour
+    let minutes = vec![
+        candle(t0 + 3_480.0, 10.0, 11.0, 9.5, 10.5),  // 00:58
+        candle(t0 + 3_540.0, 10.5, 12.0, 10.0, 11.0), // 00:59
+        candle(t0 + 3_600.0, 11.0, 11.5, 8.0, 9.0),   // 01:00 -> next hour
+        candle(t0 + 3_660.0, 9.0, 10.0, 8.5, 9.5),    // 01:01
+    ];
+    let hours = aggregate_ohlc_by_time(&minutes, hour);
+    assert_eq!(hours.len(), 2);
+    assert_eq!(tohlc(&hours[0]), (t0, 10.0, 12.0, 9.5, 11.0));
+    assert_eq!(tohlc(&hours[1]), (t0 + hour, 11.0, 11.5, 8.0, 9.5));
+}
+
+#[test]
+fn empty_intervals_are_skipped() {
+    let hour = 3_600.0;
+    let data = vec![
+        candle(0.0, 1.0, 2.0, 0.5, 1.5),
+        candle(3.0 * hour + 60.0, 5.0, 6.0, 4.0, 5.5),
+    ];
+    let out = aggregate_ohlc_by_time(&data, hour);
+    assert_eq!(out.iter().map(|c| c.t).collect::<Vec<_>>(), vec![0.0, 3.0 * hour]);
+    assert!(aggregate_ohlc_by_time(&[], hour).is_empty());
+}
+
+#[test]
+fn m4_keeps_single_point_spike() {
+    // Gentle sine with one spike that occupies a single sample
+    let mut pts: Vec<(f64, f64)> = (0..10_000).map(|i| (i as f64, (i as f64 * 0.01).sin())).collect();
+    pts[4_321].1 = 50.0;
+    pts[7_777].1 = -50.0;
+    let out = m4(&pts, 200);
+    assert!(out.len() <= 200 && out.len() >= 4);
+    assert!(out.contains(&(4_321.0, 50.0)));
+    assert!(out.contains(&(7_777.0, -50.0)));
+    // Endpoints and ordering preserved
+    assert_eq!(out.first(), pts.first());
+    assert_eq!(out.last(), pts.last());
+    assert!(out.windows(2).all(|w| w[0].0 < w[1].0));
+}
+
+#[test]
+fn m4_below_one_column_keeps_endpoints() {
+    let pts: Vec<(f64, f64)> = (0..10).map(|i| (i as f64, i as f64 * 2.0)).collect();
+    for target in 0..4 {
+        assert_eq!(m4(&pts, target), vec![(0.0, 0.0), (9.0, 18.0)], "target {target}");
+    }
+    assert_eq!(m4(&[(1.0, 5.0)], 0), vec![(1.0, 5.0)]);
+}
+
+#[test]
+fn lttb_indices_are_increasing_and_match_points() {
+    let pts: Vec<(f64, f64)> = (0..1_000).map(|i| (i as f64, ((i * 37) % 101) as f64)).collect();
+    let idx = lttb_indices(&pts, 50);
+    assert_eq!(idx.len(), 50);
+    assert_eq!(idx.first(), Some(&0));
+    assert_eq!(idx.last(), Some(&999));
+    assert!(idx.windows(2).all(|w| w[0] < w[1]));
+
+    // lttb is the same selection mapped back to points
+    let picked: Vec<(f64, f64)> = idx.iter().map(|&i| pts[i]).collect();
+    assert_eq!(lttb(&pts, 50), picked);
+}
@@ -0,0 +1,116 @@
+// File: crates/chart-core/tests/drawing.rs
+// Purpose: Event-driven drawing overlays (trendlines, ruler) checked through their computed output.
+
+use chart_core::{Axis, Chart, Overlay, OverlayEvent, OverlayLabel, RenderOptions, RulerMeasure, RulerOverlay, Series, SeriesType, Trendline, TrendlineOverlay};
+
+fn chart() -> Chart {
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 10.0);
+    chart
+}
+
+#[test]
+fn trendline_follows_down_move_up() {
+    let chart = chart();
+    let tool = TrendlineOverlay::new();
+    assert!(tool.handle_event(&OverlayEvent::PointerDown { x: 2.0, y: 1.0 }, &chart).is_consumed());
+    tool.handle_event(&OverlayEvent::PointerMove { x: 3.0, y: 2.0 }, &chart);
+    // Preview drawn while dragging, but nothing stored yet
+    assert_eq!(tool.compute(&chart)[0].data_xy, vec![(2.0, 1.0), (3.0, 2.0)]);
+    assert!(tool.lines().is_empty());
+
+    tool.handle_event(&OverlayEvent::PointerUp { x: 4.0, y: 5.0 }, &chart);
+    assert_eq!(tool.lines(), vec![Trendline { a: (2.0, 1.0), b: (4.0, 5.0) }]);
+
+    // Second line drawn right to left; a bare move afterwards is not consumed
+    tool.handle_event(&OverlayEvent::PointerDown { x: 8.0, y: 0.0 }, &chart);
+    tool.handle_event(&OverlayEvent::PointerUp { x: 6.0, y: 1.0 }, &chart);
+    assert!(!tool.handle_event(&OverlayEvent::PointerMove { x: 1.0, y: 1.0 }, &chart).is_consumed());
+    let out = tool.compute(&chart);
+    assert_eq!(out.len(), 2);
+    assert_eq!(out[0].data_xy, vec![(2.0, 1.0), (4.0, 5.0)]);
+    assert_eq!(out[1].data_xy, vec![(6.0, 1.0), (8.0, 0.0)]);
+}
+
+#[test]
+fn trendline_click_without_drag_is_discarded() {
+    let chart = chart();
+    let tool = TrendlineOverlay::new();
+    tool.handle_event(&OverlayEvent::PointerDown { x: 2.0, y: 1.0 }, &chart);
+    tool.handle_event(&OverlayEvent::PointerUp { x: 2.0, y: 1.0 }, &chart);
+    assert!(tool.lines().is_empty());
+    assert!(tool.compute(&chart).is_empty());
+}
+
+#[test]
+fn extended_trendline_spans_x_axis() {
+    let chart = chart();
+    let tool = TrendlineOverlay::new().with_extend(true);
+    tool.handle_event(&OverlayEvent::PointerDown { x: 2.0, y: 1.0 }, &chart);
+    tool.handle_event(&OverlayEvent::PointerUp { x: 4.0, y: 2.0 }, &chart);
+    assert_eq!(tool.compute(&chart)[0].data_xy, vec![(0.0, 0.0), (10.0, 5.0)]);
+}
+
+fn unit_bars(n: usize) -> Chart {
+    let mut chart = chart();
+    chart.add_series(Series::with_data(SeriesType::Line, (0..n).map(|i| (i as f64, 50.0)).collect()));
+    chart
+}
+
+#[test]
+fn ruler_measures_price_and_bar_delta() {
+    let chart = unit_bars(10);
+    let m = RulerMeasure::new((2.0, 50.0), (7.0, 51.5), &chart);
+    assert_eq!((m.dx, m.dy, m.bars), (5.0, 1.5, 5));
+    assert!((m.percent.unwrap() - 3.0).abs() < 1e-12);
+    assert_eq!(m.label(), "+1.50 (+3.00%), 5 bars, dx 5");
+
+    // Measured right to left the deltas flip sign but the bar count does not
+    let back = RulerMeasure::new((7.0, 51.5), (2.0, 50.0), &chart);
+    assert_eq!((back.dx, back.bars), (-5.0, 5));
+    assert_eq!(RulerMeasure::new((1.0, 0.0), (2.0, 1.0), &chart).percent, None);
+}
+
+#[test]
+fn ruler_drag_shades_box_and_labels_it() {
+    let chart = unit_bars(10);
+    let ruler = RulerOverlay::new();
+    assert!(!ruler.handle_event(&OverlayEvent::PointerMove { x: 1.0, y: 1.0 }, &chart).is_consumed());
+    ruler.handle_event(&OverlayEvent::PointerDown { x: 6.0, y: 40.0 }, &chart);
+    ruler.handle_event(&OverlayEvent::PointerUp { x: 4.0, y: 44.0 }, &chart);
+
+    let out = ruler.compute(&chart);
+    assert_eq!(out[0].data_band, vec![(4.0, 40.0, 44.0), (6.0, 40.0, 44.0)]);
+    assert_eq!(ruler.labels(&chart), vec![OverlayLabel { x: 5.0, y: 44.0, text: "+4.00 (+10.00%), 2 bars, dx -2".into() }]);
+
+    // The finished measurement ignores moves; rendering includes the badge
+    ruler.handle_event(&OverlayEvent::PointerMove { x: 9.0, y: 1.0 }, &chart);
+    assert_eq!(ruler.measure(&chart).unwrap().bars, 2);
+    let mut chart = chart;
+    chart.add_overlay(ruler);
+    let svg = chart.render_to_svg_string(&RenderOptions::default()).unwrap();
+    assert!(svg.contains("class=\"overlay-label\""));
+    assert!(svg.contains("+4.00 (+10.00%), 2 bars, dx -2"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn trendlines_round_trip_through_json() {
+    let chart = chart();
+    let tool = TrendlineOverlay::new();
+    for (a, b) in [((1.0, 2.0), (3.0, 4.5)), ((6.0, 0.25), (9.0, -1.0))] {
+        tool.handle_event(&OverlayEvent::PointerDown { x: a.0, y: a.1 }, &chart);
+        tool.handle_event(&OverlayEvent::PointerUp { x: b.0, y: b.1 }, &chart);
+    }
+    // An unfinished drag is not part of the saved state
+    tool.handle_event(&OverlayEvent::PointerDown { x: 5.0, y: 5.0 }, &chart);
+
+    let saved = tool.serialize_state().unwrap().to_string();
+    assert_eq!(saved, r#"[{"a":[1.0,2.0],"b":[3.0,4.5]},{"a":[6.0,0.25],"b":[9.0,-1.0]}]"#);
+
+    let mut restored = TrendlineOverlay::new();
+    restored.restore_state(&serde_json::from_str(&saved).unwrap()).unwrap();
+    assert_eq!(restored.lines(), tool.lines());
+    assert_eq!(restored.compute(&chart).len(), 2);
+    assert!(restored.restore_state(&serde_json::json!({"a": 1})).is_err());
+}
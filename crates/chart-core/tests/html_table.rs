@@ -0,0 +1,26 @@
+// File: crates/chart-core/tests/html_table.rs
+// Purpose: HTML data-table export (accessible fallback for rendered images).
+
+use chart_core::series::{Candle, SeriesType};
+use chart_core::{Axis, Chart, Series};
+
+#[test]
+fn html_table_has_one_row_per_point() {
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("Time", 0.0, 3.0);
+    chart.y_axis = Axis::new("P&L <USD>", 0.0, 20.0);
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 1.0), (1.0, 2.0), (2.0, 3.0)]));
+    chart.add_series(Series::from_candles(vec![
+        Candle { t: 0.0, o: 10.0, h: 12.0, l: 9.0, c: 11.0 },
+        Candle { t: 1.0, o: 11.0, h: 13.0, l: 10.0, c: 12.5 },
+    ]));
+
+    let html = chart.export_html_table();
+    assert!(html.starts_with("<table") && html.trim_end().ends_with("</table>"));
+    let body = &html[html.find("<tbody>").expect("tbody")..];
+    assert_eq!(body.matches("<tr>").count(), 5);
+    // Candles contribute their close, as in `iter_xy`
+    assert!(body.contains(&format!("<td>1</td><td>{}</td><td>{}</td>", chart.x_axis.format_value(1.0), chart.y_axis.format_value(12.5))));
+    // Labels are escaped
+    assert!(html.contains("<th scope=\"col\">P&amp;L &lt;USD&gt;</th>"), "{html}");
+}
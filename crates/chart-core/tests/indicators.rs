@@ -0,0 +1,160 @@
+// File: crates/chart-core/tests/indicators.rs
+// Purpose: Numeric checks for indicator helpers and indicator-backed overlays.
+
+use chart_core::{Chart, DonchianOverlay, EventResult, HvLineOverlay, Overlay, OverlayEvent, OverlayParams, PaneTarget, RsiOverlay, Series, SeriesType, SmaOverlay};
+use chart_core::series::Candle;
+
+fn candles_from_hl(hl: &[(f64, f64)]) -> Vec<Candle> {
+    hl.iter()
+        .enumerate()
+        .map(|(i, &(h, l))| Candle { t: i as f64, o: (h + l) * 0.5, h, l, c: (h + l) * 0.5 })
+        .collect()
+}
+
+#[test]
+fn donchian_upper_is_rolling_max_high() {
+    let hl = [(5.0, 4.0), (7.0, 5.0), (6.0, 3.0), (4.0, 2.0), (8.0, 6.0), (5.0, 4.5)];
+    let candles = candles_from_hl(&hl);
+    let mut chart = Chart::new();
+    chart.add_series(Series::from_candles(candles));
+
+    let out = DonchianOverlay { period: 3 }.compute(&chart);
+    assert_eq!(out.len(), 2);
+    assert!(matches!(out[0].series_type, SeriesType::Band));
+    assert!(matches!(out[1].series_type, SeriesType::Line));
+
+    let band = &out[0].data_band;
+    // warm-up: first output at index period-1
+    assert_eq!(band.len(), hl.len() - 2);
+    assert_eq!(band[0].0, 2.0);
+    for (k, &(_, lo, hi)) in band.iter().enumerate() {
+        let w = &hl[k..k + 3];
+        let want_hi = w.iter().map(|p| p.0).fold(f64::NEG_INFINITY, f64::max);
+        let want_lo = w.iter().map(|p| p.1).fold(f64::INFINITY, f64::min);
+        assert_eq!(hi, want_hi);
+        assert_eq!(lo, want_lo);
+        assert_eq!(out[1].data_xy[k].1, (lo + hi) * 0.5);
+    }
+}
+
+#[test]
+fn donchian_without_candles_is_empty() {
+    let mut chart = Chart::new();
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 1.0), (1.0, 2.0)]));
+    assert!(DonchianOverlay { period: 2 }.compute(&chart).is_empty());
+}
+
+#[test]
+fn true_range_gap_up_uses_prev_close() {
+    use chart_core::indicators::{atr, true_range};
+    let candles = vec![
+        Candle { t: 0.0, o: 10.0, h: 11.0, l: 9.0, c: 10.0 },
+        // gap up: range 1, but high is 6 above the prior close
+        Candle { t: 1.0, o: 15.0, h: 16.0, l: 15.0, c: 15.5 },
+        // gap down: low is 5.5 below the prior close
+        Candle { t: 2.0, o: 10.5, h: 11.0, l: 10.0, c: 10.5 },
+    ];
+    let tr = true_range(&candles);
+    assert_eq!(tr, vec![2.0, 6.0, 5.5]);
+
+    // Wilder ATR(2): seed = (2 + 6) / 2 = 4, then (4 * 1 + 5.5) / 2 = 4.75
+    assert_eq!(atr(&candles, 2), vec![(1.0, 4.0), (2.0, 4.75)]);
+    assert!(atr(&candles, 4).is_empty());
+    assert!(atr(&candles, 0).is_empty());
+}
+
+#[test]
+fn wma_matches_hand_weighted_average() {
+    use chart_core::plugin::wma_xy;
+    let data = [(0.0, 2.0), (1.0, 4.0), (2.0, 6.0), (3.0, 3.0)];
+    let out = wma_xy(&data, 3);
+    // (1*2 + 2*4 + 3*6) / 6 and (1*4 + 2*6 + 3*3) / 6
+    assert_eq!(out.len(), 2);
+    assert_eq!(out[0], (2.0, 28.0 / 6.0));
+    assert_eq!(out[1], (3.0, 25.0 / 6.0));
+    assert!(wma_xy(&data, 5).is_empty());
+}
+
+#[test]
+fn hma_length_and_linear_input() {
+    use chart_core::plugin::hma_xy;
+    use chart_core::{HmaIndicator, Indicator, IndicatorParams};
+    let data: Vec<(f64, f64)> = (0..30).map(|i| (i as f64, 3.0 * i as f64 + 1.0)).collect();
+    let n = 9; // half 4, sqrt 3
+    let out = hma_xy(&data, n);
+    assert_eq!(out.len(), data.len() - n - 3 + 2);
+    assert_eq!(out[0].0, (n + 3 - 2) as f64);
+    // HMA tracks a straight line with zero lag
+    for &(x, y) in &out {
+        assert!((y - (3.0 * x + 1.0)).abs() < 1e-9, "x {} y {}", x, y);
+    }
+
+    let s = HmaIndicator.compute(&Series::with_data(SeriesType::Line, data), &IndicatorParams { period: n });
+    assert_eq!(s.data_xy, out);
+}
+
+#[test]
+fn overlay_period_changes_between_computes() {
+    let mut chart = Chart::new();
+    chart.add_series(Series::with_data(SeriesType::Line, (0..10).map(|i| (i as f64, i as f64)).collect()));
+    chart.add_overlay(SmaOverlay { period: 3 });
+    assert_eq!(chart.overlays[0].compute(&chart)[0].data_xy.len(), 8);
+
+    assert!(chart.set_overlay_params("sma_overlay", OverlayParams { period: 5 }));
+    assert_eq!(chart.overlays[0].params(), Some(OverlayParams { period: 5 }));
+    assert_eq!(chart.overlays[0].compute(&chart)[0].data_xy.len(), 6);
+
+    assert!(!chart.set_overlay_params("missing", OverlayParams { period: 2 }));
+}
+
+#[test]
+fn remove_overlay_by_id_keeps_the_others() {
+    let mut chart = Chart::new();
+    chart.add_overlay(SmaOverlay { period: 3 });
+    chart.add_overlay(DonchianOverlay { period: 5 });
+    assert_eq!(chart.overlay_ids(), vec!["sma_overlay", "donchian_overlay"]);
+
+    assert!(chart.remove_overlay("sma_overlay"));
+    assert_eq!(chart.overlay_ids(), vec!["donchian_overlay"]);
+    assert!(!chart.remove_overlay("sma_overlay"));
+    assert_eq!(chart.overlays.len(), 1);
+}
+
+#[test]
+fn rsi_overlay_targets_separate_pane() {
+    let rsi = RsiOverlay { period: 3 };
+    assert_eq!(rsi.target_pane(), PaneTarget::Separate { y_min: 0.0, y_max: 100.0 });
+    assert_eq!(SmaOverlay { period: 3 }.target_pane(), PaneTarget::Main);
+
+    let mut chart = Chart::new();
+    // Rising only, then flat: RSI pins at 100 (no losses to average)
+    chart.add_series(Series::with_data(SeriesType::Line, (0..8).map(|i| (i as f64, i.min(5) as f64)).collect()));
+    let out = rsi.compute(&chart);
+    assert_eq!(out[0].data_xy.len(), 5);
+    assert!(out[0].data_xy.iter().all(|&(_, v)| v == 100.0));
+}
+
+#[test]
+fn hv_line_overlay_is_shareable_across_threads() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<HvLineOverlay>();
+
+    let ov = std::sync::Arc::new(HvLineOverlay::new());
+    let chart = Chart::new();
+    assert!(ov.compute(&chart).is_empty());
+    let shared = ov.clone();
+    std::thread::spawn(move || shared.handle_event(&OverlayEvent::PointerDown { x: 0.0, y: 3.5 }, &Chart::new())).join().unwrap();
+    let out = ov.compute(&chart);
+    assert_eq!(out[0].data_xy, vec![(chart.x_axis.min, 3.5), (chart.x_axis.max, 3.5)]);
+}
+
+#[test]
+fn drawing_overlay_consumes_pointer_down() {
+    let mut chart = Chart::new();
+    assert_eq!(chart.handle_overlay_event(&OverlayEvent::PointerDown { x: 0.0, y: 1.0 }), EventResult::Ignored);
+
+    chart.add_overlay(SmaOverlay { period: 3 });
+    chart.add_overlay(HvLineOverlay::new());
+    assert_eq!(chart.handle_overlay_event(&OverlayEvent::PointerMove { x: 0.0, y: 1.0 }), EventResult::Ignored);
+    assert!(chart.handle_overlay_event(&OverlayEvent::PointerDown { x: 0.0, y: 1.0 }).is_consumed());
+}
@@ -0,0 +1,79 @@
+// File: crates/chart-core/tests/labels.rs
+// Purpose: Axis label formatting (LabelFormat variants and precision overrides).
+
+use chart_core::{Axis, LabelFormat};
+
+fn axis(min: f64, max: f64, format: LabelFormat) -> Axis {
+    let mut a = Axis::new("Y", min, max);
+    a.label_format = format;
+    a
+}
+
+#[test]
+fn auto_follows_span_unless_precision_is_set() {
+    let mut a = axis(0.0, 5.0, LabelFormat::Auto);
+    assert_eq!(a.format_value(1.5), "1.500");
+    let wide = axis(0.0, 50.0, LabelFormat::Auto);
+    assert_eq!(wide.format_value(1.5), "1.50");
+
+    // Precision override keeps prices at 2 decimals regardless of zoom
+    a.label_precision = Some(2);
+    assert_eq!(a.format_value(1.5), "1.50");
+    a.max = 5_000.0;
+    assert_eq!(a.format_value(1234.5), "1234.50");
+}
+
+#[test]
+fn fixed_uses_its_own_decimals() {
+    let mut a = axis(0.0, 1_000_000.0, LabelFormat::Fixed(2));
+    assert_eq!(a.format_value(101.256), "101.26");
+    a.label_precision = Some(5); // ignored for Fixed
+    assert_eq!(a.format_value(-3.0), "-3.00");
+}
+
+#[test]
+fn scientific_notation() {
+    let mut a = axis(0.0, 1.0, LabelFormat::Scientific);
+    assert_eq!(a.format_value(12_345.0), "1.23e4");
+    a.label_precision = Some(1);
+    assert_eq!(a.format_value(0.00042), "4.2e-4");
+}
+
+#[test]
+fn si_prefix_suffixes() {
+    let mut a = axis(0.0, 10.0, LabelFormat::SiPrefix);
+    assert_eq!(a.format_value(1_250.0), "1.2K");
+    assert_eq!(a.format_value(3_400_000.0), "3.4M");
+    assert_eq!(a.format_value(2_500_000_000.0), "2.50B");
+    assert_eq!(a.format_value(12.0), "12.00");
+    a.label_precision = Some(0);
+    assert_eq!(a.format_value(3_400_000.0), "3M");
+}
+
+#[test]
+fn percent_appends_sign() {
+    let mut a = axis(-20.0, 120.0, LabelFormat::Percent);
+    assert_eq!(a.format_value(100.0), "100.00%");
+    a.label_precision = Some(0);
+    assert_eq!(a.format_value(-12.4), "-12%");
+}
+
+#[test]
+fn grouped_thousands_helper() {
+    use chart_core::axis::format_grouped;
+    assert_eq!(format_grouped(1_234_567.0, 0), "1,234,567");
+    assert_eq!(format_grouped(999.0, 0), "999");
+    assert_eq!(format_grouped(1_000.0, 0), "1,000");
+    assert_eq!(format_grouped(-1_234_567.891, 2), "-1,234,567.89");
+    assert_eq!(format_grouped(-12.5, 1), "-12.5");
+    assert_eq!(format_grouped(0.125, 3), "0.125");
+    assert_eq!(format_grouped(123_456.0, 2), "123,456.00");
+}
+
+#[test]
+fn grouped_format_respects_precision() {
+    let mut a = axis(0.0, 5_000_000.0, LabelFormat::Grouped);
+    assert_eq!(a.format_value(1_234_567.0), "1,234,567");
+    a.label_precision = Some(2);
+    assert_eq!(a.format_value(1_234_567.0), "1,234,567.00");
+}
@@ -0,0 +1,94 @@
+// File: crates/chart-core/tests/layout.rs
+// Purpose: Plot layout helpers (insets, label-driven sizing).
+
+use chart_core::{Axis, Chart, RenderOptions};
+use chart_core::types::Insets;
+
+fn chart_with_y(min: f64, max: f64) -> Chart {
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 10.0);
+    chart.y_axis = Axis::new("Y", min, max);
+    chart
+}
+
+#[test]
+fn with_insets_replaces_defaults() {
+    let opts = RenderOptions::default().with_insets(Insets::new(40, 10, 12, 30));
+    assert_eq!(opts.insets, Insets::new(40, 10, 12, 30));
+}
+
+#[test]
+fn builder_defaults_match_default() {
+    let built = RenderOptions::builder().build();
+    assert_eq!(format!("{built:?}"), format!("{:?}", RenderOptions::default()));
+
+    let opts = RenderOptions::builder()
+        .width(640)
+        .height(360)
+        .insets(Insets { left: 10, right: 20, top: 30, bottom: 40 })
+        .crosshair(Some((5.0, 6.0)))
+        .build();
+    let expected = RenderOptions {
+        width: 640,
+        height: 360,
+        insets: Insets { left: 10, right: 20, top: 30, bottom: 40 },
+        crosshair: Some((5.0, 6.0)),
+        ..Default::default()
+    };
+    assert_eq!(format!("{opts:?}"), format!("{expected:?}"));
+}
+
+#[test]
+fn six_digit_labels_grow_left_inset() {
+    let small = chart_with_y(0.0, 5.0);
+    let large = chart_with_y(100_000.0, 900_000.0);
+    assert!(large.required_left_inset(&RenderOptions::default()) > small.required_left_inset(&RenderOptions::default()));
+
+    // Grows a tight inset for 6-digit prices, never shrinks a roomy one
+    let tight = Insets::new(24, 24, 24, 56);
+    let grown = RenderOptions::default().with_insets(tight).fit_left_inset(&large);
+    assert!(grown.insets.left > tight.left, "left inset {}", grown.insets.left);
+    let kept = RenderOptions::default().with_insets(Insets::new(200, 24, 24, 56)).fit_left_inset(&large);
+    assert_eq!(kept.insets.left, 200);
+}
+
+#[test]
+fn auto_left_inset_tracks_value_range() {
+    let mut opts = RenderOptions::default();
+    opts.auto_left_inset = true;
+    let small = chart_with_y(0.0, 5.0);
+    let large = chart_with_y(100_000.0, 900_000.0);
+    let small_in = small.effective_insets(&opts);
+    let large_in = large.effective_insets(&opts);
+    assert!(large_in.left > small_in.left);
+    // only the left side is adjusted
+    assert_eq!((large_in.right, large_in.top, large_in.bottom), (opts.insets.right, opts.insets.top, opts.insets.bottom));
+
+    // Raster mapping and SVG layout share the effective plot rect
+    let (px, _) = large.data_to_pixel(&opts, 0.0, 100_000.0);
+    assert_eq!(px, large_in.left as f32);
+    assert_eq!(large.plot_rect(&opts).left, large_in.left as i32);
+
+    opts.auto_left_inset = false;
+    assert_eq!(large.effective_insets(&opts), opts.insets);
+}
+
+#[test]
+fn insets_scale_with_dpr() {
+    let chart = chart_with_y(100_000.0, 900_000.0);
+    let one = RenderOptions::default();
+    let two = RenderOptions { dpr: 2.0, width: one.width * 2, height: one.height * 2, ..Default::default() };
+
+    // Insets stay logical; the plot rect doubles its margins at dpr 2
+    let (r1, r2) = (chart.plot_rect(&one), chart.plot_rect(&two));
+    assert_eq!(r2.left, 2 * r1.left);
+    assert_eq!(two.width - r2.right, 2 * (one.width - r1.right));
+    assert_eq!((r2.top, r2.bottom), (2 * r1.top, 2 * r1.bottom));
+
+    // Label-sized gutters are logical too, so they track the doubled text
+    let auto = |dpr: f32| RenderOptions { auto_left_inset: true, dpr, ..Default::default() };
+    let (l1, l2) = (chart.required_left_inset(&auto(1.0)), chart.required_left_inset(&auto(2.0)));
+    assert!(l1.abs_diff(l2) <= 1, "logical gutters {} vs {}", l1, l2);
+    let (d1, d2) = (chart.plot_rect(&auto(1.0)).left, chart.plot_rect(&auto(2.0)).left);
+    assert!((d2 - 2 * d1).abs() <= 2, "device gutters {} vs {}", d1, d2);
+}
@@ -0,0 +1,71 @@
+// File: crates/chart-core/tests/live.rs
+// Purpose: Streaming candles from a DataSource into a chart and scrolling the view with them.
+
+use chart_core::series::Candle;
+use chart_core::{Chart, DataSource, FollowLatest, LiveFeed, ReplaySource, Series, ViewState};
+
+fn candle(t: f64, c: f64) -> Candle {
+    Candle { t, o: c, h: c + 1.0, l: c - 1.0, c }
+}
+
+#[test]
+fn push_candle_updates_forming_bar_and_rejects_older() {
+    let mut s = Series::from_candles(vec![candle(0.0, 10.0), candle(1.0, 11.0)]);
+    assert!(s.push_candle(candle(1.0, 12.0)));
+    assert!(s.push_candle(candle(2.0, 13.0)));
+    assert!(!s.push_candle(candle(0.5, 99.0)));
+    assert_eq!(s.data_ohlc.iter().map(|c| (c.t, c.c)).collect::<Vec<_>>(), vec![(0.0, 10.0), (1.0, 12.0), (2.0, 13.0)]);
+}
+
+#[test]
+fn polled_candles_appear_in_chart() {
+    let mut chart = Chart::new();
+    chart.add_series(Series::from_candles(Vec::new()));
+    let mut view = ViewState { x_min: 0.0, x_max: 1.0, y_min: 0.0, y_max: 1.0 };
+    let mut feed = LiveFeed::new(ReplaySource::new((0..10).map(|i| candle(i as f64, 10.0 + i as f64)).collect(), 4), 0);
+
+    // First batch fits the view to the data
+    assert_eq!(feed.update(&mut chart, &mut view), 4);
+    assert_eq!(chart.series[0].data_ohlc.len(), 4);
+    assert_eq!((view.x_min, view.x_max), (0.0, 3.0));
+
+    // At the right edge: the view scrolls with the new bars, same span
+    assert_eq!(feed.update(&mut chart, &mut view), 4);
+    assert_eq!((view.x_min, view.x_max), (4.0, 7.0));
+
+    // Panned back into history: new bars arrive but the view stays
+    view.x_min = 1.0;
+    view.x_max = 4.0;
+    assert_eq!(feed.update(&mut chart, &mut view), 2);
+    assert_eq!((view.x_min, view.x_max), (1.0, 4.0));
+    assert_eq!(chart.series[0].data_ohlc.last().unwrap().c, 19.0);
+    assert!(feed.source.is_exhausted());
+    assert!(feed.source.poll().is_empty());
+}
+
+#[test]
+fn follow_latest_advances_until_user_pans() {
+    let mut chart = Chart::new();
+    chart.add_series(Series::from_candles((0..20).map(|i| candle(i as f64, 10.0)).collect()));
+    let mut view = ViewState::from_chart(&chart);
+    let mut follow = FollowLatest::new(5.0);
+
+    assert!(follow.update(&mut view, &chart));
+    assert_eq!((view.x_min, view.x_max), (14.0, 19.0));
+    chart.series[0].push_candle(candle(20.0, 11.0));
+    chart.series[0].push_candle(candle(21.0, 12.0));
+    assert!(follow.update(&mut view, &chart));
+    assert_eq!((view.x_min, view.x_max), (16.0, 21.0));
+
+    // Panning away stops following; new data no longer moves the view
+    view.x_min -= 3.0;
+    view.x_max -= 3.0;
+    chart.series[0].push_candle(candle(22.0, 12.0));
+    assert!(!follow.update(&mut view, &chart));
+    assert!(!follow.following);
+    assert_eq!(view.x_max, 18.0);
+
+    follow.resume();
+    assert!(follow.update(&mut view, &chart));
+    assert_eq!((view.x_min, view.x_max), (17.0, 22.0));
+}
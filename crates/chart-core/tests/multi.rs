@@ -0,0 +1,112 @@
+// File: crates/chart-core/tests/multi.rs
+// Purpose: Stacked panel layout, synchronized crosshair behavior and grid sheets.
+
+use chart_core::{Axis, Chart, MultiChart, RenderOptions, Series, SeriesType};
+
+fn two_panels() -> MultiChart {
+    let mut price = Chart::new();
+    price.x_axis = Axis::new("X", 0.0, 10.0);
+    price.y_axis = Axis::new("Price", 0.0, 100.0);
+    price.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 10.0), (5.0, 90.0), (10.0, 40.0)]));
+
+    let mut rsi = Chart::new();
+    rsi.x_axis = Axis::new("X", 0.0, 10.0);
+    rsi.y_axis = Axis::new("RSI", 0.0, 100.0);
+    rsi.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 30.0), (10.0, 70.0)]));
+
+    let mut multi = MultiChart::new();
+    multi.add_panel(price, 0.75);
+    multi.add_panel(rsi, 0.25);
+    multi
+}
+
+#[test]
+fn crosshair_x_is_shared_across_panels() {
+    let multi = two_panels();
+    let mut opts = RenderOptions::default();
+    opts.draw_labels = false;
+    let rects = multi.panel_rects(&opts);
+    let cy = (rects[0].top + 20) as f32;
+    opts.crosshair = Some((412.3, cy));
+    opts.show_tooltip = true;
+
+    let layout = multi.crosshair_layout(&opts);
+    assert_eq!(layout.len(), 2);
+    assert_eq!(layout[0].x_px, layout[1].x_px);
+    assert_eq!(layout[0].x_px, 412.3);
+    // Horizontal line only in the panel under the cursor
+    assert_eq!(layout[0].y_px, Some(cy));
+    assert_eq!(layout[1].y_px, None);
+
+    // Cursor left of the plot clamps identically for every panel
+    opts.crosshair = Some((3.0, (rects[1].top + 5) as f32));
+    let layout = multi.crosshair_layout(&opts);
+    assert!(layout.iter().all(|c| c.x_px == opts.insets.left as f32));
+    assert!(layout[1].y_px.is_some());
+
+    // Rendering with crosshair + tooltips succeeds
+    let (w, h) = (opts.width, opts.height);
+    let bytes = multi.render_to_png_bytes(&opts).expect("render");
+    let img = image::load_from_memory(&bytes).expect("decode").to_rgba8();
+    assert_eq!((img.width() as i32, img.height() as i32), (w, h));
+}
+
+#[test]
+fn no_crosshair_without_cursor() {
+    let multi = two_panels();
+    let opts = RenderOptions::default();
+    assert!(multi.crosshair_layout(&opts).is_empty());
+}
+
+#[test]
+fn grid_sheet_lays_out_cells_row_major() {
+    use chart_core::render_grid_to_png_bytes;
+
+    let charts: Vec<Chart> = (0..4)
+        .map(|i| {
+            let mut c = Chart::new();
+            c.x_axis = Axis::new("X", 0.0, 4.0);
+            c.y_axis = Axis::new("Y", 0.0, 4.0);
+            c.add_series(Series::with_data(SeriesType::Line, vec![(0.0, i as f64), (4.0, 4.0 - i as f64)]));
+            c
+        })
+        .collect();
+    let opts = RenderOptions { draw_labels: false, ..Default::default() };
+    let bytes = render_grid_to_png_bytes(&charts, &opts, 2, 200, 150).expect("render grid");
+    let sheet = image::load_from_memory(&bytes).expect("decode").to_rgba8();
+    assert_eq!(sheet.dimensions(), (400, 300));
+
+    // The bottom-right cell matches rendering the last chart on its own
+    // (up to anti-aliasing rounding from the cell translation)
+    let single_opts = RenderOptions { width: 200, height: 150, draw_labels: false, ..Default::default() };
+    let single = image::load_from_memory(&charts[3].render_to_png_bytes(&single_opts).unwrap()).unwrap().to_rgba8();
+    let cell = image::imageops::crop_imm(&sheet, 200, 150, 200, 150).to_image();
+    let max_diff = cell.as_raw().iter().zip(single.as_raw()).map(|(a, b)| a.abs_diff(*b)).max().unwrap();
+    assert!(max_diff <= 2, "cell differs from standalone render by {max_diff}");
+
+    assert!(render_grid_to_png_bytes(&charts, &opts, 0, 200, 150).is_err());
+}
+
+#[test]
+fn separate_overlays_get_their_own_panes() {
+    use chart_core::{RsiOverlay, SmaOverlay};
+
+    let mut price = Chart::new();
+    price.x_axis = Axis::new("X", 0.0, 20.0);
+    price.y_axis = Axis::new("Price", 0.0, 30.0);
+    price.add_series(Series::with_data(SeriesType::Line, (0..=20).map(|i| (i as f64, 10.0 + (i as f64 * 0.7).sin() * 5.0)).collect()));
+    price.add_overlay(SmaOverlay { period: 5 });
+    price.add_overlay(RsiOverlay { period: 14 });
+
+    let multi = MultiChart::with_overlay_panes(price, 0.3);
+    assert_eq!(multi.panels.len(), 2);
+    let (pane, frac) = &multi.panels[1];
+    assert_eq!(*frac, 0.3);
+    assert_eq!((pane.y_axis.min, pane.y_axis.max), (0.0, 100.0));
+    assert_eq!(pane.series[0].data_xy.len(), 21 - 14);
+
+    // The RSI stays out of the price panel's own overlay group
+    let svg = multi.panels[0].0.render_to_svg_string(&RenderOptions { draw_labels: false, ..Default::default() }).unwrap();
+    let overlays = &svg[svg.find("<g id=\"overlays\"").unwrap()..];
+    assert_eq!(overlays[..overlays.find("  </g>").unwrap()].matches("<path ").count(), 1);
+}
@@ -0,0 +1,35 @@
+// File: crates/chart-core/tests/pnf.rs
+// Purpose: Point-and-figure column construction and reversal behavior.
+
+use chart_core::point_and_figure;
+use chart_core::pnf::{PnfColumn, PnfKind};
+
+#[test]
+fn reversal_sized_move_switches_column() {
+    // box 1, 3-box reversal
+    let closes = [10.0, 11.2, 12.0, 13.4, 11.5, 10.9, 10.0, 12.5, 13.0];
+    let pnf = point_and_figure(&closes, 1.0, 3);
+    assert_eq!(
+        pnf.columns,
+        vec![
+            // rises 10 -> 13; the 2-box dips to 11.5/10.9 do not reverse
+            PnfColumn { kind: PnfKind::X, low: 10, high: 13 },
+            // 10.0 is three boxes below 13 -> O column starting one box under the top
+            PnfColumn { kind: PnfKind::O, low: 10, high: 12 },
+            // 12.5 is only two boxes up; 13.0 completes the reversal
+            PnfColumn { kind: PnfKind::X, low: 11, high: 13 },
+        ]
+    );
+    assert_eq!(pnf.columns[0].len(), 4);
+    assert_eq!(pnf.box_points(PnfKind::O), vec![(1.0, 10.0), (1.0, 11.0), (1.0, 12.0)]);
+}
+
+#[test]
+fn falling_start_and_invalid_params() {
+    let pnf = point_and_figure(&[20.0, 19.5, 18.0, 17.0], 1.0, 3);
+    assert_eq!(pnf.columns, vec![PnfColumn { kind: PnfKind::O, low: 17, high: 20 }]);
+
+    assert!(point_and_figure(&[1.0, 5.0], 0.0, 3).columns.is_empty());
+    assert!(point_and_figure(&[1.0, 5.0], 1.0, 0).columns.is_empty());
+    assert!(point_and_figure(&[], 1.0, 3).columns.is_empty());
+}
@@ -0,0 +1,37 @@
+// File: crates/chart-core/tests/png_metadata.rs
+// Purpose: PNG text-chunk metadata survives encoding and reads back.
+
+use chart_core::{read_text_chunks, Chart, PngMetadata, RenderOptions, Series};
+use chart_core::series::SeriesType;
+
+#[test]
+fn png_text_chunks_read_back() {
+    let mut chart = Chart::new();
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 1.0), (1.0, 2.0)]));
+    chart.add_series(Series::with_data(SeriesType::Histogram, vec![(0.0, 3.0), (1.0, 1.0)]));
+    let when = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    let opts = RenderOptions {
+        draw_labels: false,
+        width: 200,
+        height: 120,
+        png_metadata: Some(PngMetadata::new("BTC-USD 1h").with_timestamp(when)),
+        ..Default::default()
+    };
+
+    let bytes = chart.render_to_png_bytes(&opts).expect("png");
+    let chunks = read_text_chunks(&bytes);
+    assert_eq!(chunks, vec![
+        ("Title".to_string(), "BTC-USD 1h".to_string()),
+        ("Creation Time".to_string(), "2023-11-14T22:13:20Z".to_string()),
+        ("Series Count".to_string(), "2".to_string()),
+    ]);
+
+    // Still a valid image of the requested size
+    let img = image::load_from_memory(&bytes).expect("decode png");
+    assert_eq!((img.width(), img.height()), (200, 120));
+
+    // Non-Latin-1 titles go through iTXt
+    let opts = RenderOptions { png_metadata: Some(PngMetadata::new("日経平均").with_timestamp(when)), ..opts };
+    let bytes = chart.render_to_png_bytes(&opts).expect("png");
+    assert_eq!(read_text_chunks(&bytes)[0], ("Title".to_string(), "日経平均".to_string()));
+}
@@ -22,3 +22,200 @@ fn render_rgba8_buffer() {
     assert_eq!(a, 255);
 }
 
+#[test]
+fn horizontal_only_crosshair_skips_vertical_line() {
+    use chart_core::CrosshairMode;
+
+    let chart = Chart::new();
+    let mut opts = RenderOptions { draw_labels: false, crosshair: Some((300.0, 200.0)), ..Default::default() };
+    opts.crosshair_mode = CrosshairMode::HorizontalOnly;
+    let (px, w, _h, stride) = chart.render_to_rgba8(&opts).expect("rgba render");
+    let c = opts.theme.crosshair;
+    let is_crosshair = |x: usize, y: usize| {
+        let i = y * stride + x * 4;
+        px[i..i + 3] == [c.r(), c.g(), c.b()]
+    };
+    assert_eq!(w, 1024);
+    // Horizontal line runs through y = 200 away from the cursor column
+    assert!(is_crosshair(500, 200));
+    // No vertical line through x = 300 above or below the cursor
+    assert!(!is_crosshair(300, 100));
+    assert!(!is_crosshair(300, 400));
+
+    opts.crosshair_mode = CrosshairMode::Cross;
+    let (px, ..) = chart.render_to_rgba8(&opts).expect("rgba render");
+    let i = 100 * stride + 300 * 4;
+    assert_eq!(px[i..i + 3], [c.r(), c.g(), c.b()]);
+}
+
+#[test]
+fn recorded_picture_replays_same_pixels() {
+    use skia_safe as skia;
+
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 4.0);
+    chart.y_axis = Axis::new("Y", 0.0, 4.0);
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 1.0), (2.0, 3.5), (4.0, 0.5)]));
+    chart.add_series(Series::with_data(SeriesType::Histogram, vec![(1.0, 2.0), (3.0, 1.0)]));
+    let opts = RenderOptions { draw_labels: false, width: 320, height: 200, ..Default::default() };
+
+    let (expected, w, h, stride) = chart.render_to_rgba8(&opts).expect("rgba render");
+    let picture = chart.record_picture(&opts).expect("record picture");
+
+    let mut surface = skia::surfaces::raster_n32_premul((w, h)).expect("surface");
+    surface.canvas().draw_picture(&picture, None, None);
+    let info = skia::ImageInfo::new((w, h), skia::ColorType::RGBA8888, skia::AlphaType::Premul, None);
+    let mut replayed = vec![0u8; stride * h as usize];
+    assert!(surface.read_pixels(&info, replayed.as_mut_slice(), stride, (0, 0)));
+    assert!(replayed == expected, "replayed picture differs from direct render");
+}
+
+#[test]
+fn line_stroke_scales_with_dpr() {
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 4.0);
+    chart.y_axis = Axis::new("Y", 0.0, 1.0);
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 0.37), (4.0, 0.37)]));
+
+    // AA off so the stroke covers whole rows only
+    let line_rows = |dpr: f32| {
+        let opts = RenderOptions { draw_labels: false, deterministic: true, dpr, ..Default::default() };
+        let (px, w, h, stride) = chart.render_to_rgba8(&opts).expect("rgba render");
+        let c = opts.theme.line_stroke;
+        let x = (w / 2 + 7) as usize;
+        (0..h as usize).filter(|&y| px[y * stride + x * 4..][..3] == [c.r(), c.g(), c.b()]).count()
+    };
+    let (one, two) = (line_rows(1.0), line_rows(2.0));
+    assert!(one >= 2, "dpr 1 line rows: {}", one);
+    assert!(two + 1 >= 2 * one && two <= 2 * one + 1, "dpr 1: {} rows, dpr 2: {} rows", one, two);
+}
+
+#[test]
+fn frame_outlines_image_edge() {
+    use chart_core::Frame;
+
+    let chart = Chart::new();
+    let red = skia_safe::Color::from_argb(255, 220, 20, 30);
+    let opts = RenderOptions { draw_labels: false, width: 160, height: 100, frame: Some(Frame::new(red, 2.0)), ..Default::default() };
+    let (px, w, h, stride) = chart.render_to_rgba8(&opts).expect("rgba render");
+    let rgb = |x: i32, y: i32| {
+        let i = y as usize * stride + x as usize * 4;
+        [px[i], px[i + 1], px[i + 2]]
+    };
+    let frame = [red.r(), red.g(), red.b()];
+    for (x, y) in [(0, 0), (w - 1, 0), (0, h - 1), (w - 1, h - 1), (w / 2, 1), (1, h / 2), (w - 2, h / 2), (w / 2, h - 2)] {
+        assert_eq!(rgb(x, y), frame, "frame pixel at ({}, {})", x, y);
+    }
+    // Just inside the 2px band the background shows again
+    let bg = opts.theme.background;
+    assert_eq!(rgb(3, 3), [bg.r(), bg.g(), bg.b()]);
+
+    let svg = chart.render_to_svg_string(&opts).expect("svg");
+    assert!(svg.contains(r##"<rect id="frame" x="1" y="1" width="158" height="98" fill="none" stroke="#DC141E""##), "{}", svg);
+}
+
+#[test]
+fn fill_between_shades_region_between_lines() {
+    use skia_safe as skia;
+
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 4.0);
+    chart.y_axis = Axis::new("Y", 0.0, 4.0);
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 3.0), (2.0, 3.5), (4.0, 3.0)]));
+    // Coarser sampling than A: B is interpolated at x = 2
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 1.0), (4.0, 1.0)]));
+    let color = skia::Color::from_rgb(200, 0, 200);
+    chart.fill_between(0, 1, color);
+
+    let opts = RenderOptions { draw_labels: false, ..Default::default() };
+    let (px, w, h, stride) = chart.render_to_rgba8(&opts).expect("rgba render");
+    let is_fill = |x: usize, y: usize| {
+        let i = y * stride + x * 4;
+        px[i..i + 3] == [color.r(), color.g(), color.b()]
+    };
+    let filled = (0..h as usize).flat_map(|y| (0..w as usize).map(move |x| (x, y))).filter(|&(x, y)| is_fill(x, y)).count();
+    assert!(filled > 1000, "filled pixels: {filled}");
+
+    let (x, y) = chart.data_to_pixel(&opts, 2.0, 2.0);
+    assert!(is_fill(x as usize, y as usize));
+    let (x, y) = chart.data_to_pixel(&opts, 2.0, 0.5);
+    assert!(!is_fill(x as usize, y as usize), "below B stays unfilled");
+}
+
+#[test]
+fn renderer_reuses_surface_until_size_changes() {
+    use chart_core::Renderer;
+
+    let mut chart = Chart::new();
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 0.0), (4.0, 4.0)]));
+    let mut opts = RenderOptions { width: 200, height: 120, draw_labels: false, ..Default::default() };
+    let mut renderer = Renderer::new();
+    assert_eq!(renderer.allocations(), 0);
+
+    let first = renderer.render_to_rgba8(&chart, &opts).expect("first render").to_vec();
+    let second = renderer.render_to_rgba8(&chart, &opts).expect("second render").to_vec();
+    assert_eq!(renderer.allocations(), 1);
+    assert_eq!(first, second);
+    // Same pixels as the one-shot path
+    let (px, _, _, _) = chart.render_to_rgba8(&opts).expect("rgba render");
+    assert_eq!(first, px);
+
+    opts.width = 240;
+    let resized = renderer.render_to_rgba8(&chart, &opts).expect("resized render");
+    assert_eq!(resized.len(), 240 * 120 * 4);
+    assert_eq!(renderer.allocations(), 2);
+}
+
+#[test]
+fn background_image_fills_background() {
+    use chart_core::BackgroundFit;
+    use skia_safe as skia;
+
+    // 2x2 solid magenta PNG
+    let mut tile = skia::surfaces::raster_n32_premul((2, 2)).expect("tile surface");
+    tile.canvas().clear(skia::Color::from_argb(255, 200, 0, 200));
+    #[allow(deprecated)]
+    let png = tile.image_snapshot().encode_to_data(skia::EncodedImageFormat::PNG).expect("encode tile");
+
+    let chart = Chart::new();
+    for fit in [BackgroundFit::Stretch, BackgroundFit::Tile] {
+        let opts = RenderOptions {
+            width: 120,
+            height: 80,
+            draw_labels: false,
+            background_image: Some(png.as_bytes().to_vec()),
+            background_fit: fit,
+            ..Default::default()
+        };
+        let (px, _, _, stride) = chart.render_to_rgba8(&opts).expect("rgba render");
+        // Corners lie outside the plot and axes, so only the image shows there
+        for (x, y) in [(0usize, 0usize), (119, 0), (119, 79)] {
+            let i = y * stride + x * 4;
+            assert_eq!(px[i..i + 4], [200, 0, 200, 255], "{fit:?} at ({x}, {y})");
+        }
+    }
+}
+
+#[test]
+fn transparent_overlay_series_draws_nothing() {
+    use chart_core::plugin::Overlay;
+
+    struct Hidden;
+    impl Overlay for Hidden {
+        fn id(&self) -> &'static str { "hidden" }
+        fn compute(&self, chart: &Chart) -> Vec<Series> {
+            let data = chart.series[0].data_xy.iter().map(|&(x, y)| (x, 4.0 - y)).collect();
+            vec![Series::with_data(SeriesType::Line, data).with_opacity(0.0)]
+        }
+    }
+
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 4.0);
+    chart.y_axis = Axis::new("Y", 0.0, 4.0);
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 0.0), (4.0, 4.0)]));
+    let opts = RenderOptions { draw_labels: false, ..Default::default() };
+    let plain = chart.render_to_rgba8(&opts).expect("rgba render").0;
+
+    chart.add_overlay(Hidden);
+    assert_eq!(chart.render_to_rgba8(&opts).expect("rgba render").0, plain);
+}
@@ -0,0 +1,235 @@
+// File: crates/chart-core/tests/series.rs
+// Purpose: Series-level helpers (classification, transforms) independent of rendering.
+
+use chart_core::{CandlePattern, Series, SeriesType};
+use chart_core::series::Candle;
+
+fn candle(o: f64, h: f64, l: f64, c: f64) -> Candle {
+    Candle { t: 0.0, o, h, l, c }
+}
+
+#[test]
+fn classify_candle_thresholds() {
+    // range 10 throughout; body sizes straddle the 10% / 95% boundaries
+    let s = Series::from_candles(vec![
+        candle(5.0, 10.0, 0.0, 5.99),  // body 0.99 (< 10%) -> doji
+        candle(5.0, 10.0, 0.0, 6.0),   // body 1.0 (= 10%) -> normal
+        candle(0.0, 10.0, 0.0, 9.5),   // body 9.5 (= 95%) up -> bull marubozu
+        candle(9.6, 10.0, 0.0, 0.1),   // body 9.5 down -> bear marubozu
+        candle(0.5, 10.0, 0.0, 9.9),   // body 9.4 -> normal
+        candle(3.0, 3.0, 3.0, 3.0),    // zero range -> doji
+    ]);
+    assert_eq!(
+        s.classify_candles(),
+        vec![
+            CandlePattern::Doji,
+            CandlePattern::Normal,
+            CandlePattern::BullMarubozu,
+            CandlePattern::BearMarubozu,
+            CandlePattern::Normal,
+            CandlePattern::Doji,
+        ]
+    );
+}
+
+#[test]
+fn classify_xy_series_is_empty() {
+    let s = Series::with_data(chart_core::SeriesType::Line, vec![(0.0, 1.0)]);
+    assert!(s.classify_candles().is_empty());
+}
+
+#[test]
+fn percent_change_from_first_value() {
+    let s = Series::with_data(SeriesType::Line, vec![(0.0, 50.0), (1.0, 75.0), (2.0, 100.0), (3.0, 25.0)]);
+    let pct = s.to_percent_change(None);
+    assert_eq!(pct.data_xy, vec![(0.0, 0.0), (1.0, 50.0), (2.0, 100.0), (3.0, -50.0)]);
+
+    // Explicit base
+    let pct = s.to_percent_change(Some(100.0));
+    assert_eq!(pct.data_xy[0].1, -50.0);
+    assert_eq!(pct.data_xy[2].1, 0.0);
+}
+
+#[test]
+fn normalize_percent_rebases_each_series() {
+    use chart_core::{Chart, LabelFormat};
+    let mut chart = Chart::new();
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 10.0), (1.0, 20.0)]));
+    chart.add_series(Series::from_candles(vec![
+        Candle { t: 0.0, o: 190.0, h: 210.0, l: 180.0, c: 200.0 },
+        Candle { t: 1.0, o: 200.0, h: 420.0, l: 200.0, c: 400.0 },
+    ]));
+    chart.normalize_percent(None);
+    assert_eq!(chart.series[0].data_xy, vec![(0.0, 0.0), (1.0, 100.0)]);
+    let c = &chart.series[1].data_ohlc;
+    assert_eq!((c[0].c, c[1].c), (0.0, 100.0));
+    assert!((c[1].h - 110.0).abs() < 1e-9);
+    assert_eq!(chart.y_axis.label_format, LabelFormat::Percent);
+    assert_eq!(chart.y_axis.format_value(100.0), "100.00%");
+}
+
+#[test]
+fn log_returns_of_geometric_sequence_are_constant() {
+    let growth = 1.05f64;
+    let pts: Vec<(f64, f64)> = (0..10).map(|i| (i as f64, 100.0 * growth.powi(i))).collect();
+    let r = Series::with_data(SeriesType::Line, pts).to_log_returns();
+    assert_eq!(r.data_xy.len(), 9);
+    assert_eq!(r.data_xy[0].0, 1.0);
+    for &(_, v) in &r.data_xy {
+        assert!((v - growth.ln()).abs() < 1e-12, "return {v}");
+    }
+
+    // Steps touching non-positive values are dropped
+    let r = Series::with_data(SeriesType::Line, vec![(0.0, 1.0), (1.0, 0.0), (2.0, 2.0), (3.0, 4.0)]).to_log_returns();
+    assert_eq!(r.data_xy.len(), 1);
+    assert!((r.data_xy[0].1 - 2f64.ln()).abs() < 1e-12);
+}
+
+#[test]
+fn gap_modes_on_single_nan() {
+    use chart_core::GapMode;
+    let data = vec![(0.0, 1.0), (1.0, 2.0), (2.0, f64::NAN), (3.0, 4.0), (4.0, 5.0)];
+    let series = |mode| Series::with_data(SeriesType::Line, data.clone()).with_gap_mode(mode);
+
+    // Break (default): two runs around the hole
+    assert_eq!(Series::with_data(SeriesType::Line, data.clone()).gap_mode, GapMode::Break);
+    assert_eq!(series(GapMode::Break).line_runs(), vec![vec![(0.0, 1.0), (1.0, 2.0)], vec![(3.0, 4.0), (4.0, 5.0)]]);
+
+    // Connect: one run bridging (1, 2) -> (3, 4)
+    assert_eq!(series(GapMode::Connect).line_runs(), vec![vec![(0.0, 1.0), (1.0, 2.0), (3.0, 4.0), (4.0, 5.0)]]);
+
+    // Interpolate: the hole is filled halfway between its neighbours
+    let s = series(GapMode::Interpolate);
+    assert_eq!(s.line_runs(), vec![vec![(0.0, 1.0), (1.0, 2.0), (2.0, 3.0), (3.0, 4.0), (4.0, 5.0)]]);
+    assert!(s.data_xy[2].1.is_nan(), "stored data is untouched");
+}
+
+#[test]
+fn threshold_runs_insert_crossing_point() {
+    let s = Series::with_data(SeriesType::Line, vec![(0.0, -1.0), (2.0, 1.0), (3.0, 3.0), (5.0, -1.0)]);
+    let runs = s.threshold_runs(0.0);
+    assert_eq!(runs, vec![
+        (false, vec![(0.0, -1.0), (1.0, 0.0)]),
+        (true, vec![(1.0, 0.0), (2.0, 1.0), (3.0, 3.0), (4.5, 0.0)]),
+        (false, vec![(4.5, 0.0), (5.0, -1.0)]),
+    ]);
+
+    // Touching the threshold without crossing keeps one piece
+    let touch = Series::with_data(SeriesType::Line, vec![(0.0, 1.0), (1.0, 0.0), (2.0, 1.0)]);
+    assert_eq!(touch.threshold_runs(0.0), vec![(true, touch.data_xy.clone())]);
+}
+
+#[test]
+fn len_and_ranges_for_line_series() {
+    let s = Series::with_data(SeriesType::Line, vec![(0.0, 3.0), (1.0, f64::NAN), (2.0, -1.0), (4.0, 5.0)]);
+    assert_eq!(s.len(), 4);
+    assert!(!s.is_empty());
+    assert_eq!(s.x_range(), Some((0.0, 4.0)));
+    assert_eq!(s.y_range(), Some((-1.0, 5.0)), "NaN points are skipped");
+
+    let empty = Series::new(SeriesType::Line);
+    assert!(empty.is_empty());
+    assert_eq!(empty.x_range(), None);
+    assert_eq!(empty.y_range(), None);
+}
+
+#[test]
+fn len_and_ranges_for_candle_series() {
+    let s = Series::from_candles(vec![
+        Candle { t: 10.0, o: 5.0, h: 8.0, l: 4.0, c: 7.0 },
+        Candle { t: 20.0, o: 7.0, h: 9.5, l: 6.0, c: 6.5 },
+        Candle { t: 30.0, o: 6.5, h: 7.0, l: 2.5, c: 3.0 },
+    ]);
+    assert_eq!(s.len(), 3);
+    assert!(!s.is_empty());
+    // data_xy is unused for candles
+    assert!(s.data_xy.is_empty());
+    assert_eq!(s.x_range(), Some((10.0, 30.0)));
+    assert_eq!(s.y_range(), Some((2.5, 9.5)), "lows and highs, not closes");
+
+    assert!(Series::from_candles(Vec::new()).is_empty());
+}
+
+#[test]
+fn iter_xy_covers_every_storage_kind() {
+    use chart_core::Chart;
+
+    let mut chart = Chart::new();
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 1.0), (1.0, 2.0), (2.0, 3.0)]));
+    chart.add_series(Series::from_candles(vec![candle(1.0, 4.0, 0.5, 3.0), candle(3.0, 3.5, 1.0, 2.0)]));
+    chart.add_series(Series::with_band(vec![(0.0, 1.0, 3.0), (1.0, 2.0, 6.0)]));
+    chart.add_series(Series::with_errors(vec![(5.0, 2.0, 0.5)]));
+
+    let total: usize = chart.series.iter().map(|s| s.iter_xy().count()).sum();
+    assert_eq!(total, 3 + 2 + 2 + 1);
+    assert_eq!(chart.series[1].iter_xy().map(|p| p.1).collect::<Vec<_>>(), vec![3.0, 2.0], "candles yield closes");
+    assert_eq!(chart.series[2].iter_xy().collect::<Vec<_>>(), vec![(0.0, 2.0), (1.0, 4.0)], "bands yield midpoints");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn from_json_detects_point_pairs() {
+    let s = Series::from_json("[[0, 1.5], [1, 2.0], [2.5, -3]]").unwrap();
+    assert_eq!(s.series_type, SeriesType::Line);
+    assert_eq!(s.data_xy, vec![(0.0, 1.5), (1.0, 2.0), (2.5, -3.0)]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn from_json_detects_candle_objects() {
+    let s = Series::from_json(r#"[{"t": 1, "o": 10, "h": 12, "l": 9, "c": 11}, {"c": 10.5, "t": 2, "o": 11, "h": 11.5, "l": 10}]"#).unwrap();
+    assert_eq!(s.series_type, SeriesType::Candlestick);
+    assert_eq!(s.data_ohlc, vec![
+        Candle { t: 1.0, o: 10.0, h: 12.0, l: 9.0, c: 11.0 },
+        Candle { t: 2.0, o: 11.0, h: 11.5, l: 10.0, c: 10.5 },
+    ]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn from_json_rejects_malformed_input() {
+    assert!(Series::from_json("[[0, 1], [1]]").is_err(), "short pair");
+    assert!(Series::from_json(r#"[{"t": 1, "o": 1, "h": 1, "l": 1}]"#).is_err(), "missing close");
+    assert!(Series::from_json(r#"[[0, 1], {"t": 1, "o": 1, "h": 1, "l": 1, "c": 1}]"#).is_err(), "mixed shapes");
+    assert!(Series::from_json("{\"x\": 1}").is_err(), "not an array");
+    assert!(Series::from_json("[[0, 1]").is_err(), "truncated");
+}
+
+#[test]
+fn sorted_constructor_fixes_reversed_input() {
+    let reversed: Vec<(f64, f64)> = (0..5).rev().map(|i| (i as f64, i as f64 * 10.0)).collect();
+    let s = Series::with_sorted_data(SeriesType::Line, reversed);
+    assert_eq!(s.data_xy, vec![(0.0, 0.0), (1.0, 10.0), (2.0, 20.0), (3.0, 30.0), (4.0, 40.0)]);
+}
+
+#[test]
+fn try_with_data_rejects_bad_x() {
+    use chart_core::SeriesError;
+
+    let ok = Series::try_with_data(SeriesType::Line, vec![(2.0, 1.0), (0.0, 3.0), (1.0, 2.0)]).expect("valid");
+    assert_eq!(ok.data_xy, vec![(0.0, 3.0), (1.0, 2.0), (2.0, 1.0)]);
+
+    let nan = Series::try_with_data(SeriesType::Line, vec![(0.0, 1.0), (f64::NAN, 2.0)]);
+    assert_eq!(nan.err(), Some(SeriesError::NonFiniteX { index: 1 }));
+
+    let dup = Series::try_with_data(SeriesType::Line, vec![(0.0, 1.0), (3.0, 2.0), (1.0, 0.0), (3.0, 5.0)]);
+    assert_eq!(dup.err(), Some(SeriesError::DuplicateX { index: 3, x: 3.0 }));
+}
+
+#[test]
+fn add_series_checked_rejects_high_below_close() {
+    use chart_core::{Chart, SeriesError};
+
+    let mut chart = Chart::new();
+    let good = Series::from_candles(vec![candle(1.0, 2.0, 0.5, 1.5)]);
+    chart.add_series_checked(good).expect("valid candles");
+
+    let bad = Series::from_candles(vec![candle(1.0, 2.0, 0.5, 1.5), candle(1.0, 1.2, 0.8, 1.4)]);
+    let err = chart.add_series_checked(bad).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<SeriesError>(),
+        Some(&SeriesError::InvalidCandle { index: 1, reason: "high below max(open,close)" })
+    );
+    assert!(err.to_string().contains("index 1"));
+    assert_eq!(chart.series.len(), 1, "rejected series is not added");
+}
@@ -1,8 +1,9 @@
 // File: crates/chart-core/tests/snapshot_series.rs
-// Purpose: Golden snapshots for additional series types: candlesticks, bars, histogram, baseline.
+// Purpose: Golden snapshots for additional series types: candlesticks, bars, histogram, baseline
+// (plus render options such as gradients and deterministic labeled output).
 
 use chart_core::{Axis, Chart, RenderOptions, Series};
-use chart_core::series::{Candle, SeriesType};
+use chart_core::series::{Candle, MarkerShape, SeriesType};
 
 fn bless_mode() -> bool {
     std::env::var("UPDATE_SNAPSHOTS").ok().map(|v| v == "1" || v.eq_ignore_ascii_case("true")).unwrap_or(false)
@@ -37,6 +38,18 @@ fn render_to_bytes<F: FnOnce(&mut Chart)>(mut build: F, x_label: &str, y_label:
     chart.render_to_png_bytes(&opts).expect("render bytes")
 }
 
+fn render_with_opts<F: FnOnce(&mut Chart), G: FnOnce(&mut RenderOptions)>(build: F, tweak: G) -> Vec<u8> {
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 9.0);
+    chart.y_axis = Axis::new("Y", -2.0, 6.0);
+    build(&mut chart);
+
+    let mut opts = RenderOptions::default();
+    opts.draw_labels = false; // deterministic
+    tweak(&mut opts);
+    chart.render_to_png_bytes(&opts).expect("render bytes")
+}
+
 #[test]
 fn golden_candlesticks() {
     let candles = vec![
@@ -83,3 +96,217 @@ fn golden_baseline() {
     write_or_compare(&path, &bytes);
 }
 
+#[test]
+fn golden_baseline_two_color() {
+    let data = vec![
+        (0.0, 1.0), (1.0, 2.5), (2.0, -0.5), (3.0, 1.8), (4.0, 0.2), (5.0, 3.0),
+    ];
+    let bytes = render_with_opts(
+        |c| c.add_series(Series::with_data(SeriesType::Baseline, data).with_baseline(1.0)),
+        |o| o.theme.baseline_fill_neg = skia_safe::Color::from_argb(96, 230, 70, 70),
+    );
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/__snapshots__/baseline_two_color.png");
+    write_or_compare(&path, &bytes);
+}
+
+#[test]
+fn golden_minor_grid() {
+    let data = vec![
+        (0.0, 1.0), (2.0, 3.5), (4.0, 0.5), (6.0, 4.0), (8.0, 2.0),
+    ];
+    let bytes = render_with_opts(
+        |c| c.add_series(Series::with_data(SeriesType::Line, data)),
+        |o| o.show_minor_grid = true,
+    );
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/__snapshots__/minor_grid.png");
+    write_or_compare(&path, &bytes);
+}
+
+#[test]
+fn golden_multi_panel_price_and_histogram() {
+    use chart_core::MultiChart;
+
+    let candles = vec![
+        Candle { t: 0.0, o: 2.0, h: 3.0, l: 1.0, c: 2.5 },
+        Candle { t: 1.0, o: 2.5, h: 3.5, l: 2.0, c: 2.0 },
+        Candle { t: 2.0, o: 2.0, h: 4.0, l: 1.5, c: 3.0 },
+        Candle { t: 3.0, o: 3.0, h: 3.2, l: 2.4, c: 2.6 },
+        Candle { t: 4.0, o: 2.6, h: 2.9, l: 2.1, c: 2.2 },
+    ];
+    let deltas: Vec<(f64, f64)> = candles.iter().map(|c| (c.t, c.c - c.o)).collect();
+
+    let mut price = Chart::new();
+    price.x_axis = Axis::new("X", -0.5, 4.5);
+    price.y_axis = Axis::new("Price", 0.5, 4.5);
+    price.add_series(Series::from_candles(candles));
+
+    let mut hist = Chart::new();
+    hist.x_axis = Axis::new("X", -0.5, 4.5);
+    hist.y_axis = Axis::new("Delta", -1.0, 1.0);
+    hist.add_series(Series::with_data(SeriesType::Histogram, deltas).with_baseline(0.0));
+
+    let mut multi = MultiChart::new();
+    multi.add_panel(price, 0.7);
+    multi.add_panel(hist, 0.3);
+
+    let mut opts = RenderOptions::default();
+    opts.draw_labels = false; // deterministic
+    let rects = multi.panel_rects(&opts);
+    assert_eq!(rects.len(), 2);
+    assert!(rects[0].bottom < rects[1].top);
+    assert!(rects[0].height() > rects[1].height() * 2);
+    assert_eq!(rects[1].bottom, opts.height - opts.insets.bottom as i32);
+
+    let bytes = multi.render_to_png_bytes(&opts).expect("render bytes");
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/__snapshots__/multi_panel.png");
+    write_or_compare(&path, &bytes);
+}
+
+#[test]
+fn golden_hollow_candles() {
+    use chart_core::CandleStyle;
+
+    let candles = vec![
+        Candle { t: 0.0, o: 2.0, h: 3.0, l: 1.0, c: 2.5 },
+        Candle { t: 1.0, o: 2.5, h: 3.5, l: 2.0, c: 2.0 },
+        Candle { t: 2.0, o: 2.0, h: 4.0, l: 1.5, c: 3.0 },
+        Candle { t: 3.0, o: 3.0, h: 3.2, l: 2.4, c: 2.6 },
+        Candle { t: 4.0, o: 2.6, h: 2.9, l: 2.1, c: 2.8 },
+    ];
+    let bytes = render_with_opts(
+        |chart| chart.add_series(Series::from_candles(candles)),
+        |opts| opts.candle_style = CandleStyle::Hollow,
+    );
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/__snapshots__/hollow_candles.png");
+    write_or_compare(&path, &bytes);
+}
+
+#[test]
+fn golden_widening_band() {
+    let band: Vec<(f64, f64, f64)> = (0..10)
+        .map(|i| {
+            let x = i as f64;
+            let mid = 2.0 + 0.1 * x;
+            let half = 0.2 + 0.3 * x;
+            (x, mid - half, mid + half)
+        })
+        .collect();
+    let bytes = render_to_bytes(|chart| chart.add_series(Series::with_band(band)), "X", "Band");
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/__snapshots__/band.png");
+    write_or_compare(&path, &bytes);
+}
+
+#[test]
+fn golden_rotated_time_labels() {
+    use chart_core::types::Insets;
+
+    // Epoch-second X range under a day so ticks format as "MM-DD HH:MM"
+    let t0 = 1_700_000_000.0;
+    let hour = 3_600.0;
+    let pts: Vec<(f64, f64)> = (0..12).map(|i| (t0 + i as f64 * 2.0 * hour, 1.0 + (i % 4) as f64)).collect();
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("Time", t0, t0 + 22.0 * hour);
+    chart.y_axis = Axis::new("Y", 0.0, 5.0);
+    chart.add_series(Series::with_data(SeriesType::Line, pts));
+
+    let mut opts = RenderOptions::default().with_insets(Insets::new(60, 20, 20, 90));
+    opts.x_label_rotation_deg = -45.0;
+    let bytes = chart.render_to_png_bytes(&opts).expect("render bytes");
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/__snapshots__/rotated_time_labels.png");
+    write_or_compare(&path, &bytes);
+}
+
+#[test]
+fn golden_columns() {
+    // Five categories at x = 0..4 with explicit unit slots and a wide gap
+    let data = vec![(0.0, 3.0), (1.0, 5.0), (2.0, -1.5), (3.0, 4.2), (4.0, 2.0)];
+    let bytes = render_with_opts(
+        |chart| {
+            chart.x_axis = Axis::new("Category", -0.5, 4.5);
+            chart.add_series(Series::with_columns(data).with_column_width(1.0).with_column_gap(0.3));
+        },
+        |_| {},
+    );
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/__snapshots__/columns.png");
+    write_or_compare(&path, &bytes);
+}
+
+#[test]
+fn golden_horizontal_bars() {
+    use chart_core::Orientation;
+
+    let data = vec![(0.0, 3.0), (1.0, 5.0), (2.0, 1.5), (3.0, 4.2), (4.0, 2.0)];
+    let bytes = render_with_opts(
+        |chart| {
+            chart.x_axis = Axis::new("Category", -0.5, 4.5);
+            chart.y_axis = Axis::new("Value", 0.0, 6.0);
+            chart.add_series(Series::with_columns(data).with_column_width(1.0).with_column_gap(0.3));
+        },
+        |opts| opts.orientation = Orientation::Horizontal,
+    );
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/__snapshots__/horizontal_bars.png");
+    write_or_compare(&path, &bytes);
+}
+
+#[test]
+fn golden_gradient_fills() {
+    let candles = vec![
+        Candle { t: 0.0, o: 2.0, h: 3.0, l: 1.0, c: 2.5 },
+        Candle { t: 1.0, o: 2.5, h: 3.5, l: 2.0, c: 2.0 },
+        Candle { t: 2.0, o: 2.0, h: 4.0, l: 1.5, c: 3.0 },
+        Candle { t: 3.0, o: 3.0, h: 3.2, l: 2.4, c: 2.6 },
+        Candle { t: 4.0, o: 2.6, h: 2.9, l: 2.1, c: 2.2 },
+    ];
+    let volume = vec![(5.0, 3.0), (6.0, 4.5), (7.0, 2.0), (8.0, 5.0), (9.0, 3.5)];
+    let build = |chart: &mut Chart| {
+        chart.add_series(Series::from_candles(candles.clone()));
+        chart.add_series(Series::with_data(SeriesType::Histogram, volume.clone()));
+    };
+    let flat = render_with_opts(build, |_| {});
+    let bytes = render_with_opts(build, |opts| opts.gradient_fills = true);
+    assert_ne!(flat, bytes, "gradient fills should change the rendered bodies");
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/__snapshots__/gradient_fills.png");
+    write_or_compare(&path, &bytes);
+}
+
+#[test]
+fn golden_deterministic_labels() {
+    let data = (0..10).map(|i| (i as f64, ((i as f64) * 0.7).sin() * 3.0 + 2.0)).collect::<Vec<_>>();
+    let render = |deterministic: bool| {
+        let mut chart = Chart::new();
+        chart.x_axis = Axis::new("X", 0.0, 9.0);
+        chart.y_axis = Axis::new("Y", -2.0, 6.0);
+        chart.add_series(Series::with_data(SeriesType::Line, data.clone()));
+        let opts = RenderOptions { draw_labels: true, deterministic, ..Default::default() };
+        chart.render_to_png_bytes(&opts).expect("render bytes")
+    };
+    let first = render(true);
+    assert_eq!(first, render(true), "deterministic renders must be byte-identical");
+    assert_ne!(first, render(false), "deterministic mode draws without anti-aliasing");
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/__snapshots__/deterministic_labels.png");
+    write_or_compare(&path, &first);
+}
+
+#[test]
+fn golden_plot_border() {
+    let data = vec![(0.0, 1.0), (3.0, 4.5), (6.0, 0.5), (9.0, 3.0)];
+    let build = |chart: &mut Chart| chart.add_series(Series::with_data(SeriesType::Line, data.clone()));
+    let open = render_with_opts(build, |_| {});
+    let bytes = render_with_opts(build, |opts| opts.plot_border = true);
+    assert_ne!(open, bytes, "plot border should add the top and right edges");
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/__snapshots__/plot_border.png");
+    write_or_compare(&path, &bytes);
+}
+
+#[test]
+fn golden_line_markers() {
+    let data = vec![(0.0, 1.0), (2.0, 4.0), (4.5, 2.5), (7.0, 5.0), (9.0, 0.5)];
+    let plain = render_with_opts(|chart| chart.add_series(Series::with_data(SeriesType::Line, data.clone())), |_| {});
+    let bytes = render_with_opts(
+        |chart| chart.add_series(Series::with_data(SeriesType::Line, data.clone()).with_markers(MarkerShape::Circle)),
+        |_| {},
+    );
+    assert_ne!(plain, bytes, "markers should add dots at the vertices");
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/__snapshots__/line_markers.png");
+    write_or_compare(&path, &bytes);
+}
@@ -0,0 +1,59 @@
+// File: crates/chart-core/tests/stacking.rs
+// Purpose: Stacked column/area series (stack_group) layout and autoscale.
+
+use chart_core::{Axis, Chart, RenderOptions, Series};
+
+fn column_rects(svg: &str) -> Vec<(f32, f32)> {
+    // (y, height) of every <rect> inside the series group
+    svg.lines()
+        .skip_while(|l| !l.contains("<g id=\"series\""))
+        .take_while(|l| !l.contains("</g>"))
+        .filter(|l| l.trim_start().starts_with("<rect"))
+        .map(|l| {
+            let attr = |name: &str| -> f32 {
+                let start = l.find(&format!(" {name}=\"")).unwrap() + name.len() + 3;
+                l[start..].split('"').next().unwrap().parse().unwrap()
+            };
+            (attr("y"), attr("height"))
+        })
+        .collect()
+}
+
+#[test]
+fn stacked_columns_reach_summed_height() {
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", -0.5, 1.5);
+    chart.add_series(Series::with_columns(vec![(0.0, 2.0), (1.0, 1.0)]).with_stack_group("p"));
+    chart.add_series(Series::with_columns(vec![(0.0, 3.0), (1.0, 4.0)]).with_stack_group("p"));
+    chart.autoscale_axes(0.0);
+    // Autoscale covers the stacked totals (5 at both x), not the tallest single layer
+    assert_eq!((chart.y_axis.min, chart.y_axis.max), (0.0, 5.0));
+
+    let mut opts = RenderOptions::default();
+    opts.draw_labels = false;
+    let path = std::env::temp_dir().join(format!("chart_core_stack_{}.svg", std::process::id()));
+    chart.render_to_svg(&opts, &path).expect("render svg");
+    let svg = std::fs::read_to_string(&path).expect("read svg");
+    std::fs::remove_file(&path).ok();
+
+    let rects = column_rects(&svg);
+    assert_eq!(rects.len(), 4);
+    let (top_plot, bottom_plot) = (opts.insets.top as f32, (opts.height - opts.insets.bottom as i32) as f32);
+    // Second layer at x=0 sits directly on the first and ends at the plot top (value 5)
+    let (y_a, h_a) = rects[0];
+    let (y_b, h_b) = rects[2];
+    assert!((y_a + h_a - bottom_plot).abs() < 0.01);
+    assert!((y_b + h_b - y_a).abs() < 0.01, "second layer starts where the first ends");
+    assert!((y_b - top_plot).abs() < 0.01);
+    assert!((h_a + h_b - (bottom_plot - top_plot)).abs() < 0.01);
+}
+
+#[test]
+fn unstacked_series_keep_their_own_extents() {
+    let mut chart = Chart::new();
+    chart.add_series(Series::with_columns(vec![(0.0, 2.0)]).with_stack_group("a"));
+    chart.add_series(Series::with_columns(vec![(0.0, 3.0)]).with_stack_group("b"));
+    chart.add_series(Series::with_columns(vec![(0.0, 1.0)]));
+    chart.autoscale_axes(0.0);
+    assert_eq!(chart.y_axis.max, 3.0);
+}
@@ -0,0 +1,469 @@
+// File: crates/chart-core/tests/svg.rs
+// Purpose: Validate SVG export content (structure and colors).
+
+use chart_core::{Axis, Chart, RenderOptions, Series};
+use chart_core::series::Candle;
+
+fn svg_for(chart: &Chart, opts: &RenderOptions, name: &str) -> String {
+    let path = std::env::temp_dir().join(format!("chart_core_svg_{}_{}.svg", name, std::process::id()));
+    chart.render_to_svg(opts, &path).expect("render svg");
+    let svg = std::fs::read_to_string(&path).expect("read svg");
+    std::fs::remove_file(&path).ok();
+    svg
+}
+
+#[test]
+fn candle_wicks_use_border_color() {
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 2.0);
+    chart.y_axis = Axis::new("Y", 0.0, 5.0);
+    chart.add_series(Series::from_candles(vec![
+        Candle { t: 0.5, o: 1.0, h: 4.0, l: 0.5, c: 3.0 },
+        Candle { t: 1.5, o: 3.0, h: 3.5, l: 1.0, c: 2.0 },
+    ]));
+
+    let mut opts = RenderOptions::default();
+    opts.draw_labels = false;
+    opts.theme.candle_up = skia_safe::Color::from_argb(255, 0x10, 0xC0, 0x20);
+    opts.theme.candle_up_border = skia_safe::Color::from_argb(255, 0x05, 0x50, 0x0A);
+    opts.theme.candle_down_border = skia_safe::Color::from_argb(255, 0x60, 0x00, 0x00);
+    let svg = svg_for(&chart, &opts, "border");
+
+    let wicks: Vec<&str> = svg.lines().filter(|l| l.trim_start().starts_with("<line") && l.contains("stroke-width=\"1\"")).collect();
+    assert!(wicks.iter().any(|l| l.contains("stroke=\"#05500A\"")), "up wick should use border color");
+    assert!(wicks.iter().any(|l| l.contains("stroke=\"#600000\"")), "down wick should use border color");
+    // Body keeps the fill color while the outline uses the border
+    assert!(svg.contains("fill=\"#10C020\""));
+    assert!(!wicks.iter().any(|l| l.contains("#10C020")));
+}
+
+#[test]
+fn error_bar_whiskers_span_plus_minus_err() {
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 4.0);
+    chart.y_axis = Axis::new("Y", 0.0, 10.0);
+    chart.add_series(Series::with_errors(vec![(2.0, 5.0, 1.5)]));
+    let mut opts = RenderOptions::default();
+    opts.draw_labels = false;
+    let svg = svg_for(&chart, &opts, "errbar");
+
+    let (x, top) = chart.data_to_pixel(&opts, 2.0, 6.5);
+    let (_, bot) = chart.data_to_pixel(&opts, 2.0, 3.5);
+    let whisker = format!("M {} {} L {} {}", x, top, x, bot);
+    assert!(svg.contains(&whisker), "missing whisker {}", whisker);
+    // caps at both ends
+    assert!(svg.contains(&format!("M {} {} L {} {}", x - 4.0, top, x + 4.0, top)));
+    assert!(svg.contains(&format!("M {} {} L {} {}", x - 4.0, bot, x + 4.0, bot)));
+}
+
+#[test]
+fn narrow_chart_thins_x_labels_but_keeps_ticks() {
+    fn attr<'a>(line: &'a str, name: &str) -> Option<&'a str> {
+        let start = line.find(&format!(" {name}=\""))? + name.len() + 3;
+        line[start..].split('"').next()
+    }
+    // (x tick marks, x tick labels) inside the ticks group
+    fn x_ticks_and_labels(svg: &str) -> (usize, usize) {
+        let group: Vec<&str> = svg
+            .lines()
+            .skip_while(|l| !l.contains("<g id=\"ticks\""))
+            .take_while(|l| !l.contains("</g>"))
+            .map(str::trim_start)
+            .collect();
+        let ticks = group.iter().filter(|l| l.starts_with("<line") && attr(l, "x1") == attr(l, "x2")).count();
+        let labels = group.iter().filter(|l| l.starts_with("<text") && l.contains("text-anchor=\"middle\"")).count();
+        (ticks, labels)
+    }
+
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 1_000.0, 9_000.0);
+    chart.y_axis = Axis::new("Y", 0.0, 5.0);
+
+    let wide = svg_for(&chart, &RenderOptions::default(), "wide_labels");
+    let (ticks, labels) = x_ticks_and_labels(&wide);
+    assert!(ticks >= 4);
+    assert_eq!(labels, ticks, "wide chart labels every tick");
+
+    let mut opts = RenderOptions::default();
+    opts.width = 220;
+    let narrow = svg_for(&chart, &opts, "narrow_labels");
+    let (ticks, labels) = x_ticks_and_labels(&narrow);
+    assert!(ticks >= 4);
+    assert!(labels > 0 && labels < ticks, "expected thinned labels: {labels} of {ticks}");
+}
+
+#[test]
+fn nan_breaks_line_into_subpaths() {
+    use chart_core::SeriesType;
+
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 4.0);
+    chart.y_axis = Axis::new("Y", 0.0, 5.0);
+    chart.add_series(Series::with_data(
+        SeriesType::Line,
+        vec![(0.0, 1.0), (1.0, 2.0), (2.0, f64::NAN), (3.0, 3.0), (4.0, 4.0)],
+    ));
+    let mut opts = RenderOptions::default();
+    opts.draw_labels = false;
+    let svg = svg_for(&chart, &opts, "nan_gap");
+
+    let line = svg
+        .lines()
+        .find(|l| l.trim_start().starts_with("<path") && l.contains("stroke-width=\"2\""))
+        .expect("line path");
+    let d = line.split("d=\"").nth(1).unwrap().split('"').next().unwrap();
+    assert!(!d.contains("NaN"), "path must not contain NaN: {d}");
+    assert_eq!(d.matches('M').count(), 2, "expected a move_to after the gap: {d}");
+    assert_eq!(d.matches('L').count(), 2);
+}
+
+#[test]
+fn watermark_text_is_drawn_with_its_opacity() {
+    use chart_core::Watermark;
+
+    let mut chart = Chart::new();
+    chart.add_series(Series::with_data(chart_core::SeriesType::Line, vec![(0.0, 1.0), (1.0, 2.0)]));
+    let mut opts = RenderOptions::default();
+    opts.draw_labels = false;
+    opts.watermark = Some(Watermark::new("AAPL <1D>", 0.12));
+    let svg = svg_for(&chart, &opts, "watermark");
+
+    let line = svg.lines().find(|l| l.contains("id=\"watermark\"")).expect("watermark element");
+    assert!(line.contains(">AAPL &lt;1D&gt;</text>"), "{line}");
+    assert!(line.contains("fill-opacity=\"0.120\""), "{line}");
+    // Drawn before the grid so series paint over it
+    assert!(svg.find("id=\"watermark\"").unwrap() < svg.find("id=\"grid\"").unwrap());
+}
+
+#[test]
+fn higher_z_index_series_is_drawn_later() {
+    let line_stroke = "stroke=\"#40A0FF\"";
+    let up_body = "fill=\"#28C878\"";
+    let build = |line_z: i32| {
+        let mut chart = Chart::new();
+        chart.x_axis = Axis::new("X", 0.0, 2.0);
+        chart.y_axis = Axis::new("Y", 0.0, 5.0);
+        // Line added first; candles would normally paint over it
+        chart.add_series(Series::with_data(chart_core::SeriesType::Line, vec![(0.0, 1.0), (2.0, 4.0)]).with_z_index(line_z));
+        chart.add_series(Series::from_candles(vec![Candle { t: 1.0, o: 1.0, h: 4.5, l: 0.5, c: 3.5 }]));
+        chart
+    };
+    let opts = RenderOptions { draw_labels: false, ..Default::default() };
+
+    let svg = svg_for(&build(0), &opts, "z_default");
+    assert!(svg.find(line_stroke).unwrap() < svg.find(up_body).unwrap(), "ties keep insertion order");
+
+    let svg = svg_for(&build(1), &opts, "z_raised");
+    assert!(svg.find(line_stroke).unwrap() > svg.find(up_body).unwrap(), "high-z line paints after candles");
+}
+
+#[test]
+fn series_opacity_scales_svg_alpha() {
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 2.0);
+    chart.y_axis = Axis::new("Y", 0.0, 5.0);
+    chart.add_series(Series::with_data(chart_core::SeriesType::Line, vec![(0.0, 1.0), (2.0, 4.0)]).with_opacity(0.5));
+    let mut opts = RenderOptions { draw_labels: false, ..Default::default() };
+    // Translucent line color: 200/255 alpha, halved by the series opacity
+    opts.theme.line_stroke = skia_safe::Color::from_argb(200, 0x40, 0xA0, 0xFF);
+    let svg = svg_for(&chart, &opts, "opacity");
+
+    let line = svg.lines().find(|l| l.contains("stroke=\"#40A0FF\"") && l.contains("<path")).expect("line path");
+    let want = format!("stroke-opacity=\"{:.3}\"", 200.0 / 255.0 * 0.5);
+    assert!(line.contains(&want), "{line}");
+}
+
+#[test]
+fn svg_string_matches_file_output() {
+    let mut chart = Chart::new();
+    chart.add_series(Series::with_data(chart_core::SeriesType::Line, vec![(0.0, 1.0), (5.0, 3.0)]));
+    chart.autoscale_axes(0.05);
+    let opts = RenderOptions { draw_labels: false, ..Default::default() };
+
+    let svg = chart.render_to_svg_string(&opts).expect("svg string");
+    assert!(svg.starts_with("<?xml"));
+    assert!(svg.contains("<svg "));
+    assert_eq!(svg, svg_for(&chart, &opts, "string"));
+}
+
+#[test]
+fn svg_axes_use_configured_line_width_and_colors() {
+    let mut chart = Chart::new();
+    chart.y_axis.line_color = Some(skia_safe::Color::from_argb(255, 0xFF, 0x80, 0x00));
+    let opts = RenderOptions { draw_labels: false, axis_line_width: 0.5, ..Default::default() };
+    let svg = chart.render_to_svg_string(&opts).expect("svg");
+
+    let axes = svg.lines().find(|l| l.contains("<g id=\"axes\"")).expect("axes group");
+    assert!(axes.contains("stroke-width=\"0.5\""), "{}", axes);
+    let lines: Vec<&str> = svg.lines().skip_while(|l| !l.contains("<g id=\"axes\"")).skip(1).take(2).collect();
+    assert!(!lines[0].contains("stroke="), "x axis keeps the theme color: {}", lines[0]);
+    assert!(lines[1].contains("stroke=\"#FF8000\""), "y axis override: {}", lines[1]);
+}
+
+#[test]
+fn grid_opacity_scales_svg_stroke_opacity() {
+    let chart = Chart::new();
+    let grid_line_opacity = |grid_opacity: f32| -> f32 {
+        let opts = RenderOptions { draw_labels: false, grid_opacity, ..Default::default() };
+        let svg = chart.render_to_svg_string(&opts).expect("svg");
+        let line = svg.lines().skip_while(|l| !l.contains("<g id=\"grid\"")).nth(1).expect("grid line");
+        let op = line.split("stroke-opacity=\"").nth(1).and_then(|s| s.split('"').next()).expect("stroke-opacity");
+        op.parse().expect("opacity value")
+    };
+    let full = grid_line_opacity(1.0);
+    let faded = grid_line_opacity(0.4);
+    assert!(full > 0.0);
+    assert!((faded - full * 0.4).abs() < 0.01, "full {} faded {}", full, faded);
+    assert_eq!(grid_line_opacity(0.0), 0.0);
+}
+
+#[test]
+fn last_value_line_sits_at_last_value() {
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 4.0);
+    chart.y_axis = Axis::new("Y", 0.0, 10.0);
+    chart.add_series(Series::with_data(chart_core::SeriesType::Line, vec![(0.0, 2.0), (2.0, 8.0), (4.0, 6.25)]));
+    let opts = RenderOptions { last_value_line: true, ..Default::default() };
+    let svg = chart.render_to_svg_string(&opts).expect("svg");
+
+    let group = svg.split("<g id=\"last-value\">").nth(1).expect("last-value group");
+    let line = group.lines().find(|l| l.contains("<line")).expect("dashed line");
+    assert!(line.contains("stroke-dasharray"), "{}", line);
+    let y1: f32 = line.split("y1=\"").nth(1).and_then(|s| s.split('"').next()).unwrap().parse().unwrap();
+    let (_, want) = chart.data_to_pixel(&opts, 4.0, 6.25);
+    assert!((y1 - want).abs() <= 0.5, "line y {} vs mapped {}", y1, want);
+    assert!(group.contains(">6.25</text>"), "badge shows the value: {}", group);
+
+    let off = chart.render_to_svg_string(&RenderOptions::default()).expect("svg");
+    assert!(!off.contains("last-value"));
+}
+
+#[test]
+fn highlight_extremes_marks_max_point() {
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 4.0);
+    chart.y_axis = Axis::new("Y", 0.0, 10.0);
+    chart.add_series(
+        Series::with_data(chart_core::SeriesType::Line, vec![(0.0, 3.0), (1.0, 9.0), (2.0, 1.5), (3.0, 4.0)])
+            .with_highlight_extremes(true),
+    );
+    let opts = RenderOptions { draw_labels: false, ..Default::default() };
+    let svg = chart.render_to_svg_string(&opts).expect("svg");
+
+    let attr = |line: &str, name: &str| -> f32 {
+        line.split(&format!(" {}=\"", name)).nth(1).and_then(|s| s.split('"').next()).unwrap().parse().unwrap()
+    };
+    let max = svg.lines().find(|l| l.contains("<circle class=\"max\"")).expect("max marker");
+    let (want_x, want_y) = chart.data_to_pixel(&opts, 1.0, 9.0);
+    assert!((attr(max, "cx") - want_x).abs() < 1e-3 && (attr(max, "cy") - want_y).abs() < 1e-3, "{}", max);
+    let min = svg.lines().find(|l| l.contains("<circle class=\"min\"")).expect("min marker");
+    let (_, want_y) = chart.data_to_pixel(&opts, 2.0, 1.5);
+    assert!((attr(min, "cy") - want_y).abs() < 1e-3, "{}", min);
+}
+
+#[test]
+fn histogram_overlay_renders() {
+    use chart_core::plugin::Overlay;
+    use chart_core::series::SeriesType;
+
+    // e.g. a MACD-style histogram computed from the base series
+    struct HistOverlay;
+    impl Overlay for HistOverlay {
+        fn id(&self) -> &'static str { "hist" }
+        fn compute(&self, chart: &Chart) -> Vec<Series> {
+            let data = chart.series[0].data_xy.iter().map(|&(x, y)| (x, y - 2.0)).collect();
+            vec![Series::with_data(SeriesType::Histogram, data)]
+        }
+    }
+
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 4.0);
+    chart.y_axis = Axis::new("Y", -2.0, 4.0);
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(1.0, 1.0), (2.0, 3.0), (3.0, 4.0)]));
+    chart.add_overlay(HistOverlay);
+    let opts = RenderOptions { draw_labels: false, ..Default::default() };
+
+    let svg = chart.render_to_svg_string(&opts).expect("svg");
+    let overlays = &svg[svg.find("<g id=\"overlays\"").expect("overlay group")..];
+    let overlays = &overlays[..overlays.find("  </g>").unwrap()];
+    let h = opts.theme.histogram;
+    let fill = format!("fill=\"#{:02X}{:02X}{:02X}\"", h.r(), h.g(), h.b());
+    assert_eq!(overlays.matches("<rect ").count(), 3);
+    assert_eq!(overlays.matches(&fill).count(), 3);
+
+    // Raster: the bar at x = 3 spans 0..2 in data space
+    let (px, _, _, stride) = chart.render_to_rgba8(&opts).expect("rgba");
+    let (x, y) = chart.data_to_pixel(&opts, 3.0, 1.0);
+    let i = y as usize * stride + x as usize * 4;
+    assert_eq!(px[i..i + 3], [h.r(), h.g(), h.b()]);
+}
+
+#[test]
+fn overlay_lines_use_series_color() {
+    use chart_core::plugin::Overlay;
+    use chart_core::series::SeriesType;
+    use skia_safe::Color;
+
+    struct Shifted(f64, Option<Color>);
+    impl Overlay for Shifted {
+        fn id(&self) -> &'static str { "shifted" }
+        fn compute(&self, chart: &Chart) -> Vec<Series> {
+            let data = chart.series[0].data_xy.iter().map(|&(x, y)| (x, y + self.0)).collect();
+            let s = Series::with_data(SeriesType::Line, data);
+            vec![match self.1 { Some(c) => s.with_color(c), None => s }]
+        }
+    }
+
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 4.0);
+    chart.y_axis = Axis::new("Y", 0.0, 6.0);
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 1.0), (2.0, 2.0), (4.0, 1.5)]));
+    chart.add_overlay(Shifted(1.0, Some(Color::from_rgb(0xE0, 0x40, 0x10))));
+    chart.add_overlay(Shifted(2.0, Some(Color::from_rgb(0x10, 0x90, 0xE0))));
+    chart.add_overlay(Shifted(3.0, None));
+    let opts = RenderOptions { draw_labels: false, ..Default::default() };
+
+    let svg = chart.render_to_svg_string(&opts).expect("svg");
+    let overlays = &svg[svg.find("<g id=\"overlays\"").expect("overlay group")..];
+    let overlays = &overlays[..overlays.find("  </g>").unwrap()];
+    let strokes: Vec<&str> = overlays.lines().filter_map(|l| l.split("stroke=\"").nth(1)).map(|s| &s[..7]).collect();
+    let c = opts.theme.crosshair;
+    let crosshair = format!("#{:02X}{:02X}{:02X}", c.r(), c.g(), c.b());
+    assert_eq!(strokes, vec!["#E04010", "#1090E0", crosshair.as_str()]);
+}
+
+#[test]
+fn background_image_is_embedded_as_data_uri() {
+    let chart = Chart::new();
+    let opts = RenderOptions { draw_labels: false, background_image: Some(b"\x89PNG\r\n\x1a\nxyz".to_vec()), ..Default::default() };
+    let svg = chart.render_to_svg_string(&opts).expect("svg");
+    // Signature plus "xyz", padded to a multiple of four
+    assert!(svg.contains("href=\"data:image/png;base64,iVBORw0KGgp4eXo=\""), "{svg}");
+    let image_at = svg.find("id=\"background-image\"").expect("background image");
+    assert!(image_at < svg.find("<line").unwrap_or(svg.len()));
+}
+
+#[test]
+fn candle_color_fn_tints_even_candles() {
+    let tint = skia_safe::Color::from_argb(255, 0x20, 0x40, 0xE0);
+    let wick = skia_safe::Color::from_argb(255, 0x11, 0x22, 0x33);
+    let candles: Vec<Candle> = (0..4).map(|i| Candle { t: i as f64 + 0.5, o: 1.0, h: 4.0, l: 0.5, c: 3.0 }).collect();
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 4.0);
+    chart.y_axis = Axis::new("Y", 0.0, 5.0);
+    let theme_up = RenderOptions::default().theme.candle_up;
+    chart.add_series(Series::from_candles(candles).with_candle_colors(move |i, _| {
+        if i % 2 == 0 { (tint, wick) } else { (theme_up, theme_up) }
+    }));
+
+    let opts = RenderOptions { draw_labels: false, ..Default::default() };
+    let svg = chart.render_to_svg_string(&opts).expect("svg");
+    let bodies: Vec<&str> = svg.lines().filter(|l| l.contains("<rect") && l.contains("translate(")).collect();
+    assert_eq!(bodies.len(), 4);
+    for (i, body) in bodies.iter().enumerate() {
+        assert_eq!(body.contains("fill=\"#2040E0\""), i % 2 == 0, "candle {i}: {body}");
+    }
+    assert_eq!(svg.matches("stroke=\"#112233\"").count(), 4); // wick + body outline on two candles
+}
+
+#[test]
+fn baseline_splits_fill_at_the_baseline() {
+    use chart_core::SeriesType;
+
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 2.0);
+    chart.y_axis = Axis::new("Y", -2.0, 2.0);
+    chart.add_series(Series::with_data(SeriesType::Baseline, vec![(0.0, 1.0), (2.0, -1.0)]));
+    let mut opts = RenderOptions { draw_labels: false, ..Default::default() };
+    // One color by default
+    assert!(!chart.render_to_svg_string(&opts).expect("svg").contains("baseline-neg"));
+
+    opts.theme.baseline_fill_neg = skia_safe::Color::from_argb(255, 0xE0, 0x40, 0x40);
+    let svg = chart.render_to_svg_string(&opts).expect("svg");
+    let pos = svg.lines().find(|l| l.contains("class=\"baseline-pos\"")).expect("positive area");
+    let neg = svg.lines().find(|l| l.contains("class=\"baseline-neg\"")).expect("negative area");
+    assert!(neg.contains("fill=\"#E04040\""), "{neg}");
+    assert!(!pos.contains("#E04040"));
+    // The line crosses y = 0 halfway, so both areas share that vertex on the baseline
+    let plot = chart.plot_rect(&opts);
+    let mid = format!("{} {}", (plot.left + plot.right) as f32 * 0.5, (plot.top + plot.bottom) as f32 * 0.5);
+    assert!(pos.contains(&mid) && neg.contains(&mid), "{mid}\n{pos}\n{neg}");
+}
+
+#[test]
+fn histogram_bar_gap_and_alignment() {
+    use chart_core::{BarAlign, SeriesType};
+
+    let rects = |s: Series| -> Vec<(f32, f32)> {
+        let mut chart = Chart::new();
+        chart.x_axis = Axis::new("X", 0.0, 4.0);
+        chart.y_axis = Axis::new("Y", 0.0, 4.0);
+        chart.add_series(s);
+        let svg = chart.render_to_svg_string(&RenderOptions { draw_labels: false, ..Default::default() }).expect("svg");
+        let attr = |line: &str, name: &str| -> f32 {
+            let rest = &line[line.find(&format!(" {name}=\"")).expect("attr") + name.len() + 3..];
+            rest[..rest.find('"').unwrap()].parse().unwrap()
+        };
+        svg.lines()
+            .skip_while(|l| !l.contains("<g id=\"series\""))
+            .filter(|l| l.trim_start().starts_with("<rect"))
+            .map(|l| (attr(l, "x"), attr(l, "width")))
+            .collect()
+    };
+    let data = vec![(1.0, 2.0), (2.0, 3.0), (3.0, 1.0)];
+    let default = rects(Series::with_data(SeriesType::Histogram, data.clone()));
+    let abut = rects(Series::with_data(SeriesType::Histogram, data.clone()).with_bar_gap(0.0));
+    let wide_gap = rects(Series::with_data(SeriesType::Histogram, data.clone()).with_bar_gap(0.5));
+    assert_eq!(default.len(), 3);
+    let (w_default, w_abut, w_gap) = (default[0].1, abut[0].1, wide_gap[0].1);
+    assert!((w_abut * 0.8 - w_default).abs() < 1e-3, "{w_abut} vs {w_default}");
+    assert!((w_abut * 0.5 - w_gap).abs() < 1e-3, "{w_abut} vs {w_gap}");
+
+    // Left-aligned bars start where centered bars are centered
+    let left = rects(Series::with_data(SeriesType::Histogram, data).with_bar_align(BarAlign::Left));
+    for (l, c) in left.iter().zip(&default) {
+        assert!((l.0 - (c.0 + c.1 * 0.5)).abs() < 1e-3, "{l:?} vs {c:?}");
+    }
+}
+
+#[test]
+fn accessible_svg_describes_series_and_ranges() {
+    use chart_core::SeriesType;
+
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("Time", 0.0, 10.0);
+    chart.y_axis = Axis::new("Price", -2.0, 6.0);
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 1.0), (10.0, 3.0)]));
+    let opts = RenderOptions { draw_labels: false, ..Default::default() };
+    let svg = chart.render_to_svg_string(&opts).expect("svg");
+    assert!(svg.contains("role=\"img\""));
+    assert!(svg.contains("<title id=\"chart-title\">Price vs Time</title>"), "{svg}");
+    let desc = &svg[svg.find("<desc").expect("desc")..svg.find("</desc>").expect("desc end")];
+    assert!(desc.contains("1 series"), "{desc}");
+    // Range ends use the axes' own tick formatting
+    let (x, y) = (&chart.x_axis, &chart.y_axis);
+    assert!(desc.contains(&format!("Time axis from {} to {}", x.format_value(0.0), x.format_value(10.0))), "{desc}");
+    assert!(desc.contains(&format!("Price axis from {} to {}", y.format_value(-2.0), y.format_value(6.0))), "{desc}");
+
+    let plain = chart.render_to_svg_string(&RenderOptions { accessible_svg: false, ..opts }).expect("svg");
+    assert!(!plain.contains("<desc") && !plain.contains("role="));
+}
+
+#[test]
+fn stroke_widths_and_radii_scale_with_dpr() {
+    use chart_core::SeriesType;
+
+    let mut chart = Chart::new();
+    chart.x_axis = Axis::new("X", 0.0, 2.0);
+    chart.y_axis = Axis::new("Y", 0.0, 4.0);
+    let mut line = Series::with_data(SeriesType::Line, vec![(0.0, 1.0), (1.0, 3.0), (2.0, 2.0)]).with_markers(chart_core::MarkerShape::Circle);
+    line.highlight_extremes = true;
+    chart.add_series(line);
+    let opts = RenderOptions { draw_labels: false, dpr: 2.0, ..Default::default() };
+    let svg = chart.render_to_svg_string(&opts).expect("svg");
+    assert!(svg.contains("stroke-width=\"4\""), "line stroke not scaled");
+    assert!(svg.contains("<g id=\"grid\" stroke-linecap=\"butt\" stroke-width=\"2\""));
+    assert!(!svg.contains("stroke-width=\"1\""));
+    assert!(svg.contains("r=\"6\""), "marker radius not scaled");
+    assert!(svg.contains("r=\"7\""), "extreme dot radius not scaled");
+}
@@ -0,0 +1,86 @@
+// File: crates/chart-core/tests/theme.rs
+// Purpose: Theme construction helpers (builder, accent, TOML loading) and the runtime registry.
+
+use chart_core::Theme;
+use skia_safe::Color;
+
+fn colors(t: &Theme) -> Vec<Color> {
+    vec![
+        t.background, t.grid, t.axis_line, t.axis_label, t.tick, t.crosshair, t.line_stroke, t.candle_up,
+        t.candle_down, t.candle_up_border, t.candle_down_border, t.histogram, t.baseline_stroke,
+        t.baseline_fill, t.band_fill, t.band_stroke,
+    ]
+}
+
+#[test]
+fn builder_overrides_only_background() {
+    let bg = Color::from_argb(255, 1, 2, 3);
+    let theme = Theme::builder().name("brand").background(bg).build();
+    let dark = Theme::dark();
+    assert_eq!(theme.name, "brand");
+    assert_eq!(theme.background, bg);
+    assert_eq!(colors(&theme)[1..], colors(&dark)[1..]);
+}
+
+#[test]
+fn accent_recolors_line_baseline_and_up_candles() {
+    let accent = Color::from_argb(255, 0xAA, 0x33, 0xFF);
+    let dark = Theme::dark();
+    let theme = dark.with_accent(accent);
+    assert_eq!(theme.line_stroke, accent);
+    assert_eq!(theme.baseline_stroke, accent);
+    assert_eq!(theme.candle_up, accent);
+    // Translucent fills keep their alpha
+    assert_eq!(theme.baseline_fill, accent.with_a(dark.baseline_fill.a()));
+    // Unrelated fields untouched
+    assert_eq!(theme.candle_down, dark.candle_down);
+    assert_eq!(theme.background, dark.background);
+}
+
+#[test]
+fn parse_hex_color_accepts_rgb_and_rgba() {
+    use chart_core::theme::parse_hex_color;
+    assert_eq!(parse_hex_color("#102030"), Some(Color::from_argb(255, 0x10, 0x20, 0x30)));
+    assert_eq!(parse_hex_color("#10203080"), Some(Color::from_argb(0x80, 0x10, 0x20, 0x30)));
+    assert_eq!(parse_hex_color("102030"), None);
+    assert_eq!(parse_hex_color("#1020"), None);
+    assert_eq!(parse_hex_color("#zz2030"), None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn from_toml_str_overrides_colors_over_base_preset() {
+    let src = r##"
+        name = "brand"
+        base = "light"
+        background = "#101820"
+        band_fill = "#2080C030"
+    "##;
+    let theme = Theme::from_toml_str(src).unwrap();
+    let light = Theme::light();
+    assert_eq!(theme.name, "brand");
+    assert_eq!(theme.background, Color::from_argb(255, 0x10, 0x18, 0x20));
+    assert_eq!(theme.band_fill, Color::from_argb(0x30, 0x20, 0x80, 0xC0));
+    // Omitted fields come from the base preset
+    assert_eq!(theme.grid, light.grid);
+    assert_eq!(theme.candle_down, light.candle_down);
+
+    assert!(Theme::from_toml_str("grid = \"blue\"").is_err());
+    assert!(Theme::from_toml_str("gird = \"#000000\"").is_err());
+    assert!(Theme::from_toml_str("base = \"ligth\"").is_err());
+}
+
+#[test]
+fn registered_theme_is_found_by_name() {
+    use chart_core::theme;
+    let bg = Color::from_argb(255, 9, 8, 7);
+    theme::register_theme(Theme::builder().name("test-registry-brand").background(bg).build());
+    assert_eq!(theme::find("Test-Registry-Brand").background, bg);
+    assert!(theme::themes().iter().any(|t| t.name == "test-registry-brand"));
+
+    // Re-registering under the same name replaces rather than duplicates
+    let bg2 = Color::from_argb(255, 1, 1, 1);
+    theme::register_theme(Theme::builder().name("test-registry-brand").background(bg2).build());
+    assert_eq!(theme::find("test-registry-brand").background, bg2);
+    assert_eq!(theme::themes().iter().filter(|t| t.name == "test-registry-brand").count(), 1);
+}
@@ -0,0 +1,150 @@
+// File: crates/chart-core/tests/view.rs
+// Purpose: ViewState interpolation, resizing and time-based view motion (animation, pan inertia).
+
+use chart_core::axis::ScaleKind;
+use chart_core::{PanInertia, ViewState};
+
+#[test]
+fn lerp_half_is_midpoint_of_each_bound() {
+    let a = ViewState { x_min: 0.0, x_max: 10.0, y_min: -4.0, y_max: 2.0 };
+    let b = ViewState { x_min: 20.0, x_max: 30.0, y_min: 0.0, y_max: 8.0 };
+    let m = a.lerp(&b, 0.5);
+    assert_eq!((m.x_min, m.x_max, m.y_min, m.y_max), (10.0, 20.0, -2.0, 5.0));
+
+    let end = a.lerp(&b, 1.0);
+    assert_eq!((end.x_min, end.x_max, end.y_min, end.y_max), (b.x_min, b.x_max, b.y_min, b.y_max));
+}
+
+#[test]
+fn lerp_scaled_moves_log_bounds_geometrically() {
+    let a = ViewState { x_min: 0.0, x_max: 1.0, y_min: 1.0, y_max: 10.0 };
+    let b = ViewState { x_min: 1.0, x_max: 3.0, y_min: 100.0, y_max: 1000.0 };
+    let m = a.lerp_scaled(&b, 0.5, ScaleKind::Linear, ScaleKind::Log10);
+    assert_eq!((m.x_min, m.x_max), (0.5, 2.0));
+    assert!((m.y_min - 10.0).abs() < 1e-9, "{}", m.y_min);
+    assert!((m.y_max - 100.0).abs() < 1e-9, "{}", m.y_max);
+}
+
+#[test]
+fn pan_and_zoom_track_data_points_at_high_dpr() {
+    use chart_core::{Chart, RenderOptions, Series, SeriesType};
+
+    let mut chart = Chart::new();
+    chart.add_series(Series::with_data(SeriesType::Line, vec![(0.0, 0.0), (10.0, 5.0)]));
+    let opts = RenderOptions { width: 1600, height: 1000, dpr: 2.0, ..Default::default() };
+    let insets = opts.insets.scaled(opts.dpr);
+    let mut view = ViewState::from_chart(&chart);
+    view.apply_to_chart(&mut chart);
+    let (px, py) = chart.data_to_pixel(&opts, 4.0, 2.0);
+
+    // Dragging by (dx, dy) device px moves the data point by the same amount on screen
+    view.pan_by_pixels(120.0, -60.0, opts.width, opts.height, &insets);
+    view.apply_to_chart(&mut chart);
+    let (qx, qy) = chart.data_to_pixel(&opts, 4.0, 2.0);
+    assert!((qx - (px + 120.0)).abs() < 1e-3 && (qy - (py - 60.0)).abs() < 1e-3, "({qx}, {qy})");
+
+    // Zooming at the point's pixel keeps it under the cursor
+    view.zoom_at_pixel(0.4, qx as f64, qy as f64, opts.width, opts.height, &insets);
+    view.apply_to_chart(&mut chart);
+    let (zx, zy) = chart.data_to_pixel(&opts, 4.0, 2.0);
+    assert!((zx - qx).abs() < 1e-3 && (zy - qy).abs() < 1e-3, "({zx}, {zy})");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json_round_trip_preserves_view() {
+    let view = ViewState { x_min: 1_700_000_000.125, x_max: 1_700_086_400.0, y_min: -0.1, y_max: 1.0 / 3.0 };
+    let json = view.to_json().unwrap();
+    assert_eq!(ViewState::from_json(&json).unwrap(), view);
+
+    assert!(ViewState::from_json(r#"{"x_min": 0, "x_max": 1, "y_min": 0}"#).is_err());
+}
+
+#[test]
+fn animator_eases_and_clamps_at_end() {
+    use chart_core::view::{ease_out_cubic, Animator};
+    use std::time::Duration;
+
+    let a = ViewState { x_min: 0.0, x_max: 10.0, y_min: 0.0, y_max: 1.0 };
+    let b = ViewState { x_min: 100.0, x_max: 200.0, y_min: -5.0, y_max: 5.0 };
+    let anim = Animator::new(a, b, Duration::from_millis(200));
+
+    let start = anim.sample(Duration::ZERO);
+    assert_eq!((start.x_min, start.x_max), (a.x_min, a.x_max));
+    // Ease-out covers more than half the distance by the halfway point
+    let half = anim.sample(Duration::from_millis(100));
+    assert!((half.x_min - 100.0 * ease_out_cubic(0.5)).abs() < 1e-9);
+    assert!(half.x_min > 50.0);
+
+    for elapsed in [200, 250, 10_000] {
+        let v = anim.sample(Duration::from_millis(elapsed));
+        assert_eq!((v.x_min, v.x_max, v.y_min, v.y_max), (b.x_min, b.x_max, b.y_min, b.y_max));
+    }
+    assert!(anim.is_finished(Duration::from_millis(200)));
+    assert!(!anim.is_finished(Duration::from_millis(199)));
+}
+
+#[test]
+fn rescale_for_new_width_keeps_center() {
+    let mut v = ViewState { x_min: 100.0, x_max: 200.0, y_min: -1.0, y_max: 1.0 };
+    v.rescale_for_new_width(800, 1200);
+    assert_eq!((v.x_min + v.x_max) * 0.5, 150.0);
+    assert_eq!(v.x_max - v.x_min, 150.0);
+    assert_eq!((v.y_min, v.y_max), (-1.0, 1.0));
+
+    v.rescale_for_new_width(1200, 400);
+    assert_eq!((v.x_min, v.x_max), (125.0, 175.0));
+    // Minimized window (zero width): untouched
+    v.rescale_for_new_width(400, 0);
+    assert_eq!((v.x_min, v.x_max), (125.0, 175.0));
+}
+
+#[test]
+fn pan_inertia_decays_to_zero() {
+    use std::time::Duration;
+
+    let frame = Duration::from_millis(16);
+    let mut inertia = PanInertia::new();
+    for _ in 0..5 {
+        inertia.track(20.0, -5.0, frame);
+    }
+    inertia.release(Duration::from_millis(10));
+    assert!(inertia.is_moving());
+
+    let mut steps = Vec::new();
+    while let Some(step) = inertia.tick(frame) {
+        steps.push(step);
+        assert!(steps.len() < 1000, "inertia never settled");
+    }
+    // Keeps the drag direction while every step shrinks, then stops dead
+    assert!(steps.len() > 10);
+    assert!(steps.iter().all(|&(dx, dy)| dx > 0.0 && dy < 0.0));
+    assert!(steps.windows(2).all(|w| w[1].0 < w[0].0));
+    assert_eq!((inertia.vx, inertia.vy), (0.0, 0.0));
+    assert_eq!(inertia.tick(frame), None);
+
+    // Holding still before releasing cancels the fling
+    inertia.track(20.0, 0.0, frame);
+    inertia.release(Duration::from_millis(500));
+    assert_eq!(inertia.tick(frame), None);
+}
+
+#[test]
+fn zoom_config_inversion_flips_direction() {
+    use chart_core::ZoomConfig;
+
+    let normal = ZoomConfig::default();
+    let inverted = ZoomConfig { invert: true, ..normal };
+    assert_eq!(normal.scroll_for_lines(1.0), 0.1);
+    assert_eq!(inverted.scroll_for_lines(1.0), -0.1);
+    assert_eq!(inverted.scroll_for_pixels(48.0), -normal.scroll_for_pixels(48.0));
+    assert_eq!(ZoomConfig { sensitivity: 2.5, ..normal }.scroll_for_lines(2.0), 0.5);
+
+    // Same wheel notch: one zooms in, the other out
+    let insets = chart_core::types::Insets::default();
+    let start = ViewState { x_min: 0.0, x_max: 100.0, y_min: 0.0, y_max: 100.0 };
+    let (mut a, mut b) = (start, start);
+    a.zoom_at_pixel(normal.scroll_for_lines(1.0), 400.0, 300.0, 800, 600, &insets);
+    b.zoom_at_pixel(inverted.scroll_for_lines(1.0), 400.0, 300.0, 800, 600, &insets);
+    assert!(a.x_max - a.x_min < 100.0 && b.x_max - b.x_min > 100.0);
+}
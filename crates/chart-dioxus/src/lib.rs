@@ -36,6 +36,9 @@ pub mod ui {
         pub height_px: i32,
     }
 
+    /// Device pixel ratio the canvas renders at; pan/zoom insets must use the same scale.
+    const RENDER_DPR: f32 = 1.0;
+
     impl PartialEq for ChartCanvasProps {
         fn eq(&self, _other: &Self) -> bool { false }
     }
@@ -78,7 +81,7 @@ pub mod ui {
                 let mut opts = RenderOptions::default();
                 opts.width = *width.read();
                 opts.height = *height.read();
-                opts.dpr = 1.0;
+                opts.dpr = RENDER_DPR;
                 opts.draw_labels = true;
                 opts.show_tooltip = true;
                 opts.theme = props.theme;
@@ -136,39 +139,39 @@ pub mod ui {
                     // Pan controls
                     button { onclick: move |_| {
                             let mut v = *view.read();
-                            v.pan_by_pixels(-50.0, 0.0, *width.read(), *height.read(), &RenderOptions::default().insets);
+                            v.pan_by_pixels(-50.0, 0.0, *width.read(), *height.read(), &RenderOptions::default().insets.scaled(RENDER_DPR));
                             view.set(v);
                             render_now();
                         }, "Pan \u{2190}" }
                     button { onclick: move |_| {
                             let mut v = *view.read();
-                            v.pan_by_pixels(50.0, 0.0, *width.read(), *height.read(), &RenderOptions::default().insets);
+                            v.pan_by_pixels(50.0, 0.0, *width.read(), *height.read(), &RenderOptions::default().insets.scaled(RENDER_DPR));
                             view.set(v);
                             render_now();
                         }, "Pan \u{2192}" }
                     button { onclick: move |_| {
                             let mut v = *view.read();
-                            v.pan_by_pixels(0.0, -30.0, *width.read(), *height.read(), &RenderOptions::default().insets);
+                            v.pan_by_pixels(0.0, -30.0, *width.read(), *height.read(), &RenderOptions::default().insets.scaled(RENDER_DPR));
                             view.set(v);
                             render_now();
                         }, "Pan \u{2191}" }
                     button { onclick: move |_| {
                             let mut v = *view.read();
-                            v.pan_by_pixels(0.0, 30.0, *width.read(), *height.read(), &RenderOptions::default().insets);
+                            v.pan_by_pixels(0.0, 30.0, *width.read(), *height.read(), &RenderOptions::default().insets.scaled(RENDER_DPR));
                             view.set(v);
                             render_now();
                         }, "Pan \u{2193}" }
                     button { onclick: move |_| {
                             let mut v = *view.read();
                             let (cx, cy) = ((*width.read() as f64)/2.0, (*height.read() as f64)/2.0);
-                            v.zoom_at_pixel( 0.2, cx, cy, *width.read(), *height.read(), &RenderOptions::default().insets);
+                            v.zoom_at_pixel( 0.2, cx, cy, *width.read(), *height.read(), &RenderOptions::default().insets.scaled(RENDER_DPR));
                             view.set(v);
                             render_now();
                         }, "Zoom +" }
                     button { onclick: move |_| {
                             let mut v = *view.read();
                             let (cx, cy) = ((*width.read() as f64)/2.0, (*height.read() as f64)/2.0);
-                            v.zoom_at_pixel(-0.2, cx, cy, *width.read(), *height.read(), &RenderOptions::default().insets);
+                            v.zoom_at_pixel(-0.2, cx, cy, *width.read(), *height.read(), &RenderOptions::default().insets.scaled(RENDER_DPR));
                             view.set(v);
                             render_now();
                         }, "Zoom -" }
@@ -13,7 +13,7 @@ use winit::{dpi::LogicalSize, event::Event, event_loop::EventLoop, window::Windo
 use std::num::NonZeroU32;
 
 use chart_render_skia::SkiaRenderer;
-use chart_core::{Chart, Axis, Series, RenderOptions, SeriesType, ViewState, Theme};
+use chart_core::{Chart, Axis, Series, RenderOptions, SeriesType, ViewState, Theme, ZoomConfig};
 use chart_core::series::Candle;
 use chart_core::lttb;
 use csv;
@@ -101,17 +101,18 @@ pub fn run() -> Result<(), String> {
 
     // Prepare charts and simple view state
     let mut downsample = true;
-    let mut charts = build_charts(&candles, downsample, window.inner_size().width as usize);
+    let mut charts = build_charts(&candles, downsample, window.inner_size().width as usize, window.scale_factor() as f32);
     let mut idx: usize = 0;
     let mut view = ViewState::from_chart(&charts[idx]);
     let mut opts = RenderOptions::default();
     opts.draw_labels = true;
     opts.show_tooltip = true;
     opts.dpr = window.scale_factor() as f32;
-    let themes = chart_core::theme::presets();
+    let themes = chart_core::theme::themes();
     let mut theme_idx: usize = 0;
     let mut cursor_pos: Option<(f64, f64)> = None;
     let mut dragging = false;
+    let zoom = ZoomConfig { invert: std::env::args().any(|a| a == "--invert-zoom"), ..ZoomConfig::default() };
 
     // Initial title with theme + downsampling status
     window.set_title(&format!(
@@ -155,12 +156,12 @@ pub fn run() -> Result<(), String> {
                 }
                 winit::event::WindowEvent::MouseWheel { delta, .. } => {
                     if let Some((cx, cy)) = cursor_pos {
-                        let insets = RenderOptions::default().insets;
+                        let insets = RenderOptions::default().insets.scaled(window.scale_factor() as f32);
                         let w = window.inner_size().width as i32;
                         let h = window.inner_size().height as i32;
                         let scroll = match delta {
-                            winit::event::MouseScrollDelta::LineDelta(_, y) => y as f64 * 0.1,
-                            winit::event::MouseScrollDelta::PixelDelta(p) => (p.y as f64) / 240.0,
+                            winit::event::MouseScrollDelta::LineDelta(_, y) => zoom.scroll_for_lines(y as f64),
+                            winit::event::MouseScrollDelta::PixelDelta(p) => zoom.scroll_for_pixels(p.y),
                         };
                         view.zoom_at_pixel(scroll, cx, cy, w, h, &insets);
                     }
@@ -184,7 +185,7 @@ pub fn run() -> Result<(), String> {
                     )
                     .expect("Skia GPU surface recreate failed");
                     if downsample {
-                        charts = build_charts(&candles, downsample, new_size.width as usize);
+                        charts = build_charts(&candles, downsample, new_size.width as usize, window.scale_factor() as f32);
                         view = ViewState::from_chart(&charts[idx]);
                     }
                 }
@@ -197,7 +198,7 @@ pub fn run() -> Result<(), String> {
                         Some(winit::event::VirtualKeyCode::Key4) | Some(winit::event::VirtualKeyCode::Numpad4) => { idx = 3; view = ViewState::from_chart(&charts[idx]); }
                         Some(winit::event::VirtualKeyCode::A) => { view = ViewState::from_chart(&charts[idx]); }
                         Some(winit::event::VirtualKeyCode::Y) => { let _ = view.autoscale_y_visible(&charts[idx]); }
-                        Some(winit::event::VirtualKeyCode::D) => { downsample = !downsample; charts = build_charts(&candles, downsample, window.inner_size().width as usize); view = ViewState::from_chart(&charts[idx]); window.set_title(&format!("Constellation Chart - {} | {}{}", series_title(idx), themes.get(theme_idx % themes.len()).map(|t| t.name).unwrap_or("dark"), if downsample { " | DS:on" } else { " | DS:off" })); }
+                        Some(winit::event::VirtualKeyCode::D) => { downsample = !downsample; charts = build_charts(&candles, downsample, window.inner_size().width as usize, window.scale_factor() as f32); view = ViewState::from_chart(&charts[idx]); window.set_title(&format!("Constellation Chart - {} | {}{}", series_title(idx), themes.get(theme_idx % themes.len()).map(|t| t.name).unwrap_or("dark"), if downsample { " | DS:on" } else { " | DS:off" })); }
                         Some(winit::event::VirtualKeyCode::T) => { theme_idx = (theme_idx + 1) % themes.len(); window.set_title(&format!("Constellation Chart - {} | {}{}", series_title(idx), themes.get(theme_idx % themes.len()).map(|t| t.name).unwrap_or("dark"), if downsample { " | DS:on" } else { " | DS:off" })); }
                         Some(winit::event::VirtualKeyCode::Escape) => { *control_flow = winit::event_loop::ControlFlow::Exit; }
                         _ => {}
@@ -215,7 +216,7 @@ pub fn run() -> Result<(), String> {
             Event::DeviceEvent { event: winit::event::DeviceEvent::MouseMotion { delta }, .. } => {
                 if dragging {
                     let (dx, dy) = delta;
-                    let insets = RenderOptions::default().insets;
+                    let insets = RenderOptions::default().insets.scaled(window.scale_factor() as f32);
                     let w = window.inner_size().width as i32;
                     let h = window.inner_size().height as i32;
                     view.pan_by_pixels(dx as f64, dy as f64, w, h, &insets);
@@ -286,10 +287,10 @@ fn minmax_xy(v: &[(f64, f64)]) -> (f64, f64) {
     (min_v, max_v)
 }
 
-fn build_charts(candles: &[Candle], enable_downsample: bool, target_width_px: usize) -> Vec<Chart> {
+fn build_charts(candles: &[Candle], enable_downsample: bool, target_width_px: usize, dpr: f32) -> Vec<Chart> {
     let n = candles.len();
     let (min_p, max_p) = minmax_price(candles);
-    let insets = RenderOptions::default().insets;
+    let insets = RenderOptions::default().insets.scaled(dpr);
     let plot_w = target_width_px.saturating_sub((insets.left + insets.right) as usize).max(400);
     let target_points = plot_w;
     let bucket = if enable_downsample && n > target_points { ((n as f64) / (target_points as f64)).ceil() as usize } else { 1 };
@@ -1,11 +1,11 @@
 // File: crates/window-demo/src/main.rs
 // Windowed demo: shows chart-core in a window with crosshair, pan, and zoom.
 
-use chart_core::{Axis, Chart, RenderOptions, Series, ViewState, Theme, SmaOverlay, HvLineOverlay, OverlayEvent, Overlay};
+use chart_core::{Animator, Axis, Chart, PanInertia, RenderOptions, Renderer, Series, ViewState, ZoomConfig, Theme, SmaOverlay, HvLineOverlay, OverlayEvent, Overlay};
 use chart_core::scale::{TimeScale, ValueScale};
 use chart_core::series::{Candle, SeriesType};
 use std::num::NonZeroU32;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use winit::event::{Event, MouseButton, WindowEvent, ElementState, VirtualKeyCode};
@@ -49,7 +49,7 @@ fn main() {
 
     // Prepare charts for multiple series types with optional downsampling
     let mut downsample = true; // toggle with 'D'
-    let mut charts = build_charts(&candles, downsample, 1024);
+    let mut charts = build_charts(&candles, downsample, 1024, 1.0);
     let mut show_overlay = true; // toggle with 'O'
     let hv_overlay = std::sync::Arc::new(HvLineOverlay::new());
     if show_overlay {
@@ -59,6 +59,8 @@ fn main() {
         }
     }
 
+    eprintln!("Keys: 1-4 series | arrows pan | +/- zoom | A fit | Y autoscale Y | L log | O overlays | D downsample | T theme | E export | Esc quit");
+
     // Window + softbuffer
     let event_loop = EventLoop::new();
     let window = WindowBuilder::new()
@@ -79,7 +81,13 @@ fn main() {
     let view: Arc<Mutex<ViewState>> = Arc::new(Mutex::new(view0));
     let view_draw = Arc::clone(&view);
     let mut dragging = false;
-    let themes: Arc<Vec<Theme>> = Arc::new(chart_core::theme::presets());
+    // Wheel zoom; natural-scroll users can pass --invert-zoom
+    let zoom = ZoomConfig { invert: std::env::args().any(|a| a == "--invert-zoom"), ..ZoomConfig::default() };
+    // An app-defined theme; T cycles it together with the built-in presets
+    if let Some(accent) = chart_core::theme::parse_hex_color("#B48CFF") {
+        chart_core::theme::register_theme(Theme::builder().name("dark-violet").accent(accent).build());
+    }
+    let themes: Arc<Vec<Theme>> = Arc::new(chart_core::theme::themes());
     let theme_idx: Arc<Mutex<usize>> = Arc::new(Mutex::new(0));
     let theme_idx_draw = Arc::clone(&theme_idx);
     let themes_draw = Arc::clone(&themes);
@@ -87,6 +95,8 @@ fn main() {
     // Drawing closure
     let dpr: Arc<Mutex<f32>> = Arc::new(Mutex::new(window.scale_factor() as f32));
     let dpr_draw = Arc::clone(&dpr);
+    // One raster surface reused across frames; reallocated only on resize
+    let mut renderer = Renderer::new();
     let mut draw = move |charts: &mut [Chart]| {
         let w = size.width.max(1);
         let h = size.height.max(1);
@@ -118,8 +128,8 @@ fn main() {
         }
 
         // Render and blit
-        let (rgba, _, _, _) = charts[idx]
-            .render_to_rgba8(&opts)
+        let rgba = renderer
+            .render_to_rgba8(&charts[idx], &opts)
             .expect("render rgba");
         let mut frame = surface.buffer_mut().expect("frame");
         let max_px = frame.len().min(rgba.len() / 4);
@@ -138,6 +148,13 @@ fn main() {
         }
     };
 
+    // In-flight view animation (A key) and when it started
+    let mut view_anim: Option<(Animator, Instant)> = None;
+    // Momentum after a fast drag; timestamps for drag velocity and frame steps
+    let mut inertia = PanInertia::new();
+    let mut last_motion = Instant::now();
+    let mut last_frame = Instant::now();
+
     // Event loop
     let control_flow = ControlFlow::Wait;
     event_loop.run(move |event, _, cf| {
@@ -148,23 +165,26 @@ fn main() {
                     *cf = ControlFlow::Exit;
                 }
                 WindowEvent::Resized(new_size) => {
+                    let old_width = size.width as i32;
                     size = new_size;
+                    *dpr.lock().unwrap() = window.scale_factor() as f32;
                     if downsample {
-                        charts = build_charts(&candles, downsample, size.width as usize);
-                        *view.lock().unwrap() = ViewState::from_chart(&charts[idx]);
+                        charts = build_charts(&candles, downsample, size.width as usize, *dpr.lock().unwrap());
                     }
-                    *dpr.lock().unwrap() = window.scale_factor() as f32;
+                    // Keep the user's position: same center, span follows the width
+                    view.lock().unwrap().rescale_for_new_width(old_width, size.width as i32);
                 }
                 WindowEvent::CursorMoved { position, .. } => {
                     *cursor_pos.lock().unwrap() = Some((position.x, position.y));
                 }
                 WindowEvent::MouseInput { state, button, .. } => {
                     if button == MouseButton::Left {
-                        dragging = state == winit::event::ElementState::Pressed;
-                        if dragging {
+                        let pressed = state == winit::event::ElementState::Pressed;
+                        let mut consumed = false;
+                        if pressed {
                             if let Some((cx, cy)) = *cursor_pos.lock().unwrap() {
                                 // Map pixel -> world (chart coords)
-                                let insets = RenderOptions::default().insets;
+                                let insets = RenderOptions::default().insets.scaled(*dpr.lock().unwrap());
                                 let w = size.width as i32; let h = size.height as i32;
                                 let ch = &charts[idx];
                                 let l = insets.left as f32; let rpx = w as f32 - insets.right as f32;
@@ -177,22 +197,26 @@ fn main() {
                                 };
                                 let xw = ts.from_px(cx as f32);
                                 let yw = vs.from_px(cy as f32);
-                                hv_overlay.handle_event(&OverlayEvent::PointerDown { x: xw, y: yw }, ch);
+                                consumed = hv_overlay.handle_event(&OverlayEvent::PointerDown { x: xw, y: yw }, ch).is_consumed();
                             }
                         }
+                        // A press the overlay handled (e.g. placing the guide line) doesn't start a pan
+                        dragging = pressed && !consumed;
+                        if pressed { inertia.stop(); } else { inertia.release(last_motion.elapsed()); }
                     }
                 }
                 
                 WindowEvent::MouseWheel { delta, .. } => {
                     if let Some((cx, cy)) = *cursor_pos.lock().unwrap() {
                         // Map cursor to world and zoom around it
-                        let insets = RenderOptions::default().insets;
+                        let insets = RenderOptions::default().insets.scaled(*dpr.lock().unwrap());
                         let w = size.width as i32;
                         let h = size.height as i32;
                         let scroll = match delta {
-                            winit::event::MouseScrollDelta::LineDelta(_, y) => y as f64 * 0.1,
-                            winit::event::MouseScrollDelta::PixelDelta(p) => (p.y as f64) / 240.0,
+                            winit::event::MouseScrollDelta::LineDelta(_, y) => zoom.scroll_for_lines(y as f64),
+                            winit::event::MouseScrollDelta::PixelDelta(p) => zoom.scroll_for_pixels(p.y),
                         };
+                        view_anim = None;
                         let mut vmut = view.lock().unwrap();
                         vmut.zoom_at_pixel(scroll, cx, cy, w, h, &insets);
                     }
@@ -208,7 +232,10 @@ fn main() {
                         Some(VirtualKeyCode::Key4) | Some(VirtualKeyCode::Numpad4) => Some(3),
                         // Autoscale: A = full extents both axes; Y = autoscale Y on visible X range
                         Some(VirtualKeyCode::A) => {
-                            *view.lock().unwrap() = ViewState::from_chart(&charts[idx]);
+                            // Ease into the fit-all view instead of snapping
+                            let from = *view.lock().unwrap();
+                            let to = ViewState::from_chart(&charts[idx]);
+                            view_anim = Some((Animator::new(from, to, Duration::from_millis(250)), Instant::now()));
                             let ti = *theme_idx.lock().unwrap();
                             window.set_title(&format!(
                                 "Constellation Chart - {} | {}{}",
@@ -242,6 +269,30 @@ fn main() {
                             *view.lock().unwrap() = ViewState::from_chart(&charts[idx]);
                             None
                         }
+                        // Keyboard navigation, same steps as the Dioxus toolbar buttons
+                        Some(key @ (VirtualKeyCode::Left | VirtualKeyCode::Right | VirtualKeyCode::Up | VirtualKeyCode::Down)) => {
+                            let (dx, dy) = match key {
+                                VirtualKeyCode::Left => (-50.0, 0.0),
+                                VirtualKeyCode::Right => (50.0, 0.0),
+                                VirtualKeyCode::Up => (0.0, -30.0),
+                                _ => (0.0, 30.0),
+                            };
+                            view_anim = None;
+                            inertia.stop();
+                            let insets = RenderOptions::default().insets.scaled(*dpr.lock().unwrap());
+                            view.lock().unwrap().pan_by_pixels(dx, dy, size.width as i32, size.height as i32, &insets);
+                            None
+                        }
+                        Some(key @ (VirtualKeyCode::Plus | VirtualKeyCode::Equals | VirtualKeyCode::NumpadAdd | VirtualKeyCode::Minus | VirtualKeyCode::NumpadSubtract)) => {
+                            // Zoom around the window center
+                            let scroll = if matches!(key, VirtualKeyCode::Minus | VirtualKeyCode::NumpadSubtract) { -0.2 } else { 0.2 };
+                            view_anim = None;
+                            inertia.stop();
+                            let insets = RenderOptions::default().insets.scaled(*dpr.lock().unwrap());
+                            let (w, h) = (size.width as i32, size.height as i32);
+                            view.lock().unwrap().zoom_at_pixel(scroll, w as f64 / 2.0, h as f64 / 2.0, w, h, &insets);
+                            None
+                        }
                         Some(VirtualKeyCode::Y) => {
                             let mut vmut = view.lock().unwrap();
                             vmut.autoscale_y_visible(&charts[idx]);
@@ -250,7 +301,7 @@ fn main() {
                         Some(VirtualKeyCode::D) => {
                             // Toggle downsampling and rebuild to match current width
                             downsample = !downsample;
-                            charts = build_charts(&candles, downsample, size.width as usize);
+                            charts = build_charts(&candles, downsample, size.width as usize, *dpr.lock().unwrap());
                             if show_overlay {
                                 for ch in charts.iter_mut() {
                                     ch.add_overlay(SmaOverlay { period: 14 });
@@ -339,13 +390,36 @@ fn main() {
             } => {
                 if dragging {
                     let (dx, dy) = delta;
-                    let insets = RenderOptions::default().insets;
+                    let insets = RenderOptions::default().insets.scaled(*dpr.lock().unwrap());
                     let w = size.width as i32;
                     let h = size.height as i32;
+                    view_anim = None;
                     view.lock().unwrap().pan_by_pixels(dx as f64, dy as f64, w, h, &insets);
+                    let now = Instant::now();
+                    inertia.track(dx, dy, now - last_motion);
+                    last_motion = now;
                 }
             }
             Event::MainEventsCleared => {
+                let now = Instant::now();
+                // Cap the step so the first frame after an idle wait doesn't fling the view
+                let frame_dt = (now - last_frame).min(Duration::from_millis(50));
+                last_frame = now;
+                if let Some((anim, started)) = view_anim {
+                    let elapsed = started.elapsed();
+                    *view.lock().unwrap() = anim.sample(elapsed);
+                    if anim.is_finished(elapsed) { view_anim = None; }
+                } else if !dragging {
+                    // Coast after a fling until the velocity decays
+                    if let Some((dx, dy)) = inertia.tick(frame_dt) {
+                        let insets = RenderOptions::default().insets.scaled(*dpr.lock().unwrap());
+                        view.lock().unwrap().pan_by_pixels(dx, dy, size.width as i32, size.height as i32, &insets);
+                    }
+                }
+                // Keep frames coming while the view animates or coasts; under Wait it would only advance on input
+                if view_anim.is_some() || inertia.is_moving() {
+                    *cf = ControlFlow::WaitUntil(now + Duration::from_millis(16));
+                }
                 window.request_redraw();
             }
             Event::RedrawRequested(_) => {
@@ -366,10 +440,10 @@ fn series_title(idx: usize) -> &'static str {
     }
 }
 
-fn build_charts(candles: &[Candle], enable_downsample: bool, target_width_px: usize) -> Vec<Chart> {
+fn build_charts(candles: &[Candle], enable_downsample: bool, target_width_px: usize, dpr: f32) -> Vec<Chart> {
     let n = candles.len();
     let (min_p, max_p) = minmax_price(candles);
-    let insets = RenderOptions::default().insets;
+    let insets = RenderOptions::default().insets.scaled(dpr);
     let plot_w = target_width_px.saturating_sub((insets.left + insets.right) as usize).max(400);
     let target_points = plot_w; // approx 1 point per pixel
     let bucket = if enable_downsample && n > target_points { ((n as f64) / (target_points as f64)).ceil() as usize } else { 1 };